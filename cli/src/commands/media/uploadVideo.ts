@@ -19,6 +19,7 @@ import last from 'it-last'
 import toBuffer from 'it-to-buffer'
 import ffprobeInstaller from '@ffprobe-installer/ffprobe'
 import ffmpeg from 'fluent-ffmpeg'
+import mime from 'mime-types'
 import MediaCommandBase from '../../base/MediaCommandBase'
 import { getInputJson, validateInput, IOFlags } from '../../helpers/InputOutput'
 
@@ -26,6 +27,7 @@ ffmpeg.setFfprobePath(ffprobeInstaller.path)
 
 const DATA_OBJECT_TYPE_ID = 1
 const MAX_FILE_SIZE = 2000 * 1024 * 1024
+const DEFAULT_CONTENT_TYPE = 'application/octet-stream'
 
 type VideoMetadata = {
   width?: number
@@ -387,11 +389,14 @@ export default class UploadVideoCommand extends MediaCommandBase {
     })
 
     // Send dataDirectory.addContent extrinsic
+    const contentType = mime.lookup(filePath) || DEFAULT_CONTENT_TYPE
     await this.sendAndFollowNamedTx(account, 'dataDirectory', 'addContent', [
       memberId,
+      channelId,
       contentId,
       DATA_OBJECT_TYPE_ID,
       fileSize,
+      contentType,
       ipfsCid,
     ])
 
@@ -32,7 +32,7 @@ async function main() {
     memberId = (await api.query.members.nextMemberId()).toNumber()
     await txHelper.sendAndCheck(
       LeadKeyPair,
-      [api.tx.members.buyMembership(0, 'alice', null, null)],
+      [api.tx.members.buyMembership(0, 'alice', null, null, null)],
       'Failed to setup member account'
     )
   }
@@ -32,8 +32,12 @@
 
 use std::sync::Arc;
 
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result as RpcResult};
+use jsonrpc_derive::rpc;
 use node_runtime::UncheckedExtrinsic;
 use node_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, Index};
+use node_runtime::{ContentId, DataDirectoryApi as DataDirectoryRuntimeApi};
+use node_runtime::{ProposalId, ProposalInfo, ProposalsApi as ProposalsRuntimeApi};
 use sc_consensus_babe::{Config, Epoch};
 use sc_consensus_babe_rpc::BabeRpcHandler;
 use sc_consensus_epochs::SharedEpochChanges;
@@ -46,6 +50,7 @@ use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 use sp_consensus::SelectChain;
 use sp_consensus_babe::BabeApi;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
 use sp_transaction_pool::TransactionPool;
 
 /// Light client extra dependencies.
@@ -94,6 +99,100 @@ pub struct FullDeps<C, P, SC> {
     pub grandpa: GrandpaDeps,
 }
 
+/// Proposal queries RPC methods.
+#[rpc]
+pub trait ProposalsApi<BlockHash> {
+    /// Returns the proposal with its decoded details, voting results, projected decision status
+    /// and the block at which its voting or grace period ends.
+    #[rpc(name = "proposals_proposal")]
+    fn proposal(
+        &self,
+        proposal_id: ProposalId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ProposalInfo>>;
+}
+
+/// Implements the proposal queries RPC methods using the `ProposalsApi` runtime API.
+pub struct Proposals<C> {
+    client: Arc<C>,
+}
+
+impl<C> Proposals<C> {
+    /// Creates a new instance of the proposal queries RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Proposals { client }
+    }
+}
+
+impl<C> ProposalsApi<<Block as BlockT>::Hash> for Proposals<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ProposalsRuntimeApi<Block>,
+{
+    fn proposal(
+        &self,
+        proposal_id: ProposalId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ProposalInfo>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.proposal(&at, proposal_id).map_err(|err| RpcError {
+            code: ErrorCode::ServerError(1),
+            message: "Unable to query the proposal.".into(),
+            data: Some(format!("{:?}", err).into()),
+        })
+    }
+}
+
+/// Data directory queries RPC methods.
+#[rpc]
+pub trait DataDirectoryApi<BlockHash> {
+    /// Returns a page of the known content ids starting from the `start` position.
+    #[rpc(name = "dataDirectory_knownContentIds")]
+    fn known_content_ids(
+        &self,
+        start: u64,
+        limit: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ContentId>>;
+}
+
+/// Implements the data directory queries RPC methods using the `DataDirectoryApi` runtime API.
+pub struct DataDirectory<C> {
+    client: Arc<C>,
+}
+
+impl<C> DataDirectory<C> {
+    /// Creates a new instance of the data directory queries RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        DataDirectory { client }
+    }
+}
+
+impl<C> DataDirectoryApi<<Block as BlockT>::Hash> for DataDirectory<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DataDirectoryRuntimeApi<Block>,
+{
+    fn known_content_ids(
+        &self,
+        start: u64,
+        limit: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ContentId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.known_content_ids(&at, start, limit)
+            .map_err(|err| RpcError {
+                code: ErrorCode::ServerError(1),
+                message: "Unable to query the known content ids.".into(),
+                data: Some(format!("{:?}", err).into()),
+            })
+    }
+}
+
 /// Instantiate all Full RPC extensions.
 pub fn create_full<C, P, M, SC>(deps: FullDeps<C, P, SC>) -> jsonrpc_core::IoHandler<M>
 where
@@ -108,6 +207,8 @@ where
     >,
     C::Api: BabeApi<Block>,
     C::Api: BlockBuilder<Block>,
+    C::Api: ProposalsRuntimeApi<Block>,
+    C::Api: DataDirectoryRuntimeApi<Block>,
     P: TransactionPool + 'static,
     M: jsonrpc_core::Metadata + Default,
     SC: SelectChain<Block> + 'static,
@@ -145,6 +246,10 @@ where
     io.extend_with(TransactionPaymentApi::to_delegate(TransactionPayment::new(
         client.clone(),
     )));
+    io.extend_with(ProposalsApi::to_delegate(Proposals::new(client.clone())));
+    io.extend_with(DataDirectoryApi::to_delegate(DataDirectory::new(
+        client.clone(),
+    )));
     io.extend_with(sc_consensus_babe_rpc::BabeApi::to_delegate(
         BabeRpcHandler::new(
             client,
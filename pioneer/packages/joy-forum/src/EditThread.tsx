@@ -146,7 +146,8 @@ const InnerForm = (props: FormProps) => {
       return [
         resolvedCategoryId,
         title,
-        text
+        text,
+        null
       ];
     } else {
       // NOTE: currently forum SRML doesn't support thread update.
@@ -12,7 +12,7 @@ import AddressMini from '@polkadot/react-components/AddressMini';
 import { formatNumber } from '@polkadot/util';
 
 import translate from './translate';
-import { MemberId, Membership, EntryMethod, Paid, Screening, Genesis, SubscriptionId } from '@joystream/types/members';
+import { MemberId, Membership, EntryMethod, Paid, Screening, Genesis, Invited, SubscriptionId } from '@joystream/types/members';
 import { queryMembershipToProp } from './utils';
 import { Seat } from '@joystream/types/council';
 import { nonEmptyStr, queryToProp } from '@polkadot/joy-utils/functions/misc';
@@ -159,6 +159,10 @@ class Component extends React.PureComponent<Props> {
       return <div>Screened by <AddressMini value={accountId} isShort={false} isPadded={false} withBalance /></div>;
     } else if (etype === Genesis.name) {
       return <div>Created at Genesis</div>;
+    } else if (etype === Invited.name) {
+      const inviterId = entry.value as Invited;
+
+      return <div>Invited by member ID: {inviterId.toNumber()}</div>;
     } else {
       return <em className='muted text'>Unknown</em>;
     }
@@ -122,8 +122,8 @@ const InnerForm = (props: FormProps) => {
       // update profile
       return [memberId, ...userInfo];
     } else {
-      // register as new member
-      return [paidTermId, ...userInfo];
+      // register as new member without a referrer
+      return [paidTermId, ...userInfo, null];
     }
   };
 
@@ -0,0 +1,489 @@
+//! Content directory module benchmarking.
+
+use super::*;
+use frame_benchmarking::benchmarks;
+use sp_std::prelude::*;
+use system::RawOrigin;
+
+/// Lead authorization is provided by the runtime `ActorAuthenticator`, so the runtime has to
+/// tell the benchmarks which account acts as the content directory lead.
+pub trait Trait: crate::Trait {
+    /// Sets up the content directory lead and returns its account.
+    fn create_lead() -> Self::AccountId;
+}
+
+const FIRST_PROPERTY_ID: PropertyId = 0;
+
+// Builds a unique text of the given length.
+fn text_of_length(id: u32, len: u16) -> Vec<u8> {
+    let mut text = b"text".to_vec();
+    text.extend_from_slice(&id.to_be_bytes());
+    text.resize(len as usize, b'x');
+    text
+}
+
+fn add_curator_group<T: Trait>(lead: &T::AccountId) -> T::CuratorGroupId {
+    let curator_group_id = Module::<T>::next_curator_group_id();
+
+    Module::<T>::add_curator_group(RawOrigin::Signed(lead.clone()).into())
+        .expect("lead can add curator groups");
+
+    curator_group_id
+}
+
+// Adds the given number of curator groups and returns their ids.
+fn add_curator_groups<T: Trait>(lead: &T::AccountId, count: u32) -> BTreeSet<T::CuratorGroupId> {
+    (0..count).map(|_| add_curator_group::<T>(lead)).collect()
+}
+
+// Fills the curator group with the given number of curators and returns the next unused curator id.
+fn add_curators_to_group<T: Trait>(
+    lead: &T::AccountId,
+    curator_group_id: T::CuratorGroupId,
+    count: u32,
+) -> T::CuratorId {
+    let mut curator_id = T::CuratorId::zero();
+
+    for _ in 0..count {
+        Module::<T>::add_curator_to_group(
+            RawOrigin::Signed(lead.clone()).into(),
+            curator_group_id,
+            curator_id,
+        )
+        .expect("lead can add curators");
+
+        curator_id += T::CuratorId::one();
+    }
+
+    curator_id
+}
+
+fn create_class<T: Trait>(
+    lead: &T::AccountId,
+    maintainers: BTreeSet<T::CuratorGroupId>,
+) -> T::ClassId {
+    let class_id = Module::<T>::next_class_id();
+
+    let mut class_permissions = ClassPermissions::default();
+    class_permissions.set_maintainers(maintainers);
+
+    Module::<T>::create_class(
+        RawOrigin::Signed(lead.clone()).into(),
+        text_of_length(0, T::ClassNameLengthConstraint::get().max()),
+        text_of_length(0, T::ClassDescriptionLengthConstraint::get().max()),
+        class_permissions,
+        T::MaxNumberOfEntitiesPerClass::get(),
+        T::IndividualEntitiesCreationLimit::get(),
+    )
+    .expect("lead can create classes");
+
+    class_id
+}
+
+fn property<T: Trait>(id: u32, property_type: PropertyType<T::ClassId>) -> Property<T::ClassId> {
+    Property {
+        property_type,
+        required: false,
+        unique: false,
+        name: text_of_length(id, T::PropertyNameLengthConstraint::get().max()),
+        description: text_of_length(id, T::PropertyDescriptionLengthConstraint::get().max()),
+        locking_policy: PropertyLockingPolicy::default(),
+    }
+}
+
+// The maximum number of the longest possible text properties.
+fn text_properties<T: Trait>() -> Vec<Property<T::ClassId>> {
+    (0..T::MaxNumberOfPropertiesPerSchema::get())
+        .map(|id| {
+            property::<T>(
+                id,
+                PropertyType::Single(Type::Text(T::TextMaxLengthConstraint::get())),
+            )
+        })
+        .collect()
+}
+
+// A single property holding the longest possible vector of the longest possible texts.
+fn text_vector_properties<T: Trait>() -> Vec<Property<T::ClassId>> {
+    let vec_type = VecPropertyType::new(
+        Type::Text(T::TextMaxLengthConstraint::get()),
+        T::VecMaxLengthConstraint::get(),
+    );
+
+    vec![property::<T>(0, PropertyType::Vector(vec_type))]
+}
+
+fn text_values<T: Trait>(id: u32) -> BTreeMap<PropertyId, InputPropertyValue<T>> {
+    (0..T::MaxNumberOfPropertiesPerSchema::get())
+        .map(|property_id| {
+            let text = text_of_length(id, T::TextMaxLengthConstraint::get());
+            (
+                property_id as PropertyId,
+                InputPropertyValue::Single(InputValue::Text(text)),
+            )
+        })
+        .collect()
+}
+
+fn text_vector_values<T: Trait>(len: u16) -> BTreeMap<PropertyId, InputPropertyValue<T>> {
+    let texts = (0..len)
+        .map(|id| text_of_length(id.into(), T::TextMaxLengthConstraint::get()))
+        .collect();
+
+    let mut values = BTreeMap::new();
+    values.insert(
+        FIRST_PROPERTY_ID,
+        InputPropertyValue::Vector(VecInputValue::Text(texts)),
+    );
+    values
+}
+
+// Splits the payload of the given size into the longest possible texts of the schema properties.
+fn parametrized_text_values<T: Trait>(size: u32) -> Vec<ParametrizedClassPropertyValue<T>> {
+    let text_max_length = u32::from(T::TextMaxLengthConstraint::get());
+    let mut remaining_size = size;
+    let mut values = Vec::new();
+
+    for property_id in 0..T::MaxNumberOfPropertiesPerSchema::get() {
+        if remaining_size == 0 {
+            break;
+        }
+
+        let text_length = remaining_size.min(text_max_length);
+        remaining_size -= text_length;
+
+        values.push(ParametrizedClassPropertyValue {
+            in_class_index: property_id as PropertyId,
+            value: ParametrizedPropertyValue::InputPropertyValue(InputPropertyValue::Single(
+                InputValue::Text(vec![b'x'; text_length as usize]),
+            )),
+        });
+    }
+
+    values
+}
+
+// Creates a class with a single schema consisting of the given properties.
+fn create_class_with_schema<T: Trait>(
+    lead: &T::AccountId,
+    properties: Vec<Property<T::ClassId>>,
+) -> T::ClassId {
+    let class_id = create_class::<T>(lead, BTreeSet::new());
+
+    Module::<T>::add_class_schema(
+        RawOrigin::Signed(lead.clone()).into(),
+        class_id,
+        BTreeSet::new(),
+        properties,
+    )
+    .expect("lead can add class schemas");
+
+    class_id
+}
+
+fn create_entity<T: Trait>(lead: &T::AccountId, class_id: T::ClassId) -> T::EntityId {
+    let entity_id = Module::<T>::next_entity_id();
+
+    Module::<T>::create_entity(
+        RawOrigin::Signed(lead.clone()).into(),
+        class_id,
+        Actor::Lead,
+    )
+    .expect("lead can create entities");
+
+    entity_id
+}
+
+// Creates an entity of the given class supporting its first schema with the given values.
+fn create_entity_with_values<T: Trait>(
+    lead: &T::AccountId,
+    class_id: T::ClassId,
+    values: BTreeMap<PropertyId, InputPropertyValue<T>>,
+) -> T::EntityId {
+    let entity_id = create_entity::<T>(lead, class_id);
+
+    Module::<T>::add_schema_support_to_entity(
+        RawOrigin::Signed(lead.clone()).into(),
+        Actor::Lead,
+        entity_id,
+        0,
+        values,
+    )
+    .expect("lead can add schema support");
+
+    entity_id
+}
+
+fn vector_len<T: Trait>(entity_id: T::EntityId) -> usize {
+    match Module::<T>::entity_by_id(entity_id)
+        .get_values_ref()
+        .get(&FIRST_PROPERTY_ID)
+        .and_then(|value| value.as_vec_property_value())
+        .map(|vec_value| vec_value.get_vec_value_ref())
+    {
+        Some(VecStoredValue::Text(texts)) => texts.len(),
+        _ => 0,
+    }
+}
+
+benchmarks! {
+    _ { }
+
+    add_curator_group {
+        let lead = T::create_lead();
+        let curator_group_id = Module::<T>::next_curator_group_id();
+    }: _(RawOrigin::Signed(lead))
+    verify {
+        assert!(<CuratorGroupById<T>>::contains_key(curator_group_id));
+    }
+
+    remove_curator_group {
+        let lead = T::create_lead();
+        let curator_group_id = add_curator_group::<T>(&lead);
+        add_curators_to_group::<T>(&lead, curator_group_id, T::MaxNumberOfCuratorsPerGroup::get());
+    }: _(RawOrigin::Signed(lead), curator_group_id)
+    verify {
+        assert!(!<CuratorGroupById<T>>::contains_key(curator_group_id));
+    }
+
+    set_curator_group_status {
+        let lead = T::create_lead();
+        let curator_group_id = add_curator_group::<T>(&lead);
+    }: _(RawOrigin::Signed(lead), curator_group_id, true)
+    verify {
+        assert!(Module::<T>::curator_group_by_id(curator_group_id).is_active());
+    }
+
+    add_curator_to_group {
+        let lead = T::create_lead();
+        let curator_group_id = add_curator_group::<T>(&lead);
+        let curator_id = add_curators_to_group::<T>(
+            &lead,
+            curator_group_id,
+            T::MaxNumberOfCuratorsPerGroup::get() - 1
+        );
+    }: _(RawOrigin::Signed(lead), curator_group_id, curator_id)
+    verify {
+        assert!(Module::<T>::curator_group_by_id(curator_group_id).is_curator(&curator_id));
+    }
+
+    remove_curator_from_group {
+        let lead = T::create_lead();
+        let curator_group_id = add_curator_group::<T>(&lead);
+        add_curators_to_group::<T>(&lead, curator_group_id, T::MaxNumberOfCuratorsPerGroup::get());
+        let curator_id = T::CuratorId::zero();
+    }: _(RawOrigin::Signed(lead), curator_group_id, curator_id)
+    verify {
+        assert!(!Module::<T>::curator_group_by_id(curator_group_id).is_curator(&curator_id));
+    }
+
+    update_entity_creation_voucher {
+        let lead = T::create_lead();
+        let class_id = create_class::<T>(&lead, BTreeSet::new());
+        let controller = EntityController::<T::MemberId>::Lead;
+        // Entity creation adds the voucher, so that it is updated below.
+        create_entity::<T>(&lead, class_id);
+    }: _(RawOrigin::Signed(lead), class_id, controller.clone(), T::IndividualEntitiesCreationLimit::get())
+    verify {
+        assert!(<EntityCreationVouchers<T>>::contains_key(class_id, controller));
+    }
+
+    create_class {
+        let lead = T::create_lead();
+        let maintainers = add_curator_groups::<T>(&lead, T::MaxNumberOfMaintainersPerClass::get());
+        let mut class_permissions = ClassPermissions::default();
+        class_permissions.set_maintainers(maintainers);
+        let class_id = Module::<T>::next_class_id();
+    }: _(
+        RawOrigin::Signed(lead),
+        text_of_length(0, T::ClassNameLengthConstraint::get().max()),
+        text_of_length(0, T::ClassDescriptionLengthConstraint::get().max()),
+        class_permissions,
+        T::MaxNumberOfEntitiesPerClass::get(),
+        T::IndividualEntitiesCreationLimit::get()
+    )
+    verify {
+        assert!(<ClassById<T>>::contains_key(class_id));
+    }
+
+    add_maintainer_to_class {
+        let lead = T::create_lead();
+        let maintainers = add_curator_groups::<T>(&lead, T::MaxNumberOfMaintainersPerClass::get() - 1);
+        let class_id = create_class::<T>(&lead, maintainers);
+        let curator_group_id = add_curator_group::<T>(&lead);
+    }: _(RawOrigin::Signed(lead), class_id, curator_group_id)
+    verify {
+        assert!(Module::<T>::class_by_id(class_id).get_permissions_ref().is_maintainer(&curator_group_id));
+    }
+
+    remove_maintainer_from_class {
+        let lead = T::create_lead();
+        let maintainers = add_curator_groups::<T>(&lead, T::MaxNumberOfMaintainersPerClass::get());
+        let curator_group_id = *maintainers.iter().next().expect("maintainers were added");
+        let class_id = create_class::<T>(&lead, maintainers);
+    }: _(RawOrigin::Signed(lead), class_id, curator_group_id)
+    verify {
+        assert!(!Module::<T>::class_by_id(class_id).get_permissions_ref().is_maintainer(&curator_group_id));
+    }
+
+    update_class_permissions {
+        let lead = T::create_lead();
+        let class_id = create_class::<T>(&lead, BTreeSet::new());
+        let maintainers = add_curator_groups::<T>(&lead, T::MaxNumberOfMaintainersPerClass::get());
+    }: _(RawOrigin::Signed(lead), class_id, Some(true), Some(true), Some(true), Some(maintainers.clone()))
+    verify {
+        assert_eq!(Module::<T>::class_by_id(class_id).get_permissions_ref().get_maintainers(), &maintainers);
+    }
+
+    add_class_schema {
+        let lead = T::create_lead();
+        let class_id = create_class::<T>(&lead, BTreeSet::new());
+    }: _(RawOrigin::Signed(lead), class_id, BTreeSet::new(), text_properties::<T>())
+    verify {
+        assert_eq!(Module::<T>::class_by_id(class_id).get_schemas().len(), 1);
+    }
+
+    update_class_schema_status {
+        let lead = T::create_lead();
+        let class_id = create_class_with_schema::<T>(&lead, text_properties::<T>());
+    }: _(RawOrigin::Signed(lead), class_id, 0, false)
+    verify {
+        assert!(!Module::<T>::class_by_id(class_id).get_schemas()[0].is_active());
+    }
+
+    update_entity_permissions {
+        let lead = T::create_lead();
+        let class_id = create_class::<T>(&lead, BTreeSet::new());
+        let entity_id = create_entity::<T>(&lead, class_id);
+    }: _(RawOrigin::Signed(lead), entity_id, Some(true), Some(true))
+    verify {
+        assert!(Module::<T>::entity_by_id(entity_id).get_permissions_ref().is_referancable());
+    }
+
+    transfer_entity_ownership {
+        let lead = T::create_lead();
+        let class_id = create_class_with_schema::<T>(&lead, text_properties::<T>());
+        let entity_id = create_entity_with_values::<T>(&lead, class_id, text_values::<T>(0));
+        let new_controller = EntityController::<T::MemberId>::Member(T::MemberId::default());
+    }: _(RawOrigin::Signed(lead), entity_id, new_controller.clone(), BTreeMap::new())
+    verify {
+        assert!(Module::<T>::entity_by_id(entity_id).get_permissions_ref().controller_is_equal_to(&new_controller));
+    }
+
+    create_entity {
+        let lead = T::create_lead();
+        let class_id = create_class::<T>(&lead, BTreeSet::new());
+        let entity_id = Module::<T>::next_entity_id();
+    }: _(RawOrigin::Signed(lead), class_id, Actor::Lead)
+    verify {
+        assert!(<EntityById<T>>::contains_key(entity_id));
+    }
+
+    remove_entity {
+        let lead = T::create_lead();
+        let class_id = create_class_with_schema::<T>(&lead, text_properties::<T>());
+        let entity_id = create_entity_with_values::<T>(&lead, class_id, text_values::<T>(0));
+    }: _(RawOrigin::Signed(lead), Actor::Lead, entity_id)
+    verify {
+        assert!(!<EntityById<T>>::contains_key(entity_id));
+    }
+
+    add_schema_support_to_entity {
+        let lead = T::create_lead();
+        let class_id = create_class_with_schema::<T>(&lead, text_properties::<T>());
+        let entity_id = create_entity::<T>(&lead, class_id);
+    }: _(RawOrigin::Signed(lead), Actor::Lead, entity_id, 0, text_values::<T>(0))
+    verify {
+        assert_eq!(
+            Module::<T>::entity_by_id(entity_id).get_values_ref().len(),
+            T::MaxNumberOfPropertiesPerSchema::get() as usize
+        );
+    }
+
+    update_entity_property_values {
+        let lead = T::create_lead();
+        let class_id = create_class_with_schema::<T>(&lead, text_properties::<T>());
+        let entity_id = create_entity_with_values::<T>(&lead, class_id, text_values::<T>(0));
+    }: _(RawOrigin::Signed(lead), Actor::Lead, entity_id, text_values::<T>(1))
+    verify {
+        let expected_values = Module::<T>::make_output_property_values(text_values::<T>(1));
+        assert!(Module::<T>::entity_by_id(entity_id).get_values() == expected_values);
+    }
+
+    clear_entity_property_vector {
+        let lead = T::create_lead();
+        let class_id = create_class_with_schema::<T>(&lead, text_vector_properties::<T>());
+        let values = text_vector_values::<T>(T::VecMaxLengthConstraint::get());
+        let entity_id = create_entity_with_values::<T>(&lead, class_id, values);
+    }: _(RawOrigin::Signed(lead), Actor::Lead, entity_id, FIRST_PROPERTY_ID)
+    verify {
+        assert_eq!(vector_len::<T>(entity_id), 0);
+    }
+
+    remove_at_entity_property_vector {
+        let lead = T::create_lead();
+        let class_id = create_class_with_schema::<T>(&lead, text_vector_properties::<T>());
+        let len = T::VecMaxLengthConstraint::get();
+        let entity_id = create_entity_with_values::<T>(&lead, class_id, text_vector_values::<T>(len));
+    }: _(RawOrigin::Signed(lead), Actor::Lead, entity_id, FIRST_PROPERTY_ID, 0, T::Nonce::zero())
+    verify {
+        assert_eq!(vector_len::<T>(entity_id), len as usize - 1);
+    }
+
+    insert_at_entity_property_vector {
+        let lead = T::create_lead();
+        let class_id = create_class_with_schema::<T>(&lead, text_vector_properties::<T>());
+        let len = T::VecMaxLengthConstraint::get() - 1;
+        let entity_id = create_entity_with_values::<T>(&lead, class_id, text_vector_values::<T>(len));
+        let value = InputValue::Text(text_of_length(len.into(), T::TextMaxLengthConstraint::get()));
+    }: _(RawOrigin::Signed(lead), Actor::Lead, entity_id, FIRST_PROPERTY_ID, 0, value, T::Nonce::zero())
+    verify {
+        assert_eq!(vector_len::<T>(entity_id), len as usize + 1);
+    }
+
+    transaction {
+        let c in 0 .. T::MaxNumberOfOperationsDuringAtomicBatching::get() / 3;
+        let u in 0 .. T::MaxNumberOfOperationsDuringAtomicBatching::get() / 3;
+        let a in 0 .. T::MaxNumberOfOperationsDuringAtomicBatching::get() / 3;
+        let s in 0 .. T::MaxNumberOfPropertiesPerSchema::get()
+            * u32::from(T::TextMaxLengthConstraint::get());
+
+        let lead = T::create_lead();
+        let class_id = create_class_with_schema::<T>(&lead, text_properties::<T>());
+
+        // The payload is spread evenly over the operations carrying property values.
+        let operation_payload_size = s.checked_div(u + a).unwrap_or_default();
+
+        let mut operations: Vec<OperationType<T>> = (0..c)
+            .map(|_| OperationType::CreateEntity(CreateEntityOperation { class_id }))
+            .collect();
+
+        for _ in 0..u {
+            let entity_id = create_entity_with_values::<T>(&lead, class_id, text_values::<T>(0));
+            operations.push(OperationType::UpdatePropertyValues(UpdatePropertyValuesOperation {
+                entity_id: ParameterizedEntity::ExistingEntity(entity_id),
+                new_parametrized_property_values: parametrized_text_values::<T>(
+                    operation_payload_size,
+                ),
+            }));
+        }
+
+        for _ in 0..a {
+            let entity_id = create_entity::<T>(&lead, class_id);
+            operations.push(OperationType::AddSchemaSupportToEntity(
+                AddSchemaSupportToEntityOperation {
+                    entity_id: ParameterizedEntity::ExistingEntity(entity_id),
+                    schema_id: 0,
+                    parametrized_property_values: parametrized_text_values::<T>(
+                        operation_payload_size,
+                    ),
+                },
+            ));
+        }
+
+        let entity_id = Module::<T>::next_entity_id();
+    }: _(RawOrigin::Signed(lead), Actor::Lead, operations)
+    verify {
+        assert_eq!(<EntityById<T>>::contains_key(entity_id), c > 0);
+    }
+}
@@ -118,6 +118,8 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
 mod class;
 mod entity;
 mod errors;
@@ -146,6 +148,7 @@ use frame_support::{
     dispatch::{DispatchError, DispatchResult},
     ensure,
     traits::Get,
+    weights::Weight,
     Parameter,
 };
 #[cfg(feature = "std")]
@@ -275,6 +278,108 @@ pub trait Trait: system::Trait + ActorAuthenticator + Clone {
 
     /// Entities creation constraint per individual
     type IndividualEntitiesCreationLimit: Get<Self::EntityId>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+}
+
+/// Weight functions needed for the content directory module.
+pub trait WeightInfo {
+    fn add_curator_group() -> Weight;
+    fn remove_curator_group() -> Weight;
+    fn set_curator_group_status() -> Weight;
+    fn add_curator_to_group() -> Weight;
+    fn remove_curator_from_group() -> Weight;
+    fn update_entity_creation_voucher() -> Weight;
+    fn create_class() -> Weight;
+    fn add_maintainer_to_class() -> Weight;
+    fn remove_maintainer_from_class() -> Weight;
+    fn update_class_permissions() -> Weight;
+    fn add_class_schema() -> Weight;
+    fn update_class_schema_status() -> Weight;
+    fn update_entity_permissions() -> Weight;
+    fn transfer_entity_ownership() -> Weight;
+    fn create_entity() -> Weight;
+    fn remove_entity() -> Weight;
+    fn add_schema_support_to_entity() -> Weight;
+    fn update_entity_property_values() -> Weight;
+    fn clear_entity_property_vector() -> Weight;
+    fn remove_at_entity_property_vector() -> Weight;
+    fn insert_at_entity_property_vector() -> Weight;
+    fn transaction(c: u32, u: u32, a: u32, s: u32) -> Weight;
+}
+
+/// Flat weights for the test runtimes. Batch transactions are still priced per operation and
+/// payload byte, so that the tests can check how their weight scales.
+impl WeightInfo for () {
+    fn add_curator_group() -> Weight {
+        10_000_000
+    }
+    fn remove_curator_group() -> Weight {
+        10_000_000
+    }
+    fn set_curator_group_status() -> Weight {
+        10_000_000
+    }
+    fn add_curator_to_group() -> Weight {
+        10_000_000
+    }
+    fn remove_curator_from_group() -> Weight {
+        10_000_000
+    }
+    fn update_entity_creation_voucher() -> Weight {
+        10_000_000
+    }
+    fn create_class() -> Weight {
+        10_000_000
+    }
+    fn add_maintainer_to_class() -> Weight {
+        10_000_000
+    }
+    fn remove_maintainer_from_class() -> Weight {
+        10_000_000
+    }
+    fn update_class_permissions() -> Weight {
+        10_000_000
+    }
+    fn add_class_schema() -> Weight {
+        10_000_000
+    }
+    fn update_class_schema_status() -> Weight {
+        10_000_000
+    }
+    fn update_entity_permissions() -> Weight {
+        10_000_000
+    }
+    fn transfer_entity_ownership() -> Weight {
+        10_000_000
+    }
+    fn create_entity() -> Weight {
+        10_000_000
+    }
+    fn remove_entity() -> Weight {
+        10_000_000
+    }
+    fn add_schema_support_to_entity() -> Weight {
+        10_000_000
+    }
+    fn update_entity_property_values() -> Weight {
+        10_000_000
+    }
+    fn clear_entity_property_vector() -> Weight {
+        10_000_000
+    }
+    fn remove_at_entity_property_vector() -> Weight {
+        10_000_000
+    }
+    fn insert_at_entity_property_vector() -> Weight {
+        10_000_000
+    }
+    fn transaction(c: u32, u: u32, a: u32, s: u32) -> Weight {
+        10_000_000
+            + (c.saturating_add(u).saturating_add(a) as Weight).saturating_mul(10_000_000)
+            + (s as Weight).saturating_mul(1_000)
+    }
 }
 
 decl_storage! {
@@ -321,7 +426,7 @@ decl_module! {
         // ======
 
         /// Add new curator group to runtime storage
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_curator_group()]
         pub fn add_curator_group(
             origin,
         ) -> DispatchResult {
@@ -347,7 +452,7 @@ decl_module! {
         }
 
         /// Remove curator group under given `curator_group_id` from runtime storage
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::remove_curator_group()]
         pub fn remove_curator_group(
             origin,
             curator_group_id: T::CuratorGroupId,
@@ -376,7 +481,7 @@ decl_module! {
         }
 
         /// Set `is_active` status for curator group under given `curator_group_id`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_curator_group_status()]
         pub fn set_curator_group_status(
             origin,
             curator_group_id: T::CuratorGroupId,
@@ -404,7 +509,7 @@ decl_module! {
         }
 
         /// Add curator to curator group under given `curator_group_id`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_curator_to_group()]
         pub fn add_curator_to_group(
             origin,
             curator_group_id: T::CuratorGroupId,
@@ -438,7 +543,7 @@ decl_module! {
         }
 
         /// Remove curator from a given curator group
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::remove_curator_from_group()]
         pub fn remove_curator_from_group(
             origin,
             curator_group_id: T::CuratorGroupId,
@@ -469,7 +574,7 @@ decl_module! {
         }
 
         /// Updates or creates new `EntityCreationVoucher` for given `EntityController` with individual limit
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_entity_creation_voucher()]
         pub fn update_entity_creation_voucher(
             origin,
             class_id: T::ClassId,
@@ -520,7 +625,7 @@ decl_module! {
         }
 
         /// Create new `Class` with provided parameters
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::create_class()]
         pub fn create_class(
             origin,
             name: Vec<u8>,
@@ -572,7 +677,7 @@ decl_module! {
         }
 
         /// Add curator group under given `curator_group_id` as `Class` maintainer
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_maintainer_to_class()]
         pub fn add_maintainer_to_class(
             origin,
             class_id: T::ClassId,
@@ -615,7 +720,7 @@ decl_module! {
         }
 
         /// Remove curator group under given `curator_group_id` from `Class` maintainers set
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::remove_maintainer_from_class()]
         pub fn remove_maintainer_from_class(
             origin,
             class_id: T::ClassId,
@@ -650,7 +755,7 @@ decl_module! {
         }
 
         /// Update `ClassPermissions` under specific `class_id`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_class_permissions()]
         pub fn update_class_permissions(
             origin,
             class_id: T::ClassId,
@@ -711,7 +816,7 @@ decl_module! {
         }
 
         /// Create new class schema from existing property ids and new properties
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_class_schema()]
         pub fn add_class_schema(
             origin,
             class_id: T::ClassId,
@@ -771,7 +876,7 @@ decl_module! {
         }
 
         /// Update `schema_status` under specific `schema_id` in `Class`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_class_schema_status()]
         pub fn update_class_schema_status(
             origin,
             class_id: T::ClassId,
@@ -803,7 +908,7 @@ decl_module! {
         }
 
         /// Update entity permissions
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_entity_permissions()]
         pub fn update_entity_permissions(
             origin,
             entity_id: T::EntityId,
@@ -842,7 +947,7 @@ decl_module! {
 
         /// Transfer ownership to new `EntityController` for `Entity` under given `entity_id`
         /// `new_property_value_references_with_same_owner_flag_set` should be provided manually
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::transfer_entity_ownership()]
         pub fn transfer_entity_ownership(
             origin,
             entity_id: T::EntityId,
@@ -987,7 +1092,7 @@ decl_module! {
         /// Create entity.
         /// If someone is making an entity of this class for first time,
         /// then a voucher is also added with the class limit as the default limit value.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::create_entity()]
         pub fn create_entity(
             origin,
             class_id: T::ClassId,
@@ -1072,7 +1177,7 @@ decl_module! {
         }
 
         /// Remove `Entity` under provided `entity_id`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::remove_entity()]
         pub fn remove_entity(
             origin,
             actor: Actor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -1138,7 +1243,7 @@ decl_module! {
         }
 
         /// Add schema support to entity under given `schema_id` and provided `property_values`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_schema_support_to_entity()]
         pub fn add_schema_support_to_entity(
             origin,
             actor: Actor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -1238,7 +1343,7 @@ decl_module! {
         }
 
         /// Update `Entity` `InputPropertyValue`'s with provided ones
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_entity_property_values()]
         pub fn update_entity_property_values(
             origin,
             actor: Actor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -1337,7 +1442,7 @@ decl_module! {
         }
 
         /// Clear `PropertyValueVec` under given `entity_id` & `in_class_schema_property_id`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::clear_entity_property_vector()]
         pub fn clear_entity_property_vector(
             origin,
             actor: Actor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -1417,7 +1522,7 @@ decl_module! {
 
         /// Remove value at given `index_in_property_vector`
         /// from `PropertyValueVec` under `in_class_schema_property_id`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::remove_at_entity_property_vector()]
         pub fn remove_at_entity_property_vector(
             origin,
             actor: Actor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -1521,7 +1626,7 @@ decl_module! {
 
         /// Insert `SingleInputPropertyValue` at given `index_in_property_vector`
         /// into `PropertyValueVec` under `in_class_schema_property_id`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::insert_at_entity_property_vector()]
         pub fn insert_at_entity_property_vector(
             origin,
             actor: Actor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -1630,7 +1735,7 @@ decl_module! {
         }
 
        /// Batch transaction
-       #[weight = 10_000_000] // TODO: adjust weight
+       #[weight = transaction_weight::<T>(operations)]
        pub fn transaction(origin, actor: Actor<T::CuratorGroupId, T::CuratorId, T::MemberId>, operations: Vec<OperationType<T>>) -> DispatchResult {
 
            // Ensure maximum number of operations during atomic batching limit not reached
@@ -266,6 +266,7 @@ impl Trait for Runtime {
     type TextMaxLengthConstraint = TextMaxLengthConstraint;
     type HashedTextMaxLengthConstraint = HashedTextMaxLengthConstraint;
     type IndividualEntitiesCreationLimit = IndividualEntitiesCreationLimit;
+    type WeightInfo = ();
 }
 
 impl ActorAuthenticator for Runtime {
@@ -1,5 +1,8 @@
-use crate::{Error, InputPropertyValue, InputValue, PropertyId, SchemaId, Trait, VecInputValue};
+use crate::{
+    Error, InputPropertyValue, InputValue, PropertyId, SchemaId, Trait, VecInputValue, WeightInfo,
+};
 use codec::{Decode, Encode};
+use frame_support::weights::Weight;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::prelude::*;
 
@@ -139,3 +142,43 @@ pub fn parametrized_property_values_to_property_values<T: Trait>(
 
     Ok(class_property_values)
 }
+
+/// Compute the weight of the batch `transaction`, depending on the number of operations of each
+/// type and the total encoded size of their parametrized property values.
+pub fn transaction_weight<T: Trait>(operations: &[OperationType<T>]) -> Weight {
+    let mut create_entity_operations: u32 = 0;
+    let mut update_property_values_operations: u32 = 0;
+    let mut add_schema_support_operations: u32 = 0;
+    let mut property_values_size: u32 = 0;
+
+    for operation in operations {
+        match operation {
+            OperationType::CreateEntity(_) => create_entity_operations += 1,
+            OperationType::UpdatePropertyValues(update_property_values_operation) => {
+                update_property_values_operations += 1;
+                property_values_size = property_values_size.saturating_add(
+                    update_property_values_operation
+                        .new_parametrized_property_values
+                        .encode()
+                        .len() as u32,
+                );
+            }
+            OperationType::AddSchemaSupportToEntity(add_schema_support_to_entity_operation) => {
+                add_schema_support_operations += 1;
+                property_values_size = property_values_size.saturating_add(
+                    add_schema_support_to_entity_operation
+                        .parametrized_property_values
+                        .encode()
+                        .len() as u32,
+                );
+            }
+        }
+    }
+
+    T::WeightInfo::transaction(
+        create_entity_operations,
+        update_property_values_operations,
+        add_schema_support_operations,
+        property_values_size,
+    )
+}
@@ -153,3 +153,37 @@ fn transaction_failed() {
         );
     })
 }
+
+#[test]
+fn transaction_weight_grows_with_operations_count_and_payload_size() {
+    let update_property_values_operation = |text_length: usize| {
+        OperationType::UpdatePropertyValues(UpdatePropertyValuesOperation {
+            entity_id: ParameterizedEntity::ExistingEntity(FIRST_ENTITY_ID),
+            new_parametrized_property_values: vec![ParametrizedClassPropertyValue {
+                in_class_index: 0,
+                value: ParametrizedPropertyValue::InputPropertyValue(InputPropertyValue::Single(
+                    InputValue::Text(generate_text(text_length)),
+                )),
+            }],
+        })
+    };
+
+    let create_entity_operation = OperationType::CreateEntity(CreateEntityOperation {
+        class_id: FIRST_CLASS_ID,
+    });
+
+    // Weight grows with the number of operations
+    let single_operation_weight = transaction_weight::<Runtime>(&[create_entity_operation.clone()]);
+    let multiple_operations_weight =
+        transaction_weight::<Runtime>(&vec![create_entity_operation; 10]);
+
+    assert!(multiple_operations_weight > single_operation_weight);
+
+    // Weight grows with the size of the property values payload
+    let small_payload_weight =
+        transaction_weight::<Runtime>(&[update_property_values_operation(1)]);
+    let large_payload_weight =
+        transaction_weight::<Runtime>(&[update_property_values_operation(1000)]);
+
+    assert!(large_payload_weight > small_payload_weight);
+}
@@ -2101,6 +2101,7 @@ pub fn add_member(
             0,
             Some(handle),
             None,
+            None,
             None
         )
         .unwrap(),
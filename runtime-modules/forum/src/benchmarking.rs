@@ -0,0 +1,471 @@
+//! Forum module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use sp_std::prelude::*;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+
+/// Forum users are provided by the runtime `MembershipRegistry`, so the runtime has to tell the
+/// benchmarks how to register one.
+pub trait Trait: crate::Trait {
+    /// Makes the given account a forum user and returns the forum user id.
+    fn create_forum_user(account_id: &Self::AccountId) -> Self::ForumUserId;
+}
+
+fn max_text(constraint: InputValidationLengthConstraint) -> Vec<u8> {
+    vec![b'x'; constraint.max() as usize]
+}
+
+fn create_forum_sudo<T: Trait>() -> T::AccountId {
+    let forum_sudo: T::AccountId = account("forum_sudo", 0, SEED);
+    <ForumSudo<T>>::put(forum_sudo.clone());
+    forum_sudo
+}
+
+fn create_forum_user<T: Trait>(id: u32) -> T::AccountId {
+    let forum_user: T::AccountId = account("forum_user", id, SEED);
+    T::create_forum_user(&forum_user);
+    forum_user
+}
+
+// Creates the deepest possible category path and returns the id of its leaf.
+fn create_category_path<T: Trait>(forum_sudo: &T::AccountId) -> CategoryId {
+    let mut parent = None;
+
+    for _ in 0..(MAX_CATEGORY_DEPTH - 1) {
+        let category_id = NextCategoryId::get();
+
+        Module::<T>::create_category(
+            RawOrigin::Signed(forum_sudo.clone()).into(),
+            parent,
+            max_text(Module::<T>::category_title_constraint()),
+            max_text(Module::<T>::category_description_constraint()),
+        )
+        .expect("forum sudo can create categories");
+
+        parent = Some(category_id);
+    }
+
+    parent.expect("at least one category was created")
+}
+
+fn create_thread<T: Trait>(author: &T::AccountId, category_id: CategoryId) -> T::ThreadId {
+    let thread_id = NextThreadId::<T>::get();
+
+    Module::<T>::create_thread(
+        RawOrigin::Signed(author.clone()).into(),
+        category_id,
+        max_text(Module::<T>::thread_title_constraint()),
+        max_text(Module::<T>::post_text_constraint()),
+        None,
+    )
+    .expect("forum user can create threads");
+
+    thread_id
+}
+
+fn poll_input<T: Trait>(alternatives_count: u32) -> PollInput<T::BlockNumber> {
+    PollInput {
+        question: max_text(Module::<T>::thread_title_constraint()),
+        alternatives: (0..alternatives_count)
+            .map(|_| max_text(Module::<T>::thread_title_constraint()))
+            .collect(),
+        ends_at: <system::Module<T>>::block_number() + One::one(),
+    }
+}
+
+fn create_post<T: Trait>(author: &T::AccountId, thread_id: T::ThreadId) -> T::PostId {
+    let post_id = NextPostId::<T>::get();
+
+    Module::<T>::add_post(
+        RawOrigin::Signed(author.clone()).into(),
+        thread_id,
+        max_text(Module::<T>::post_text_constraint()),
+    )
+    .expect("forum user can add posts");
+
+    post_id
+}
+
+// Ids of the categories not created by the benchmarks.
+fn other_category_ids(count: u32) -> Vec<CategoryId> {
+    (0..u64::from(count))
+        .map(|index| CategoryId::max_value() - index)
+        .collect()
+}
+
+benchmarks! {
+    _ { }
+
+    set_forum_sudo {
+        let new_forum_sudo: T::AccountId = account("forum_sudo", 0, SEED);
+    }: _(RawOrigin::Root, Some(new_forum_sudo.clone()))
+    verify {
+        assert_eq!(Module::<T>::forum_sudo(), Some(new_forum_sudo));
+    }
+
+    create_category {
+        let forum_sudo = create_forum_sudo::<T>();
+        let leaf = create_category_path::<T>(&forum_sudo);
+        let parent = <CategoryById<T>>::get(leaf)
+            .position_in_parent_category
+            .map(|position| position.parent_id);
+        let category_id = NextCategoryId::get();
+    }: _(
+        RawOrigin::Signed(forum_sudo),
+        parent,
+        max_text(Module::<T>::category_title_constraint()),
+        max_text(Module::<T>::category_description_constraint())
+    )
+    verify {
+        assert!(<CategoryById<T>>::contains_key(category_id));
+    }
+
+    update_category {
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+    }: _(RawOrigin::Signed(forum_sudo), category_id, Some(true), Some(true))
+    verify {
+        assert!(Module::<T>::category_by_id(category_id).archived);
+        assert!(Module::<T>::category_by_id(category_id).deleted);
+    }
+
+    create_thread {
+        let i in 0 .. T::MaxPollAlternatives::get();
+
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+        let author = create_forum_user::<T>(0);
+        let thread_id = NextThreadId::<T>::get();
+        // Polls need at least two alternatives.
+        let poll = if i < 2 { None } else { Some(poll_input::<T>(i)) };
+    }: _(
+        RawOrigin::Signed(author),
+        category_id,
+        max_text(Module::<T>::thread_title_constraint()),
+        max_text(Module::<T>::post_text_constraint()),
+        poll
+    )
+    verify {
+        assert!(<ThreadById<T>>::contains_key(thread_id));
+    }
+
+    vote_on_poll {
+        let i in 2 .. T::MaxPollAlternatives::get();
+
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+        let author: T::AccountId = account("forum_user", 0, SEED);
+        let forum_user_id = T::create_forum_user(&author);
+        let thread_id = NextThreadId::<T>::get();
+
+        Module::<T>::create_thread(
+            RawOrigin::Signed(author.clone()).into(),
+            category_id,
+            max_text(Module::<T>::thread_title_constraint()),
+            max_text(Module::<T>::post_text_constraint()),
+            Some(poll_input::<T>(i)),
+        )
+        .expect("forum user can create threads with polls");
+    }: _(RawOrigin::Signed(author), forum_user_id, thread_id, i - 1)
+    verify {
+        assert_eq!(Module::<T>::poll_vote(thread_id, forum_user_id), Some(i - 1));
+    }
+
+    moderate_thread {
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+        let author = create_forum_user::<T>(0);
+        let thread_id = create_thread::<T>(&author, category_id);
+        let rationale = max_text(Module::<T>::thread_moderation_rationale_constraint());
+    }: _(RawOrigin::Signed(forum_sudo), thread_id, rationale)
+    verify {
+        assert!(Module::<T>::thread_by_id(thread_id).moderation.is_some());
+    }
+
+    add_post {
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+        let author = create_forum_user::<T>(0);
+        let thread_id = create_thread::<T>(&author, category_id);
+        let post_id = NextPostId::<T>::get();
+    }: _(RawOrigin::Signed(author), thread_id, max_text(Module::<T>::post_text_constraint()))
+    verify {
+        assert!(<PostById<T>>::contains_key(post_id));
+    }
+
+    edit_post_text {
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+        let author = create_forum_user::<T>(0);
+        let thread_id = create_thread::<T>(&author, category_id);
+        let post_id = create_post::<T>(&author, thread_id);
+    }: _(RawOrigin::Signed(author), post_id, max_text(Module::<T>::post_text_constraint()))
+    verify {
+        assert_eq!(Module::<T>::post_by_id(post_id).text_change_history.len(), 1);
+    }
+
+    react_to_post {
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+        let author = create_forum_user::<T>(0);
+        let thread_id = create_thread::<T>(&author, category_id);
+        let post_id = create_post::<T>(&author, thread_id);
+
+        // Changing the reaction updates two counters.
+        Module::<T>::react_to_post(
+            RawOrigin::Signed(author.clone()).into(),
+            post_id,
+            Some(PostReaction::Like),
+        )
+        .expect("forum user can react to posts");
+    }: _(RawOrigin::Signed(author.clone()), post_id, Some(PostReaction::Dislike))
+    verify {
+        assert_eq!(Module::<T>::post_reaction(post_id, author), Some(PostReaction::Dislike));
+    }
+
+    follow_thread {
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+        let author = create_forum_user::<T>(0);
+        let thread_id = create_thread::<T>(&author, category_id);
+    }: _(RawOrigin::Signed(author.clone()), thread_id)
+    verify {
+        assert!(Module::<T>::is_thread_follower(thread_id, author));
+    }
+
+    unfollow_thread {
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+        let author = create_forum_user::<T>(0);
+        let thread_id = create_thread::<T>(&author, category_id);
+
+        Module::<T>::follow_thread(RawOrigin::Signed(author.clone()).into(), thread_id)
+            .expect("forum user can follow threads");
+    }: _(RawOrigin::Signed(author.clone()), thread_id)
+    verify {
+        assert!(!Module::<T>::is_thread_follower(thread_id, author));
+    }
+
+    edit_thread_title {
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+        let author = create_forum_user::<T>(0);
+        let thread_id = create_thread::<T>(&author, category_id);
+    }: _(RawOrigin::Signed(author), thread_id, max_text(Module::<T>::thread_title_constraint()))
+    verify {
+        assert_eq!(Module::<T>::thread_by_id(thread_id).title_change_history.len(), 1);
+    }
+
+    move_thread {
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+        let new_category_id = create_category_path::<T>(&forum_sudo);
+        let author = create_forum_user::<T>(0);
+        let thread_id = create_thread::<T>(&author, category_id);
+
+        // The moved thread is unpinned from a full sticky list.
+        let mut sticky_thread_ids = vec![thread_id];
+        for _ in 1..T::MaxStickyThreads::get() {
+            sticky_thread_ids.push(create_thread::<T>(&author, category_id));
+        }
+        <StickyThreadIds<T>>::insert(category_id, sticky_thread_ids);
+    }: _(RawOrigin::Signed(forum_sudo), thread_id, new_category_id)
+    verify {
+        assert_eq!(Module::<T>::thread_by_id(thread_id).category_id, new_category_id);
+    }
+
+    set_sticky_threads {
+        let i in 0 .. T::MaxStickyThreads::get();
+
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+        let author = create_forum_user::<T>(0);
+        let thread_ids: Vec<T::ThreadId> = (0..i)
+            .map(|_| create_thread::<T>(&author, category_id))
+            .collect();
+    }: _(RawOrigin::Signed(forum_sudo), category_id, thread_ids.clone())
+    verify {
+        assert_eq!(Module::<T>::sticky_thread_ids(category_id), thread_ids);
+    }
+
+    moderate_post {
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+        let author = create_forum_user::<T>(0);
+        let thread_id = create_thread::<T>(&author, category_id);
+        let post_id = create_post::<T>(&author, thread_id);
+        let rationale = max_text(Module::<T>::post_moderation_rationale_constraint());
+    }: _(RawOrigin::Signed(forum_sudo), post_id, rationale)
+    verify {
+        assert!(Module::<T>::post_by_id(post_id).moderation.is_some());
+    }
+
+    add_category_moderator {
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+        let moderator: T::AccountId = account("moderator", 0, SEED);
+        let moderated_category_ids = other_category_ids(T::MaxModeratedCategories::get().saturating_sub(1));
+        <ModeratedCategoryIds<T>>::insert(&moderator, moderated_category_ids);
+    }: _(RawOrigin::Signed(forum_sudo), category_id, moderator.clone())
+    verify {
+        assert!(Module::<T>::category_moderators(category_id, moderator));
+    }
+
+    remove_category_moderator {
+        let forum_sudo = create_forum_sudo::<T>();
+        let category_id = create_category_path::<T>(&forum_sudo);
+        let moderator: T::AccountId = account("moderator", 0, SEED);
+        let mut moderated_category_ids = other_category_ids(T::MaxModeratedCategories::get().saturating_sub(1));
+        moderated_category_ids.push(category_id);
+        <CategoryModerators<T>>::insert(category_id, &moderator, true);
+        <ModeratedCategoryIds<T>>::insert(&moderator, moderated_category_ids);
+    }: _(RawOrigin::Signed(forum_sudo), category_id, moderator.clone())
+    verify {
+        assert!(!Module::<T>::category_moderators(category_id, moderator));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{build_test_externalities, default_genesis_config, registry, Runtime};
+    use frame_support::assert_ok;
+
+    impl Trait for Runtime {
+        fn create_forum_user(account_id: &Self::AccountId) -> Self::ForumUserId {
+            // Every benchmark account is a separate forum user.
+            registry::TestMembershipRegistryModule::add_member(&registry::Member {
+                id: *account_id,
+                forum_user_id: *account_id,
+            });
+
+            *account_id
+        }
+    }
+
+    fn with_default_mock_builder<R, F: FnOnce() -> R>(f: F) -> R {
+        build_test_externalities(default_genesis_config()).execute_with(f)
+    }
+
+    #[test]
+    fn test_set_forum_sudo() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_set_forum_sudo::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_create_category() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_create_category::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_update_category() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_update_category::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_create_thread() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_create_thread::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_vote_on_poll() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_vote_on_poll::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_moderate_thread() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_moderate_thread::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_add_post() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_add_post::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_edit_post_text() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_edit_post_text::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_react_to_post() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_react_to_post::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_follow_thread() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_follow_thread::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_unfollow_thread() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_unfollow_thread::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_edit_thread_title() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_edit_thread_title::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_move_thread() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_move_thread::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_set_sticky_threads() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_set_sticky_threads::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_moderate_post() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_moderate_post::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_add_category_moderator() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_add_category_moderator::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn test_remove_category_moderator() {
+        with_default_mock_builder(|| {
+            assert_ok!(test_benchmark_remove_category_moderator::<Runtime>());
+        });
+    }
+}
@@ -14,13 +14,19 @@ use serde::{Deserialize, Serialize};
 pub type DispatchResult = Result<(), &'static str>;
 
 use codec::{Codec, Decode, Encode};
+use frame_support::storage::migration;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
 use frame_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
 use sp_arithmetic::traits::{BaseArithmetic, One};
 use sp_runtime::traits::{MaybeSerialize, Member};
 use sp_std::borrow::ToOwned;
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec;
 use sp_std::vec::Vec;
 
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
 mod mock;
 mod tests;
 
@@ -34,6 +40,9 @@ use common::BlockAndTime;
 /// The depth of a root category is 0.
 const MAX_CATEGORY_DEPTH: u16 = 3;
 
+// Version of the storage layout. Increased with every migration added to the `migrate()`.
+const STORAGE_VERSION: u32 = 2;
+
 /// Error messages for dispatchables
 const ERROR_FORUM_SUDO_NOT_SET: &str = "Forum sudo not set.";
 const ERROR_ORIGIN_NOT_FORUM_SUDO: &str = "Origin not forum sudo.";
@@ -61,6 +70,28 @@ const ERROR_POST_MODERATED: &str = "Post is moderated.";
 const ERROR_POST_MODERATION_RATIONALE_TOO_SHORT: &str = "Post moderation rationale too short.";
 const ERROR_POST_MODERATION_RATIONALE_TOO_LONG: &str = "Post moderation rationale too long.";
 const ERROR_CATEGORY_NOT_BEING_UPDATED: &str = "Category not being updated.";
+const ERROR_ALREADY_CATEGORY_MODERATOR: &str = "Account is already a category moderator.";
+const ERROR_NOT_CATEGORY_MODERATOR: &str = "Account is not a category moderator.";
+const ERROR_TOO_MANY_MODERATED_CATEGORIES: &str = "Account moderates too many categories.";
+const ERROR_POLL_QUESTION_TOO_SHORT: &str = "Poll question too short.";
+const ERROR_POLL_QUESTION_TOO_LONG: &str = "Poll question too long.";
+const ERROR_POLL_ALTERNATIVE_TOO_SHORT: &str = "Poll alternative too short.";
+const ERROR_POLL_ALTERNATIVE_TOO_LONG: &str = "Poll alternative too long.";
+const ERROR_POLL_TOO_FEW_ALTERNATIVES: &str = "Poll must have at least two alternatives.";
+const ERROR_POLL_TOO_MANY_ALTERNATIVES: &str = "Poll has too many alternatives.";
+const ERROR_POLL_END_IN_THE_PAST: &str = "Poll must end in the future.";
+const ERROR_THREAD_HAS_NO_POLL: &str = "Thread has no poll.";
+const ERROR_POLL_ENDED: &str = "Poll has ended.";
+const ERROR_POLL_ALTERNATIVE_DOES_NOT_EXIST: &str = "Poll alternative does not exist.";
+const ERROR_ALREADY_VOTED_ON_POLL: &str = "Forum user already voted on poll.";
+const ERROR_POST_REACTION_NOT_CHANGED: &str = "Post reaction not changed.";
+const ERROR_ALREADY_FOLLOWING_THREAD: &str = "Account already follows thread.";
+const ERROR_NOT_FOLLOWING_THREAD: &str = "Account does not follow thread.";
+const ERROR_ACCOUNT_DOES_NOT_MATCH_THREAD_AUTHOR: &str = "Account does not match thread author.";
+const ERROR_THREAD_ALREADY_IN_CATEGORY: &str = "Thread is already in the category.";
+const ERROR_TOO_MANY_STICKY_THREADS: &str = "Too many sticky threads.";
+const ERROR_STICKY_THREAD_NOT_IN_CATEGORY: &str = "Sticky thread is not in the category.";
+const ERROR_DUPLICATE_STICKY_THREADS: &str = "Sticky thread ids are not unique.";
 const ERROR_CATEGORY_CANNOT_BE_UNARCHIVED_WHEN_DELETED: &str =
     "Category cannot be unarchived when deleted.";
 
@@ -77,8 +108,11 @@ pub struct ForumUser<AccountId> {
 }
 
 /// Represents a regsitry of `ForumUser` instances.
-pub trait ForumUserRegistry<AccountId> {
+pub trait ForumUserRegistry<AccountId, ForumUserId> {
     fn get_forum_user(id: &AccountId) -> Option<ForumUser<AccountId>>;
+
+    /// Whether the account is allowed to act on behalf of the forum user with given id.
+    fn is_forum_user_account(forum_user_id: &ForumUserId, account_id: &AccountId) -> bool;
 }
 
 /// Represents a moderation outcome applied to a post or a thread.
@@ -106,6 +140,17 @@ pub struct PostTextChange<BlockNumber, Moment> {
     text: Vec<u8>,
 }
 
+/// Represents a revision of the title of a Thread
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ThreadTitleChange<BlockNumber, Moment> {
+    /// When this expiration occured
+    expired_at: BlockAndTime<BlockNumber, Moment>,
+
+    /// Title that expired
+    title: Vec<u8>,
+}
+
 /// Represents a thread post
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
@@ -139,6 +184,76 @@ pub struct Post<BlockNumber, Moment, AccountId, ThreadId, PostId> {
     author_id: AccountId,
 }
 
+/// Represents a reaction of a forum user to a post
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq)]
+pub enum PostReaction {
+    Like,
+    Dislike,
+    Love,
+    Laugh,
+}
+
+/// Represents the number of reactions of each kind to a post
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct PostReactionCounts {
+    pub likes: u32,
+    pub dislikes: u32,
+    pub loves: u32,
+    pub laughs: u32,
+}
+
+impl PostReactionCounts {
+    fn count_mut(&mut self, reaction: PostReaction) -> &mut u32 {
+        match reaction {
+            PostReaction::Like => &mut self.likes,
+            PostReaction::Dislike => &mut self.dislikes,
+            PostReaction::Love => &mut self.loves,
+            PostReaction::Laugh => &mut self.laughs,
+        }
+    }
+}
+
+/// Represents an alternative of a thread poll, along with its tally.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct PollAlternative {
+    /// Text of the alternative
+    pub text: Vec<u8>,
+
+    /// Number of votes cast for the alternative
+    pub vote_count: u32,
+}
+
+/// Represents a poll attached to a thread
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct Poll<BlockNumber> {
+    /// Question asked by the poll
+    pub question: Vec<u8>,
+
+    /// Alternatives to vote on, in the order given by the thread author
+    pub alternatives: Vec<PollAlternative>,
+
+    /// Block at which the voting ends
+    pub ends_at: BlockNumber,
+}
+
+/// Represents the poll to attach to a new thread
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct PollInput<BlockNumber> {
+    /// Question asked by the poll
+    pub question: Vec<u8>,
+
+    /// Texts of the alternatives to vote on
+    pub alternatives: Vec<Vec<u8>>,
+
+    /// Block at which the voting ends
+    pub ends_at: BlockNumber,
+}
+
 /// Represents a thread
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
@@ -153,7 +268,7 @@ pub struct Thread<BlockNumber, Moment, AccountId, ThreadId> {
     category_id: CategoryId,
 
     /// The thread number of this thread in its category, i.e. total number of thread added (including this)
-    /// to a category when it was added.
+    /// to a category when it was added, or moved into it.
     /// Is needed to give light clients assurance about getting all threads in a given range,
     /// `created_at` is not sufficient.
     /// Starts at 1 for first thread in category.
@@ -180,6 +295,12 @@ pub struct Thread<BlockNumber, Moment, AccountId, ThreadId> {
 
     /// Author of post.
     author_id: AccountId,
+
+    /// Poll attached to the thread, if any.
+    poll: Option<Poll<BlockNumber>>,
+
+    /// Edits of title ordered chronologically by edit time.
+    title_change_history: Vec<ThreadTitleChange<BlockNumber, Moment>>,
 }
 
 impl<BlockNumber, Moment, AccountId, ThreadId> Thread<BlockNumber, Moment, AccountId, ThreadId> {
@@ -188,6 +309,41 @@ impl<BlockNumber, Moment, AccountId, ThreadId> Thread<BlockNumber, Moment, Accou
     }
 }
 
+/// Thread as stored before the thread polls and title edits were introduced.
+#[derive(Decode)]
+struct LegacyThread<BlockNumber, Moment, AccountId, ThreadId> {
+    id: ThreadId,
+    title: Vec<u8>,
+    category_id: CategoryId,
+    nr_in_category: u32,
+    moderation: Option<ModerationAction<BlockNumber, Moment, AccountId>>,
+    num_unmoderated_posts: u32,
+    num_moderated_posts: u32,
+    created_at: BlockAndTime<BlockNumber, Moment>,
+    author_id: AccountId,
+}
+
+impl<BlockNumber, Moment, AccountId, ThreadId>
+    From<LegacyThread<BlockNumber, Moment, AccountId, ThreadId>>
+    for Thread<BlockNumber, Moment, AccountId, ThreadId>
+{
+    fn from(thread: LegacyThread<BlockNumber, Moment, AccountId, ThreadId>) -> Self {
+        Thread {
+            id: thread.id,
+            title: thread.title,
+            category_id: thread.category_id,
+            nr_in_category: thread.nr_in_category,
+            moderation: thread.moderation,
+            num_unmoderated_posts: thread.num_unmoderated_posts,
+            num_moderated_posts: thread.num_moderated_posts,
+            created_at: thread.created_at,
+            author_id: thread.author_id,
+            poll: None,
+            title_change_history: vec![],
+        }
+    }
+}
+
 /// Represents a category identifier
 pub type CategoryId = u64;
 
@@ -248,11 +404,55 @@ pub struct Category<BlockNumber, Moment, AccountId> {
 
     /// Account of the moderator which created category.
     moderator_id: AccountId,
+
+    /// Number of threads moved out of this category. When a thread is moved,
+    /// this is incremented and `num_direct_unmoderated_threads` decremented,
+    /// so that `nr_in_category` of the threads added later remains unique.
+    num_direct_moved_threads: u32,
 }
 
 impl<BlockNumber, Moment, AccountId> Category<BlockNumber, Moment, AccountId> {
     fn num_threads_created(&self) -> u32 {
-        self.num_direct_unmoderated_threads + self.num_direct_moderated_threads
+        self.num_direct_unmoderated_threads
+            + self.num_direct_moderated_threads
+            + self.num_direct_moved_threads
+    }
+}
+
+/// Category as stored before the thread moves were introduced.
+#[derive(Decode)]
+struct LegacyCategory<BlockNumber, Moment, AccountId> {
+    id: CategoryId,
+    title: Vec<u8>,
+    description: Vec<u8>,
+    created_at: BlockAndTime<BlockNumber, Moment>,
+    deleted: bool,
+    archived: bool,
+    num_direct_subcategories: u32,
+    num_direct_unmoderated_threads: u32,
+    num_direct_moderated_threads: u32,
+    position_in_parent_category: Option<ChildPositionInParentCategory>,
+    moderator_id: AccountId,
+}
+
+impl<BlockNumber, Moment, AccountId> From<LegacyCategory<BlockNumber, Moment, AccountId>>
+    for Category<BlockNumber, Moment, AccountId>
+{
+    fn from(category: LegacyCategory<BlockNumber, Moment, AccountId>) -> Self {
+        Category {
+            id: category.id,
+            title: category.title,
+            description: category.description,
+            created_at: category.created_at,
+            deleted: category.deleted,
+            archived: category.archived,
+            num_direct_subcategories: category.num_direct_subcategories,
+            num_direct_unmoderated_threads: category.num_direct_unmoderated_threads,
+            num_direct_moderated_threads: category.num_direct_moderated_threads,
+            position_in_parent_category: category.position_in_parent_category,
+            moderator_id: category.moderator_id,
+            num_direct_moved_threads: 0,
+        }
     }
 }
 
@@ -264,7 +464,17 @@ type CategoryTreePath<BlockNumber, Moment, AccountId> =
 pub trait Trait: system::Trait + pallet_timestamp::Trait + Sized {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
-    type MembershipRegistry: ForumUserRegistry<Self::AccountId>;
+    type MembershipRegistry: ForumUserRegistry<Self::AccountId, Self::ForumUserId>;
+
+    /// Forum user Id type, several accounts may act on behalf of the same forum user
+    type ForumUserId: Parameter
+        + Member
+        + BaseArithmetic
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerialize
+        + PartialEq;
 
     /// Thread Id type
     type ThreadId: Parameter
@@ -285,6 +495,117 @@ pub trait Trait: system::Trait + pallet_timestamp::Trait + Sized {
         + Copy
         + MaybeSerialize
         + PartialEq;
+
+    /// Maximum number of alternatives of a thread poll.
+    type MaxPollAlternatives: Get<u32>;
+
+    /// Maximum number of sticky threads of a category.
+    type MaxStickyThreads: Get<u32>;
+
+    /// Maximum number of categories moderated by a single account.
+    type MaxModeratedCategories: Get<u32>;
+
+    /// Grants forum roles to accounts managed outside of the forum, e.g. by a working group.
+    type ForumRolesValidator: ForumRolesValidator<Self::AccountId>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+}
+
+/// Grants forum roles to accounts managed outside of the forum module.
+pub trait ForumRolesValidator<AccountId> {
+    /// Whether the account has forum sudo authority, alongside the `ForumSudo` account.
+    fn is_forum_sudo(account_id: &AccountId) -> bool;
+
+    /// Whether the account moderates every category of the forum.
+    fn is_forum_moderator(account_id: &AccountId) -> bool;
+}
+
+/// Only the `ForumSudo` account and the category moderators have forum roles.
+impl<AccountId> ForumRolesValidator<AccountId> for () {
+    fn is_forum_sudo(_account_id: &AccountId) -> bool {
+        false
+    }
+
+    fn is_forum_moderator(_account_id: &AccountId) -> bool {
+        false
+    }
+}
+
+/// Weight functions needed for the forum module.
+pub trait WeightInfo {
+    fn set_forum_sudo() -> Weight;
+    fn create_category() -> Weight;
+    fn update_category() -> Weight;
+    fn create_thread(i: u32) -> Weight;
+    fn moderate_thread() -> Weight;
+    fn add_post() -> Weight;
+    fn edit_post_text() -> Weight;
+    fn moderate_post() -> Weight;
+    fn add_category_moderator() -> Weight;
+    fn remove_category_moderator() -> Weight;
+    fn vote_on_poll(i: u32) -> Weight;
+    fn react_to_post() -> Weight;
+    fn follow_thread() -> Weight;
+    fn unfollow_thread() -> Weight;
+    fn edit_thread_title() -> Weight;
+    fn move_thread() -> Weight;
+    fn set_sticky_threads(i: u32) -> Weight;
+}
+
+/// Flat weights for the test runtimes.
+impl WeightInfo for () {
+    fn set_forum_sudo() -> Weight {
+        10_000_000
+    }
+    fn create_category() -> Weight {
+        10_000_000
+    }
+    fn update_category() -> Weight {
+        10_000_000
+    }
+    fn create_thread(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn moderate_thread() -> Weight {
+        10_000_000
+    }
+    fn add_post() -> Weight {
+        10_000_000
+    }
+    fn edit_post_text() -> Weight {
+        10_000_000
+    }
+    fn moderate_post() -> Weight {
+        10_000_000
+    }
+    fn add_category_moderator() -> Weight {
+        10_000_000
+    }
+    fn remove_category_moderator() -> Weight {
+        10_000_000
+    }
+    fn vote_on_poll(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn react_to_post() -> Weight {
+        10_000_000
+    }
+    fn follow_thread() -> Weight {
+        10_000_000
+    }
+    fn unfollow_thread() -> Weight {
+        10_000_000
+    }
+    fn edit_thread_title() -> Weight {
+        10_000_000
+    }
+    fn move_thread() -> Weight {
+        10_000_000
+    }
+    fn set_sticky_threads(_i: u32) -> Weight {
+        10_000_000
+    }
 }
 
 decl_storage! {
@@ -314,6 +635,38 @@ decl_storage! {
         /// Account of forum sudo.
         pub ForumSudo get(fn forum_sudo) config(): Option<T::AccountId>;
 
+        /// Accounts moderating a category, along with all of its descendants.
+        pub CategoryModerators get(fn category_moderators): double_map hasher(blake2_128_concat)
+            CategoryId, hasher(blake2_128_concat) T::AccountId => bool;
+
+        /// Categories moderated by the account.
+        pub ModeratedCategoryIds get(fn moderated_category_ids): map hasher(blake2_128_concat)
+            T::AccountId => Vec<CategoryId>;
+
+        /// Index of the poll alternative voted for, by the thread and the voting forum user.
+        pub PollVotes get(fn poll_vote): double_map hasher(blake2_128_concat)
+            T::ThreadId, hasher(blake2_128_concat) T::ForumUserId => Option<u32>;
+
+        /// Reaction to the post, by the post and the reacting account.
+        pub PostReactions get(fn post_reaction): double_map hasher(blake2_128_concat)
+            T::PostId, hasher(blake2_128_concat) T::AccountId => Option<PostReaction>;
+
+        /// Number of reactions of each kind to the post.
+        pub PostReactionCountsByPostId get(fn post_reaction_counts): map hasher(blake2_128_concat)
+            T::PostId => PostReactionCounts;
+
+        /// Accounts following the thread.
+        pub ThreadFollowers get(fn is_thread_follower): double_map hasher(blake2_128_concat)
+            T::ThreadId, hasher(blake2_128_concat) T::AccountId => bool;
+
+        /// Number of accounts following the thread.
+        pub ThreadFollowersCount get(fn thread_followers_count): map hasher(blake2_128_concat)
+            T::ThreadId => u32;
+
+        /// Threads pinned to the top of the category, in display order.
+        pub StickyThreadIds get(fn sticky_thread_ids): map hasher(blake2_128_concat)
+            CategoryId => Vec<T::ThreadId>;
+
         /// Input constraints
         /// These are all forward looking, that is they are enforced on all
         /// future calls.
@@ -323,6 +676,9 @@ decl_storage! {
         pub PostTextConstraint get(fn post_text_constraint) config(): InputValidationLengthConstraint;
         pub ThreadModerationRationaleConstraint get(fn thread_moderation_rationale_constraint) config(): InputValidationLengthConstraint;
         pub PostModerationRationaleConstraint get(fn post_moderation_rationale_constraint) config(): InputValidationLengthConstraint;
+
+        /// Version of the storage layout. New chains start with the current version.
+        pub StorageVersion get(fn storage_version) build(|_: &GenesisConfig<T>| STORAGE_VERSION): u32;
     }
 }
 
@@ -332,6 +688,7 @@ decl_event!(
         <T as system::Trait>::AccountId,
         <T as Trait>::ThreadId,
         <T as Trait>::PostId,
+        <T as Trait>::ForumUserId,
     {
         /// A category was introduced
         CategoryCreated(CategoryId),
@@ -359,6 +716,41 @@ decl_event!(
 
         /// Given account was set as forum sudo.
         ForumSudoSet(Option<AccountId>, Option<AccountId>),
+
+        /// Given account was made a moderator of the category with given id.
+        CategoryModeratorAdded(CategoryId, AccountId),
+
+        /// Given account is no longer a moderator of the category with given id.
+        CategoryModeratorRemoved(CategoryId, AccountId),
+
+        /// Given forum user voted on the poll of the thread with given id.
+        /// The third argument is the index of the chosen alternative.
+        PollVoted(ThreadId, ForumUserId, u32),
+
+        /// Given account reacted to the post with given id.
+        /// The third argument is the new reaction, none if the reaction was withdrawn.
+        PostReacted(PostId, AccountId, Option<PostReaction>),
+
+        /// Given account started following the thread with given id.
+        ThreadFollowed(ThreadId, AccountId),
+
+        /// Given account stopped following the thread with given id.
+        ThreadUnfollowed(ThreadId, AccountId),
+
+        /// Post with given id was added to the thread with given id, which has followers.
+        /// The third argument is the number of the thread followers.
+        FollowedThreadPostAdded(ThreadId, PostId, u32),
+
+        /// Thread with given id had its title updated.
+        /// The second argument reflects the number of total edits when the title update occurs.
+        ThreadTitleUpdated(ThreadId, u64),
+
+        /// Thread with given id was moved from the category with the second id
+        /// to the category with the third id.
+        ThreadMoved(ThreadId, CategoryId, CategoryId),
+
+        /// The sticky threads of the category with given id were replaced.
+        CategoryStickyThreadsUpdated(CategoryId, Vec<ThreadId>),
     }
 );
 
@@ -367,8 +759,17 @@ decl_module! {
 
         fn deposit_event() = default;
 
+        /// Maximum number of alternatives of a thread poll.
+        const MaxPollAlternatives: u32 = T::MaxPollAlternatives::get();
+
+        /// Maximum number of sticky threads of a category.
+        const MaxStickyThreads: u32 = T::MaxStickyThreads::get();
+
+        /// Maximum number of categories moderated by a single account.
+        const MaxModeratedCategories: u32 = T::MaxModeratedCategories::get();
+
         /// Set forum sudo.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_forum_sudo()]
         fn set_forum_sudo(origin, new_forum_sudo: Option<T::AccountId>) -> DispatchResult {
             ensure_root(origin)?;
 
@@ -394,14 +795,20 @@ decl_module! {
         }
 
         /// Add a new category.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::create_category()]
         fn create_category(origin, parent: Option<CategoryId>, title: Vec<u8>, description: Vec<u8>) -> DispatchResult {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Not signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
+            // Root categories are created by forum SUDO only, subcategories also by
+            // moderators of the parent category
+            match parent {
+                Some(parent_category_id) => {
+                    Self::ensure_is_forum_sudo_or_category_moderator(&who, parent_category_id)?
+                }
+                None => Self::ensure_is_forum_sudo(&who)?,
+            }
 
             // Validate title
             Self::ensure_category_title_is_valid(&title)?;
@@ -458,7 +865,8 @@ decl_module! {
                 num_direct_unmoderated_threads: 0,
                 num_direct_moderated_threads: 0,
                 position_in_parent_category: position_in_parent_category_field,
-                moderator_id: who
+                moderator_id: who,
+                num_direct_moved_threads: 0,
             };
 
             // Insert category in map
@@ -474,14 +882,14 @@ decl_module! {
         }
 
         /// Update category
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_category()]
         fn update_category(origin, category_id: CategoryId, new_archival_status: Option<bool>, new_deletion_status: Option<bool>) -> DispatchResult {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Not signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
+            // Signed by forum SUDO or a moderator of the category
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, category_id)?;
 
             // Make sure something is actually being changed
             ensure!(
@@ -537,9 +945,17 @@ decl_module! {
             Ok(())
         }
 
-        /// Create new thread in category
-        #[weight = 10_000_000] // TODO: adjust weight
-        fn create_thread(origin, category_id: CategoryId, title: Vec<u8>, text: Vec<u8>) -> DispatchResult {
+        /// Create new thread in category, optionally with a poll
+        #[weight = T::WeightInfo::create_thread(
+            poll.as_ref().map_or(0, |poll| poll.alternatives.len() as u32)
+        )]
+        fn create_thread(
+            origin,
+            category_id: CategoryId,
+            title: Vec<u8>,
+            text: Vec<u8>,
+            poll: Option<PollInput<T::BlockNumber>>
+        ) -> DispatchResult {
 
             /*
              * Update SPEC with new errors,
@@ -565,12 +981,17 @@ decl_module! {
             // Validate post text
             Self::ensure_post_text_is_valid(&text)?;
 
+            // Validate poll
+            if let Some(ref poll) = poll {
+                Self::ensure_poll_is_valid(poll)?;
+            }
+
             /*
              * Here it is safe to mutate state.
              */
 
             // Add thread
-            let thread = Self::add_new_thread(category_id, &title, &who);
+            let thread = Self::add_new_thread(category_id, &title, &who, poll);
 
             // Add inital post to thread
             Self::add_new_post(thread.id, &text, &who);
@@ -581,19 +1002,73 @@ decl_module! {
             Ok(())
         }
 
+        /// Vote on the poll of a thread
+        #[weight = T::WeightInfo::vote_on_poll(T::MaxPollAlternatives::get())]
+        fn vote_on_poll(
+            origin,
+            forum_user_id: T::ForumUserId,
+            thread_id: T::ThreadId,
+            alternative_index: u32
+        ) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Check that account acts on behalf of the forum user
+            Self::ensure_is_forum_user_account(&forum_user_id, &who)?;
+
+            // Make sure thread exists and is mutable
+            let mut thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            // Thread has a poll which is still open
+            let poll = thread.poll.as_mut().ok_or(ERROR_THREAD_HAS_NO_POLL)?;
+
+            ensure!(
+                <system::Module<T>>::block_number() < poll.ends_at,
+                ERROR_POLL_ENDED
+            );
+
+            // One vote per forum user, whichever of its accounts signed
+            ensure!(
+                !<PollVotes<T>>::contains_key(thread_id, forum_user_id),
+                ERROR_ALREADY_VOTED_ON_POLL
+            );
+
+            let alternative = poll
+                .alternatives
+                .get_mut(alternative_index as usize)
+                .ok_or(ERROR_POLL_ALTERNATIVE_DOES_NOT_EXIST)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            // Update the tally
+            alternative.vote_count += 1;
+
+            <ThreadById<T>>::insert(thread_id, thread);
+
+            <PollVotes<T>>::insert(thread_id, forum_user_id, alternative_index);
+
+            // Generate event
+            Self::deposit_event(RawEvent::PollVoted(thread_id, forum_user_id, alternative_index));
+
+            Ok(())
+        }
+
         /// Moderate thread
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::moderate_thread()]
         fn moderate_thread(origin, thread_id: T::ThreadId, rationale: Vec<u8>) -> DispatchResult {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
-
             // Get thread
             let mut thread = Self::ensure_thread_exists(thread_id)?;
 
+            // Signed by forum SUDO or a moderator of the thread category
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, thread.category_id)?;
+
             // Thread is not already moderated
             ensure!(thread.moderation.is_none(), ERROR_THREAD_ALREADY_MODERATED);
 
@@ -627,14 +1102,166 @@ decl_module! {
                 category.num_direct_moderated_threads += 1;
             });
 
+            // Moderated threads are no longer sticky
+            Self::remove_sticky_thread(thread.category_id, thread_id);
+
             // Generate event
             Self::deposit_event(RawEvent::ThreadModerated(thread_id));
 
             Ok(())
         }
 
+        /// Edit thread title
+        #[weight = T::WeightInfo::edit_thread_title()]
+        fn edit_thread_title(origin, thread_id: T::ThreadId, new_title: Vec<u8>) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Check that account is forum member
+            Self::ensure_is_forum_member(&who)?;
+
+            // Validate title
+            Self::ensure_thread_title_is_valid(&new_title)?;
+
+            // Make sure thread exists and is mutable
+            let mut thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            // Signer does not match creator of thread with identifier threadId
+            ensure!(thread.author_id == who, ERROR_ACCOUNT_DOES_NOT_MATCH_THREAD_AUTHOR);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            let expired_thread_title = ThreadTitleChange {
+                expired_at: common::current_block_time::<T>(),
+                title: thread.title.clone()
+            };
+
+            // Set current title to new title
+            thread.title = new_title;
+
+            // Copy current title to history of expired titles
+            thread.title_change_history.push(expired_thread_title);
+
+            let title_edits_count = thread.title_change_history.len() as u64;
+
+            <ThreadById<T>>::insert(thread_id, thread);
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadTitleUpdated(thread_id, title_edits_count));
+
+            Ok(())
+        }
+
+        /// Move thread to another category
+        #[weight = T::WeightInfo::move_thread()]
+        fn move_thread(origin, thread_id: T::ThreadId, new_category_id: CategoryId) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            // Thread is actually being moved
+            ensure!(thread.category_id != new_category_id, ERROR_THREAD_ALREADY_IN_CATEGORY);
+
+            // Signed by forum SUDO or a moderator of both categories
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, thread.category_id)?;
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, new_category_id)?;
+
+            // Get path from new category to root of category tree.
+            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(new_category_id)?;
+
+            // No ancestor is blocking us doing mutation in the new category
+            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+
+            // The new category is the leaf of its path
+            let new_category = category_tree_path.first().ok_or(ERROR_CATEGORY_DOES_NOT_EXIST)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            let old_category_id = thread.category_id;
+
+            // Thread leaves the old category, without freeing its number there
+            <CategoryById<T>>::mutate(old_category_id, |c| {
+                c.num_direct_unmoderated_threads -= 1;
+                c.num_direct_moved_threads += 1;
+            });
+
+            Self::remove_sticky_thread(old_category_id, thread_id);
+
+            // Thread is numbered in the new category as if it was added there
+            <ThreadById<T>>::mutate(thread_id, |t| {
+                t.category_id = new_category_id;
+                t.nr_in_category = new_category.num_threads_created() + 1;
+            });
+
+            <CategoryById<T>>::mutate(new_category_id, |c| {
+                c.num_direct_unmoderated_threads += 1;
+            });
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadMoved(thread_id, old_category_id, new_category_id));
+
+            Ok(())
+        }
+
+        /// Replace the sticky threads of the category
+        #[weight = T::WeightInfo::set_sticky_threads(thread_ids.len() as u32)]
+        fn set_sticky_threads(origin, category_id: CategoryId, thread_ids: Vec<T::ThreadId>) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Signed by forum SUDO or a moderator of the category
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, category_id)?;
+
+            // Get path from category to root of category tree.
+            let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(category_id)?;
+
+            // No ancestor is blocking us doing mutation in this category
+            Self::ensure_can_mutate_in_path_leaf(&category_tree_path)?;
+
+            ensure!(
+                thread_ids.len() <= T::MaxStickyThreads::get() as usize,
+                ERROR_TOO_MANY_STICKY_THREADS
+            );
+
+            let unique_thread_ids: BTreeSet<_> = thread_ids.iter().collect();
+            ensure!(unique_thread_ids.len() == thread_ids.len(), ERROR_DUPLICATE_STICKY_THREADS);
+
+            // Only unmoderated threads of the category can be sticky
+            for thread_id in thread_ids.iter() {
+                let thread = Self::ensure_thread_exists(*thread_id)?;
+
+                ensure!(thread.category_id == category_id, ERROR_STICKY_THREAD_NOT_IN_CATEGORY);
+
+                ensure!(thread.moderation.is_none(), ERROR_THREAD_MODERATED);
+            }
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            if thread_ids.is_empty() {
+                <StickyThreadIds<T>>::remove(category_id);
+            } else {
+                <StickyThreadIds<T>>::insert(category_id, thread_ids.clone());
+            }
+
+            // Generate event
+            Self::deposit_event(RawEvent::CategoryStickyThreadsUpdated(category_id, thread_ids));
+
+            Ok(())
+        }
+
         /// Edit post text
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_post()]
         fn add_post(origin, thread_id: T::ThreadId, text: Vec<u8>) -> DispatchResult {
 
             /*
@@ -668,11 +1295,118 @@ decl_module! {
             // Generate event
             Self::deposit_event(RawEvent::PostAdded(post.id));
 
+            // Notify the thread followers
+            let followers_count = Self::thread_followers_count(thread_id);
+
+            if followers_count > 0 {
+                Self::deposit_event(RawEvent::FollowedThreadPostAdded(thread_id, post.id, followers_count));
+            }
+
+            Ok(())
+        }
+
+        /// React to a post, or withdraw the reaction
+        #[weight = T::WeightInfo::react_to_post()]
+        fn react_to_post(origin, post_id: T::PostId, reaction: Option<PostReaction>) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Check that account is forum member
+            Self::ensure_is_forum_member(&who)?;
+
+            // Make sure post exists and is mutable
+            Self::ensure_post_is_mutable(post_id)?;
+
+            let old_reaction = <PostReactions<T>>::get(post_id, &who);
+
+            ensure!(old_reaction != reaction, ERROR_POST_REACTION_NOT_CHANGED);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            // Update the reaction counters
+            <PostReactionCountsByPostId<T>>::mutate(post_id, |counts| {
+                if let Some(old_reaction) = old_reaction {
+                    *counts.count_mut(old_reaction) -= 1;
+                }
+
+                if let Some(reaction) = reaction {
+                    *counts.count_mut(reaction) += 1;
+                }
+            });
+
+            match reaction {
+                Some(reaction) => <PostReactions<T>>::insert(post_id, &who, reaction),
+                None => <PostReactions<T>>::remove(post_id, &who),
+            }
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostReacted(post_id, who, reaction));
+
+            Ok(())
+        }
+
+        /// Follow a thread to get notified about its new posts
+        #[weight = T::WeightInfo::follow_thread()]
+        fn follow_thread(origin, thread_id: T::ThreadId) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Check that account is forum member
+            Self::ensure_is_forum_member(&who)?;
+
+            Self::ensure_thread_exists(thread_id)?;
+
+            ensure!(
+                !<ThreadFollowers<T>>::get(thread_id, &who),
+                ERROR_ALREADY_FOLLOWING_THREAD
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <ThreadFollowers<T>>::insert(thread_id, &who, true);
+
+            <ThreadFollowersCount<T>>::mutate(thread_id, |count| *count += 1);
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadFollowed(thread_id, who));
+
+            Ok(())
+        }
+
+        /// Stop following a thread
+        #[weight = T::WeightInfo::unfollow_thread()]
+        fn unfollow_thread(origin, thread_id: T::ThreadId) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                <ThreadFollowers<T>>::get(thread_id, &who),
+                ERROR_NOT_FOLLOWING_THREAD
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <ThreadFollowers<T>>::remove(thread_id, &who);
+
+            <ThreadFollowersCount<T>>::mutate(thread_id, |count| *count -= 1);
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadUnfollowed(thread_id, who));
+
             Ok(())
         }
 
         /// Edit post text
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::edit_post_text()]
         fn edit_post_text(origin, post_id: T::PostId, new_text: Vec<u8>) -> DispatchResult {
 
             /* Edit spec.
@@ -720,18 +1454,19 @@ decl_module! {
         }
 
         /// Moderate post
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::moderate_post()]
         fn moderate_post(origin, post_id: T::PostId, rationale: Vec<u8>) -> DispatchResult {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
-
             // Make sure post exists and is mutable
             let post = Self::ensure_post_is_mutable(post_id)?;
 
+            // Signed by forum SUDO or a moderator of the post category
+            let thread = <ThreadById<T>>::get(post.thread_id);
+            Self::ensure_is_forum_sudo_or_category_moderator(&who, thread.category_id)?;
+
             Self::ensure_post_moderation_rationale_is_valid(&rationale)?;
 
             /*
@@ -761,10 +1496,133 @@ decl_module! {
             Ok(())
         }
 
+        /// Make an account a moderator of the category and all of its descendants.
+        #[weight = T::WeightInfo::add_category_moderator()]
+        fn add_category_moderator(origin, category_id: CategoryId, account_id: T::AccountId) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Signed by forum SUDO
+            Self::ensure_is_forum_sudo(&who)?;
+
+            Self::ensure_valid_category_and_build_category_tree_path(category_id)?;
+
+            ensure!(
+                !<CategoryModerators<T>>::get(category_id, &account_id),
+                ERROR_ALREADY_CATEGORY_MODERATOR
+            );
+
+            ensure!(
+                Self::moderated_category_ids(&account_id).len() < T::MaxModeratedCategories::get() as usize,
+                ERROR_TOO_MANY_MODERATED_CATEGORIES
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <CategoryModerators<T>>::insert(category_id, &account_id, true);
+            <ModeratedCategoryIds<T>>::mutate(&account_id, |ids| ids.push(category_id));
+
+            // Generate event
+            Self::deposit_event(RawEvent::CategoryModeratorAdded(category_id, account_id));
+
+            Ok(())
+        }
+
+        /// Revoke moderation rights of an account over the category.
+        #[weight = T::WeightInfo::remove_category_moderator()]
+        fn remove_category_moderator(origin, category_id: CategoryId, account_id: T::AccountId) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Signed by forum SUDO
+            Self::ensure_is_forum_sudo(&who)?;
+
+            ensure!(
+                <CategoryModerators<T>>::get(category_id, &account_id),
+                ERROR_NOT_CATEGORY_MODERATOR
+            );
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            <CategoryModerators<T>>::remove(category_id, &account_id);
+            <ModeratedCategoryIds<T>>::mutate(&account_id, |ids| ids.retain(|id| *id != category_id));
+
+            // Generate event
+            Self::deposit_event(RawEvent::CategoryModeratorRemoved(category_id, account_id));
+
+            Ok(())
+        }
+
     }
 }
 
 impl<T: Trait> Module<T> {
+    /// Migrates the storage to the current layout version. Runs only the migrations
+    /// the storage has not passed yet. Returns the weight of the migration.
+    pub fn migrate() -> Weight {
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+        if Self::storage_version() < 1 {
+            weight = weight.saturating_add(Self::migrate_threads());
+        }
+
+        if Self::storage_version() < 2 {
+            weight = weight.saturating_add(Self::migrate_categories());
+        }
+
+        <StorageVersion>::put(STORAGE_VERSION);
+
+        weight
+    }
+
+    // Re-encodes the threads stored before the thread polls were introduced. The forum holds
+    // a few thousand threads at most, so all of them are migrated at once in the upgrade block.
+    fn migrate_threads() -> Weight {
+        let legacy_threads = migration::StorageIterator::<
+            LegacyThread<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId>,
+        >::new(b"Forum", b"ThreadById");
+
+        let mut migrated_threads_count: Weight = 0;
+
+        for (hashed_key, legacy_thread) in legacy_threads {
+            let thread: Thread<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId> =
+                legacy_thread.into();
+
+            migration::put_storage_value(b"Forum", b"ThreadById", &hashed_key, thread);
+
+            migrated_threads_count += 1;
+        }
+
+        T::DbWeight::get().reads_writes(migrated_threads_count, migrated_threads_count)
+    }
+
+    // Re-encodes the categories stored before the thread moves were introduced. Categories are
+    // created by the forum sudo only, so all of them are migrated at once.
+    fn migrate_categories() -> Weight {
+        let legacy_categories = migration::StorageIterator::<
+            LegacyCategory<T::BlockNumber, T::Moment, T::AccountId>,
+        >::new(b"Forum", b"CategoryById");
+
+        let mut migrated_categories_count: Weight = 0;
+
+        for (hashed_key, legacy_category) in legacy_categories {
+            let category: Category<T::BlockNumber, T::Moment, T::AccountId> =
+                legacy_category.into();
+
+            migration::put_storage_value(b"Forum", b"CategoryById", &hashed_key, category);
+
+            migrated_categories_count += 1;
+        }
+
+        T::DbWeight::get().reads_writes(migrated_categories_count, migrated_categories_count)
+    }
+
     fn ensure_category_title_is_valid(title: &[u8]) -> DispatchResult {
         CategoryTitleConstraint::get().ensure_valid(
             title.len(),
@@ -813,6 +1671,40 @@ impl<T: Trait> Module<T> {
         )
     }
 
+    fn ensure_poll_is_valid(poll: &PollInput<T::BlockNumber>) -> DispatchResult {
+        // Questions and alternatives are as short as thread titles
+        ThreadTitleConstraint::get().ensure_valid(
+            poll.question.len(),
+            ERROR_POLL_QUESTION_TOO_SHORT,
+            ERROR_POLL_QUESTION_TOO_LONG,
+        )?;
+
+        ensure!(
+            poll.alternatives.len() >= 2,
+            ERROR_POLL_TOO_FEW_ALTERNATIVES
+        );
+
+        ensure!(
+            poll.alternatives.len() <= T::MaxPollAlternatives::get() as usize,
+            ERROR_POLL_TOO_MANY_ALTERNATIVES
+        );
+
+        for alternative in poll.alternatives.iter() {
+            ThreadTitleConstraint::get().ensure_valid(
+                alternative.len(),
+                ERROR_POLL_ALTERNATIVE_TOO_SHORT,
+                ERROR_POLL_ALTERNATIVE_TOO_LONG,
+            )?;
+        }
+
+        ensure!(
+            poll.ends_at > <system::Module<T>>::block_number(),
+            ERROR_POLL_END_IN_THE_PAST
+        );
+
+        Ok(())
+    }
+
     fn ensure_post_is_mutable(
         post_id: T::PostId,
     ) -> Result<Post<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId, T::PostId>, &'static str>
@@ -873,6 +1765,10 @@ impl<T: Trait> Module<T> {
     }
 
     fn ensure_is_forum_sudo(account_id: &T::AccountId) -> DispatchResult {
+        if T::ForumRolesValidator::is_forum_sudo(account_id) {
+            return Ok(());
+        }
+
         let forum_sudo_account = Self::ensure_forum_sudo_set()?;
 
         ensure!(
@@ -882,6 +1778,44 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Checks that the account is the forum sudo or moderates the category,
+    /// directly or through one of its ancestors.
+    fn ensure_is_forum_sudo_or_category_moderator(
+        account_id: &T::AccountId,
+        category_id: CategoryId,
+    ) -> DispatchResult {
+        if Self::is_category_moderator(account_id, category_id) {
+            return Ok(());
+        }
+
+        Self::ensure_is_forum_sudo(account_id)
+    }
+
+    /// Whether the account moderates the category, any of its ancestors or the whole forum.
+    pub fn is_category_moderator(account_id: &T::AccountId, category_id: CategoryId) -> bool {
+        if T::ForumRolesValidator::is_forum_moderator(account_id) {
+            return true;
+        }
+
+        <CategoryById<T>>::contains_key(category_id)
+            && Self::build_category_tree_path(category_id)
+                .iter()
+                .any(|category| <CategoryModerators<T>>::get(category.id, account_id))
+    }
+
+    /// Revokes the moderation rights of the account over all categories. The account moderates
+    /// at most `MaxModeratedCategories` categories.
+    pub fn remove_moderator_from_all_categories(account_id: &T::AccountId) {
+        for category_id in <ModeratedCategoryIds<T>>::take(account_id) {
+            <CategoryModerators<T>>::remove(category_id, account_id);
+
+            Self::deposit_event(RawEvent::CategoryModeratorRemoved(
+                category_id,
+                account_id.clone(),
+            ));
+        }
+    }
+
     fn ensure_is_forum_member(
         account_id: &T::AccountId,
     ) -> Result<ForumUser<T::AccountId>, &'static str> {
@@ -894,6 +1828,18 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    fn ensure_is_forum_user_account(
+        forum_user_id: &T::ForumUserId,
+        account_id: &T::AccountId,
+    ) -> DispatchResult {
+        ensure!(
+            T::MembershipRegistry::is_forum_user_account(forum_user_id, account_id),
+            ERROR_NOT_FORUM_USER
+        );
+
+        Ok(())
+    }
+
     fn ensure_catgory_is_mutable(category_id: CategoryId) -> DispatchResult {
         let category_tree_path = Self::build_category_tree_path(category_id);
 
@@ -995,6 +1941,7 @@ impl<T: Trait> Module<T> {
         category_id: CategoryId,
         title: &[u8],
         author_id: &T::AccountId,
+        poll: Option<PollInput<T::BlockNumber>>,
     ) -> Thread<T::BlockNumber, T::Moment, T::AccountId, T::ThreadId> {
         // Get category
         let category = <CategoryById<T>>::get(category_id);
@@ -1012,6 +1959,19 @@ impl<T: Trait> Module<T> {
             num_moderated_posts: 0,
             created_at: common::current_block_time::<T>(),
             author_id: author_id.clone(),
+            poll: poll.map(|poll| Poll {
+                question: poll.question,
+                alternatives: poll
+                    .alternatives
+                    .into_iter()
+                    .map(|text| PollAlternative {
+                        text,
+                        vote_count: 0,
+                    })
+                    .collect(),
+                ends_at: poll.ends_at,
+            }),
+            title_change_history: vec![],
         };
 
         // Store thread
@@ -1030,6 +1990,23 @@ impl<T: Trait> Module<T> {
         new_thread
     }
 
+    /// Unpins the thread from the category, if it is sticky there.
+    fn remove_sticky_thread(category_id: CategoryId, thread_id: T::ThreadId) {
+        if !<StickyThreadIds<T>>::contains_key(category_id) {
+            return;
+        }
+
+        let mut sticky_thread_ids = <StickyThreadIds<T>>::get(category_id);
+
+        sticky_thread_ids.retain(|sticky_thread_id| *sticky_thread_id != thread_id);
+
+        if sticky_thread_ids.is_empty() {
+            <StickyThreadIds<T>>::remove(category_id);
+        } else {
+            <StickyThreadIds<T>>::insert(category_id, sticky_thread_ids);
+        }
+    }
+
     /// Creates and ads a new post ot the given thread, and makes all required state updates
     /// `thread_id` must be valid
     fn add_new_post(
@@ -18,14 +18,15 @@ pub mod registry {
     use super::*;
 
     #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
-    pub struct Member<AccountId> {
+    pub struct Member<AccountId, ForumUserId> {
         pub id: AccountId,
+        pub forum_user_id: ForumUserId,
     }
 
     decl_storage! {
         trait Store for Module<T: Trait> as MockForumUserRegistry {
             pub ForumUserById get(fn forum_user_by_id) config(): map hasher(blake2_128_concat)
-                T::AccountId => Member<T::AccountId>;
+                T::AccountId => Member<T::AccountId, T::ForumUserId>;
         }
     }
 
@@ -34,12 +35,12 @@ pub mod registry {
     }
 
     impl<T: Trait> Module<T> {
-        pub fn add_member(member: &Member<T::AccountId>) {
+        pub fn add_member(member: &Member<T::AccountId, T::ForumUserId>) {
             <ForumUserById<T>>::insert(member.id.clone(), member.clone());
         }
     }
 
-    impl<T: Trait> ForumUserRegistry<T::AccountId> for Module<T> {
+    impl<T: Trait> ForumUserRegistry<T::AccountId, T::ForumUserId> for Module<T> {
         fn get_forum_user(id: &T::AccountId) -> Option<ForumUser<T::AccountId>> {
             if <ForumUserById<T>>::contains_key(id) {
                 let m = <ForumUserById<T>>::get(id);
@@ -49,6 +50,14 @@ pub mod registry {
                 None
             }
         }
+
+        fn is_forum_user_account(
+            forum_user_id: &T::ForumUserId,
+            account_id: &T::AccountId,
+        ) -> bool {
+            <ForumUserById<T>>::contains_key(account_id)
+                && <ForumUserById<T>>::get(account_id).forum_user_id == *forum_user_id
+        }
     }
 
     pub type TestMembershipRegistryModule = Module<Runtime>;
@@ -67,6 +76,9 @@ parameter_types! {
     pub const MaximumBlockLength: u32 = 2 * 1024;
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const MinimumPeriod: u64 = 5;
+    pub const MaxPollAlternatives: u32 = 4;
+    pub const MaxStickyThreads: u32 = 2;
+    pub const MaxModeratedCategories: u32 = 2;
 }
 
 impl system::Trait for Runtime {
@@ -105,8 +117,31 @@ impl pallet_timestamp::Trait for Runtime {
 impl Trait for Runtime {
     type Event = ();
     type MembershipRegistry = registry::TestMembershipRegistryModule;
+    type ForumUserId = u64;
     type ThreadId = u64;
     type PostId = u64;
+    type MaxPollAlternatives = MaxPollAlternatives;
+    type MaxStickyThreads = MaxStickyThreads;
+    type MaxModeratedCategories = MaxModeratedCategories;
+    type ForumRolesValidator = TestForumRolesValidator;
+    type WeightInfo = ();
+}
+
+pub const FORUM_LEAD_ACCOUNT_ID: <Runtime as system::Trait>::AccountId = 55;
+
+pub const FORUM_WORKER_ACCOUNT_ID: <Runtime as system::Trait>::AccountId = 66;
+
+/// Grants forum sudo authority to the lead and forum-wide moderation to the worker.
+pub struct TestForumRolesValidator;
+
+impl ForumRolesValidator<<Runtime as system::Trait>::AccountId> for TestForumRolesValidator {
+    fn is_forum_sudo(account_id: &<Runtime as system::Trait>::AccountId) -> bool {
+        *account_id == FORUM_LEAD_ACCOUNT_ID
+    }
+
+    fn is_forum_moderator(account_id: &<Runtime as system::Trait>::AccountId) -> bool {
+        *account_id == FORUM_WORKER_ACCOUNT_ID
+    }
 }
 
 #[derive(Clone)]
@@ -158,6 +193,14 @@ pub fn good_post_text() -> Vec<u8> {
     b"A response in the thread".to_vec()
 }
 
+pub fn good_poll() -> PollInput<<Runtime as system::Trait>::BlockNumber> {
+    PollInput {
+        question: b"Poll question".to_vec(),
+        alternatives: vec![b"Agree".to_vec(), b"Disagree".to_vec()],
+        ends_at: 10,
+    }
+}
+
 pub fn good_rationale() -> Vec<u8> {
     b"This post violates our community rules".to_vec()
 }
@@ -216,6 +259,7 @@ pub struct CreateThreadFixture {
     pub category_id: CategoryId,
     pub title: Vec<u8>,
     pub text: Vec<u8>,
+    pub poll: Option<PollInput<<Runtime as system::Trait>::BlockNumber>>,
     pub result: DispatchResult,
 }
 
@@ -226,7 +270,8 @@ impl CreateThreadFixture {
                 mock_origin(self.origin.clone()),
                 self.category_id,
                 self.title.clone(),
-                self.text.clone()
+                self.text.clone(),
+                self.poll.clone()
             ),
             self.result
         )
@@ -237,6 +282,7 @@ pub struct CreatePostFixture {
     pub origin: OriginType,
     pub thread_id: RuntimeThreadId,
     pub text: Vec<u8>,
+    pub poll: None,
     pub result: DispatchResult,
 }
 
@@ -253,13 +299,29 @@ impl CreatePostFixture {
     }
 }
 
+pub const FORUM_USER_ID: RuntimeForumUserId = 1;
+
 pub fn create_forum_member() -> OriginType {
     let member_id = 123;
-    let new_member = registry::Member { id: member_id };
+    let new_member = registry::Member {
+        id: member_id,
+        forum_user_id: FORUM_USER_ID,
+    };
     registry::TestMembershipRegistryModule::add_member(&new_member);
     OriginType::Signed(member_id)
 }
 
+/// Registers another account acting on behalf of the forum user created by `create_forum_member`.
+pub fn create_forum_member_second_account() -> OriginType {
+    let account_id = 124;
+    let new_member = registry::Member {
+        id: account_id,
+        forum_user_id: FORUM_USER_ID,
+    };
+    registry::TestMembershipRegistryModule::add_member(&new_member);
+    OriginType::Signed(account_id)
+}
+
 pub fn assert_create_category(
     forum_sudo: OriginType,
     parent_category_id: Option<CategoryId>,
@@ -285,6 +347,7 @@ pub fn assert_create_thread(
         category_id,
         title: good_thread_title(),
         text: good_thread_text(),
+        poll: None,
         result: expected_result,
     }
     .call_and_assert();
@@ -329,6 +392,7 @@ pub fn create_root_category_and_thread(
         category_id,
         title: good_thread_title(),
         text: good_thread_text(),
+        poll: None,
         result: Ok(()),
     }
     .call_and_assert();
@@ -353,6 +417,59 @@ pub fn create_root_category_and_thread_and_post(
     (member_origin, category_id, thread_id, post_id)
 }
 
+pub fn vote_on_poll(
+    origin: OriginType,
+    thread_id: RuntimeThreadId,
+    alternative_index: u32,
+) -> DispatchResult {
+    TestForumModule::vote_on_poll(
+        mock_origin(origin),
+        FORUM_USER_ID,
+        thread_id,
+        alternative_index,
+    )
+}
+
+pub fn react_to_post(
+    origin: OriginType,
+    post_id: RuntimePostId,
+    reaction: Option<PostReaction>,
+) -> DispatchResult {
+    TestForumModule::react_to_post(mock_origin(origin), post_id, reaction)
+}
+
+pub fn follow_thread(origin: OriginType, thread_id: RuntimeThreadId) -> DispatchResult {
+    TestForumModule::follow_thread(mock_origin(origin), thread_id)
+}
+
+pub fn unfollow_thread(origin: OriginType, thread_id: RuntimeThreadId) -> DispatchResult {
+    TestForumModule::unfollow_thread(mock_origin(origin), thread_id)
+}
+
+pub fn edit_thread_title(
+    origin: OriginType,
+    thread_id: RuntimeThreadId,
+    new_title: Vec<u8>,
+) -> DispatchResult {
+    TestForumModule::edit_thread_title(mock_origin(origin), thread_id, new_title)
+}
+
+pub fn move_thread(
+    origin: OriginType,
+    thread_id: RuntimeThreadId,
+    new_category_id: CategoryId,
+) -> DispatchResult {
+    TestForumModule::move_thread(mock_origin(origin), thread_id, new_category_id)
+}
+
+pub fn set_sticky_threads(
+    origin: OriginType,
+    category_id: CategoryId,
+    thread_ids: Vec<RuntimeThreadId>,
+) -> DispatchResult {
+    TestForumModule::set_sticky_threads(mock_origin(origin), category_id, thread_ids)
+}
+
 pub fn moderate_thread(
     forum_sudo: OriginType,
     thread_id: RuntimeThreadId,
@@ -385,6 +502,22 @@ pub fn undelete_category(forum_sudo: OriginType, category_id: CategoryId) -> Dis
     TestForumModule::update_category(mock_origin(forum_sudo), category_id, None, Some(false))
 }
 
+pub fn add_category_moderator(
+    origin: OriginType,
+    category_id: CategoryId,
+    account_id: <Runtime as system::Trait>::AccountId,
+) -> DispatchResult {
+    TestForumModule::add_category_moderator(mock_origin(origin), category_id, account_id)
+}
+
+pub fn remove_category_moderator(
+    origin: OriginType,
+    category_id: CategoryId,
+    account_id: <Runtime as system::Trait>::AccountId,
+) -> DispatchResult {
+    TestForumModule::remove_category_moderator(mock_origin(origin), category_id, account_id)
+}
+
 pub fn assert_not_forum_sudo_cannot_update_category(
     update_operation: fn(OriginType, CategoryId) -> DispatchResult,
 ) {
@@ -479,6 +612,7 @@ pub type RuntimeBlockchainTimestamp = BlockAndTime<
 
 pub type RuntimeThreadId = <Runtime as Trait>::ThreadId;
 pub type RuntimePostId = <Runtime as Trait>::PostId;
+pub type RuntimeForumUserId = <Runtime as Trait>::ForumUserId;
 
 pub fn genesis_config(
     category_by_id: &RuntimeMap<CategoryId, RuntimeCategory>,
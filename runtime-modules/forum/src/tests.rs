@@ -3,7 +3,8 @@
 use super::*;
 use crate::mock::*;
 
-use frame_support::{assert_err, assert_ok};
+use codec::Encode;
+use frame_support::{assert_err, assert_ok, Blake2_128Concat, StorageHasher, StorageValue};
 
 /*
 * NB!: No test checks for event emission!!!!
@@ -209,6 +210,7 @@ fn update_category_undelete_and_unarchive() {
                 num_direct_moderated_threads: 0,
                 position_in_parent_category: None,
                 moderator_id: forum_sudo,
+                num_direct_moved_threads: 0,
             },
         ),
         // A subcategory of the one above
@@ -231,6 +233,7 @@ fn update_category_undelete_and_unarchive() {
                     child_nr_in_parent_category: 1,
                 }),
                 moderator_id: forum_sudo,
+                num_direct_moved_threads: 0,
             },
         ),
     ];
@@ -294,6 +297,7 @@ fn create_thread_successfully() {
             category_id,
             title: good_thread_title(),
             text: good_thread_text(),
+            poll: None,
             result: Ok(()),
         }
         .call_and_assert();
@@ -315,6 +319,7 @@ fn create_thread_title_too_short() {
             category_id,
             title: generate_text(min_len - 1),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_THREAD_TITLE_TOO_SHORT),
         }
         .call_and_assert();
@@ -336,6 +341,7 @@ fn create_thread_title_too_long() {
             category_id,
             title: generate_text(max_len + 1),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_THREAD_TITLE_TOO_LONG),
         }
         .call_and_assert();
@@ -357,6 +363,7 @@ fn create_thread_text_too_short() {
             category_id,
             title: good_thread_title(),
             text: generate_text(min_len - 1),
+            poll: None,
             result: Err(ERROR_POST_TEXT_TOO_SHORT),
         }
         .call_and_assert();
@@ -378,6 +385,7 @@ fn create_thread_text_too_long() {
             category_id,
             title: good_thread_title(),
             text: generate_text(max_len + 1),
+            poll: None,
             result: Err(ERROR_POST_TEXT_TOO_LONG),
         }
         .call_and_assert();
@@ -650,6 +658,7 @@ fn not_member_cannot_create_thread() {
             category_id: create_root_category(origin),
             title: good_thread_title(),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_NOT_FORUM_USER),
         }
         .call_and_assert();
@@ -718,6 +727,7 @@ fn cannot_create_thread_with_invalid_category_id() {
             category_id: INVLAID_CATEGORY_ID,
             title: good_thread_title(),
             text: good_thread_text(),
+            poll: None,
             result: Err(ERROR_CATEGORY_DOES_NOT_EXIST),
         }
         .call_and_assert();
@@ -979,3 +989,1021 @@ fn cannot_edit_post_in_moderated_thread() {
 // TODO impl
 // #[test]
 // fn cannot_edit_moderated_post() {}
+
+// Category moderators
+// -----------------------------------------------------------------------------
+
+const MODERATOR_ACCOUNT_ID: <Runtime as system::Trait>::AccountId = 777;
+const OTHER_MODERATOR_ACCOUNT_ID: <Runtime as system::Trait>::AccountId = 778;
+
+#[test]
+fn not_forum_sudo_cannot_add_category_moderator() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo);
+        assert_err!(
+            add_category_moderator(NOT_FORUM_SUDO_ORIGIN, category_id, MODERATOR_ACCOUNT_ID),
+            ERROR_ORIGIN_NOT_FORUM_SUDO
+        );
+    });
+}
+
+#[test]
+fn cannot_add_category_moderator_with_invalid_category_id() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        assert_err!(
+            add_category_moderator(forum_sudo, INVLAID_CATEGORY_ID, MODERATOR_ACCOUNT_ID),
+            ERROR_CATEGORY_DOES_NOT_EXIST
+        );
+    });
+}
+
+#[test]
+fn add_and_remove_category_moderator_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo.clone());
+
+        assert_ok!(add_category_moderator(
+            forum_sudo.clone(),
+            category_id,
+            MODERATOR_ACCOUNT_ID
+        ));
+        assert!(TestForumModule::category_moderators(
+            category_id,
+            MODERATOR_ACCOUNT_ID
+        ));
+        assert_err!(
+            add_category_moderator(forum_sudo.clone(), category_id, MODERATOR_ACCOUNT_ID),
+            ERROR_ALREADY_CATEGORY_MODERATOR
+        );
+
+        assert_ok!(remove_category_moderator(
+            forum_sudo.clone(),
+            category_id,
+            MODERATOR_ACCOUNT_ID
+        ));
+        assert!(!TestForumModule::category_moderators(
+            category_id,
+            MODERATOR_ACCOUNT_ID
+        ));
+        assert_err!(
+            remove_category_moderator(forum_sudo, category_id, MODERATOR_ACCOUNT_ID),
+            ERROR_NOT_CATEGORY_MODERATOR
+        );
+    });
+}
+
+#[test]
+fn cannot_add_category_moderator_over_moderated_categories_limit() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        for _ in 0..MaxModeratedCategories::get() {
+            let category_id = create_root_category(forum_sudo.clone());
+            assert_ok!(add_category_moderator(
+                forum_sudo.clone(),
+                category_id,
+                MODERATOR_ACCOUNT_ID
+            ));
+        }
+
+        let category_id = create_root_category(forum_sudo.clone());
+        assert_err!(
+            add_category_moderator(forum_sudo, category_id, MODERATOR_ACCOUNT_ID),
+            ERROR_TOO_MANY_MODERATED_CATEGORIES
+        );
+    });
+}
+
+#[test]
+fn remove_moderator_from_all_categories() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let root_category_id = create_root_category(forum_sudo.clone());
+        let subcategory_id = create_category(forum_sudo.clone(), Some(root_category_id));
+        let other_category_id = create_root_category(forum_sudo.clone());
+
+        for category_id in [root_category_id, subcategory_id].iter() {
+            assert_ok!(add_category_moderator(
+                forum_sudo.clone(),
+                *category_id,
+                MODERATOR_ACCOUNT_ID
+            ));
+        }
+        assert_ok!(add_category_moderator(
+            forum_sudo,
+            other_category_id,
+            OTHER_MODERATOR_ACCOUNT_ID
+        ));
+
+        TestForumModule::remove_moderator_from_all_categories(&MODERATOR_ACCOUNT_ID);
+
+        assert!(TestForumModule::moderated_category_ids(MODERATOR_ACCOUNT_ID).is_empty());
+        assert!(!TestForumModule::category_moderators(
+            root_category_id,
+            MODERATOR_ACCOUNT_ID
+        ));
+        assert!(!TestForumModule::category_moderators(
+            subcategory_id,
+            MODERATOR_ACCOUNT_ID
+        ));
+        assert!(TestForumModule::category_moderators(
+            other_category_id,
+            OTHER_MODERATOR_ACCOUNT_ID
+        ));
+    });
+}
+
+#[test]
+fn category_moderator_can_moderate_in_category_subtree() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let moderator = OriginType::Signed(MODERATOR_ACCOUNT_ID);
+
+    build_test_externalities(config).execute_with(|| {
+        let root_category_id = create_root_category(forum_sudo.clone());
+        assert_ok!(add_category_moderator(
+            forum_sudo,
+            root_category_id,
+            MODERATOR_ACCOUNT_ID
+        ));
+
+        let subcategory_id = create_category(moderator.clone(), Some(root_category_id));
+
+        let member_origin = create_forum_member();
+        let thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), subcategory_id, Ok(()));
+        let post_id = TestForumModule::next_post_id();
+        assert_create_post(member_origin, thread_id, Ok(()));
+
+        assert_ok!(moderate_post(moderator.clone(), post_id, good_rationale()));
+        assert_ok!(moderate_thread(
+            moderator.clone(),
+            thread_id,
+            good_rationale()
+        ));
+        assert_ok!(archive_category(moderator, subcategory_id));
+    });
+}
+
+#[test]
+fn category_moderator_cannot_moderate_outside_category_subtree() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let moderator = OriginType::Signed(MODERATOR_ACCOUNT_ID);
+
+    build_test_externalities(config).execute_with(|| {
+        let root_category_id = create_root_category(forum_sudo.clone());
+        let subcategory_id = create_category(forum_sudo.clone(), Some(root_category_id));
+        assert_ok!(add_category_moderator(
+            forum_sudo.clone(),
+            subcategory_id,
+            MODERATOR_ACCOUNT_ID
+        ));
+
+        // Ancestors are out of reach
+        assert_eq!(
+            archive_category(moderator.clone(), root_category_id),
+            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+        );
+
+        // So are unrelated categories
+        let (_, _, thread_id) = create_root_category_and_thread(forum_sudo);
+        assert_eq!(
+            moderate_thread(moderator.clone(), thread_id, good_rationale()),
+            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+        );
+
+        // Root categories are created by forum sudo only
+        assert_create_category(moderator, None, Err(ERROR_ORIGIN_NOT_FORUM_SUDO));
+    });
+}
+
+// Forum roles granted by the runtime
+// -----------------------------------------------------------------------------
+
+#[test]
+fn forum_lead_has_forum_sudo_authority() {
+    let config = default_genesis_config();
+    let forum_lead = OriginType::Signed(FORUM_LEAD_ACCOUNT_ID);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_lead.clone());
+
+        assert_ok!(add_category_moderator(
+            forum_lead.clone(),
+            category_id,
+            MODERATOR_ACCOUNT_ID
+        ));
+
+        let (_, _, thread_id) = create_root_category_and_thread(forum_lead.clone());
+        assert_ok!(moderate_thread(forum_lead, thread_id, good_rationale()));
+    });
+}
+
+#[test]
+fn forum_worker_moderates_all_categories() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let forum_worker = OriginType::Signed(FORUM_WORKER_ACCOUNT_ID);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo);
+
+        assert_ok!(moderate_post(
+            forum_worker.clone(),
+            post_id,
+            good_rationale()
+        ));
+        assert_ok!(moderate_thread(
+            forum_worker.clone(),
+            thread_id,
+            good_rationale()
+        ));
+        assert_ok!(archive_category(forum_worker.clone(), category_id));
+
+        // Moderators are still managed by the forum sudo authority only
+        assert_err!(
+            add_category_moderator(forum_worker.clone(), category_id, MODERATOR_ACCOUNT_ID),
+            ERROR_ORIGIN_NOT_FORUM_SUDO
+        );
+        assert_create_category(forum_worker, None, Err(ERROR_ORIGIN_NOT_FORUM_SUDO));
+    });
+}
+
+// Thread polls
+// -----------------------------------------------------------------------------
+
+fn create_root_category_and_thread_with_poll(
+    forum_sudo: OriginType,
+    poll: PollInput<<Runtime as system::Trait>::BlockNumber>,
+) -> RuntimeThreadId {
+    let category_id = create_root_category(forum_sudo);
+    let thread_id = TestForumModule::next_thread_id();
+
+    CreateThreadFixture {
+        origin: create_forum_member(),
+        category_id,
+        title: good_thread_title(),
+        text: good_thread_text(),
+        poll: Some(poll),
+        result: Ok(()),
+    }
+    .call_and_assert();
+
+    thread_id
+}
+
+fn assert_cannot_create_thread_with_poll(
+    poll: PollInput<<Runtime as system::Trait>::BlockNumber>,
+    expected_error: &'static str,
+) {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id = create_root_category(forum_sudo);
+
+        CreateThreadFixture {
+            origin: create_forum_member(),
+            category_id,
+            title: good_thread_title(),
+            text: good_thread_text(),
+            poll: Some(poll),
+            result: Err(expected_error),
+        }
+        .call_and_assert();
+    });
+}
+
+#[test]
+fn create_thread_with_poll_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let thread_id = create_root_category_and_thread_with_poll(forum_sudo, good_poll());
+
+        let poll = TestForumModule::thread_by_id(thread_id).poll.unwrap();
+        assert_eq!(poll.question, good_poll().question);
+        assert_eq!(poll.ends_at, good_poll().ends_at);
+        assert_eq!(poll.alternatives.len(), good_poll().alternatives.len());
+        assert!(poll
+            .alternatives
+            .iter()
+            .all(|alternative| alternative.vote_count == 0));
+    });
+}
+
+#[test]
+fn cannot_create_thread_with_invalid_poll() {
+    let mut poll = good_poll();
+    poll.question = generate_text(2);
+    assert_cannot_create_thread_with_poll(poll, ERROR_POLL_QUESTION_TOO_SHORT);
+
+    let mut poll = good_poll();
+    poll.alternatives.push(generate_text(47));
+    assert_cannot_create_thread_with_poll(poll, ERROR_POLL_ALTERNATIVE_TOO_LONG);
+
+    let mut poll = good_poll();
+    poll.alternatives.truncate(1);
+    assert_cannot_create_thread_with_poll(poll, ERROR_POLL_TOO_FEW_ALTERNATIVES);
+
+    let mut poll = good_poll();
+    poll.alternatives = vec![good_thread_title(); 5];
+    assert_cannot_create_thread_with_poll(poll, ERROR_POLL_TOO_MANY_ALTERNATIVES);
+
+    let mut poll = good_poll();
+    poll.ends_at = 0;
+    assert_cannot_create_thread_with_poll(poll, ERROR_POLL_END_IN_THE_PAST);
+}
+
+#[test]
+fn vote_on_poll_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let thread_id = create_root_category_and_thread_with_poll(forum_sudo, good_poll());
+        let voter = create_forum_member();
+
+        assert_ok!(vote_on_poll(voter, thread_id, 1));
+
+        let poll = TestForumModule::thread_by_id(thread_id).poll.unwrap();
+        assert_eq!(poll.alternatives[0].vote_count, 0);
+        assert_eq!(poll.alternatives[1].vote_count, 1);
+
+        assert_eq!(
+            TestForumModule::poll_vote(thread_id, FORUM_USER_ID),
+            Some(1)
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_on_poll_twice() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let thread_id = create_root_category_and_thread_with_poll(forum_sudo, good_poll());
+        let voter = create_forum_member();
+
+        assert_ok!(vote_on_poll(voter.clone(), thread_id, 0));
+        assert_err!(
+            vote_on_poll(voter, thread_id, 1),
+            ERROR_ALREADY_VOTED_ON_POLL
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_on_poll_twice_with_another_account_of_the_same_forum_user() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let thread_id = create_root_category_and_thread_with_poll(forum_sudo, good_poll());
+        let root_account = create_forum_member();
+        let controller_account = create_forum_member_second_account();
+
+        assert_ok!(vote_on_poll(root_account, thread_id, 0));
+        assert_err!(
+            vote_on_poll(controller_account, thread_id, 1),
+            ERROR_ALREADY_VOTED_ON_POLL
+        );
+
+        let poll = TestForumModule::thread_by_id(thread_id).poll.unwrap();
+        assert_eq!(poll.alternatives[0].vote_count, 1);
+        assert_eq!(poll.alternatives[1].vote_count, 0);
+    });
+}
+
+#[test]
+fn not_member_cannot_vote_on_poll() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let thread_id = create_root_category_and_thread_with_poll(forum_sudo, good_poll());
+
+        assert_err!(
+            vote_on_poll(NOT_MEMBER_ORIGIN, thread_id, 0),
+            ERROR_NOT_FORUM_USER
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_on_thread_without_poll() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo);
+
+        assert_err!(
+            vote_on_poll(member_origin, thread_id, 0),
+            ERROR_THREAD_HAS_NO_POLL
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_on_invalid_poll_alternative() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let thread_id = create_root_category_and_thread_with_poll(forum_sudo, good_poll());
+
+        assert_err!(
+            vote_on_poll(create_forum_member(), thread_id, 2),
+            ERROR_POLL_ALTERNATIVE_DOES_NOT_EXIST
+        );
+    });
+}
+
+#[test]
+fn cannot_vote_on_ended_poll() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let thread_id = create_root_category_and_thread_with_poll(forum_sudo, good_poll());
+
+        system::Module::<Runtime>::set_block_number(good_poll().ends_at);
+
+        assert_err!(
+            vote_on_poll(create_forum_member(), thread_id, 0),
+            ERROR_POLL_ENDED
+        );
+    });
+}
+
+// Post reactions
+// -----------------------------------------------------------------------------
+
+#[test]
+fn react_to_post_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+
+        assert_ok!(react_to_post(
+            member_origin.clone(),
+            post_id,
+            Some(PostReaction::Like)
+        ));
+        assert_eq!(TestForumModule::post_reaction_counts(post_id).likes, 1);
+
+        // Changing the reaction moves the vote between the counters
+        assert_ok!(react_to_post(
+            member_origin.clone(),
+            post_id,
+            Some(PostReaction::Laugh)
+        ));
+        let counts = TestForumModule::post_reaction_counts(post_id);
+        assert_eq!(counts.likes, 0);
+        assert_eq!(counts.laughs, 1);
+
+        // Withdrawing the reaction
+        assert_ok!(react_to_post(member_origin, post_id, None));
+        assert_eq!(
+            TestForumModule::post_reaction_counts(post_id),
+            PostReactionCounts::default()
+        );
+    });
+}
+
+#[test]
+fn cannot_react_to_post_with_unchanged_reaction() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+
+        assert_err!(
+            react_to_post(member_origin.clone(), post_id, None),
+            ERROR_POST_REACTION_NOT_CHANGED
+        );
+
+        assert_ok!(react_to_post(
+            member_origin.clone(),
+            post_id,
+            Some(PostReaction::Dislike)
+        ));
+        assert_err!(
+            react_to_post(member_origin, post_id, Some(PostReaction::Dislike)),
+            ERROR_POST_REACTION_NOT_CHANGED
+        );
+    });
+}
+
+#[test]
+fn not_member_cannot_react_to_post() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, _, post_id) = create_root_category_and_thread_and_post(forum_sudo);
+
+        assert_err!(
+            react_to_post(NOT_MEMBER_ORIGIN, post_id, Some(PostReaction::Like)),
+            ERROR_NOT_FORUM_USER
+        );
+    });
+}
+
+#[test]
+fn cannot_react_to_moderated_post() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) =
+            create_root_category_and_thread_and_post(forum_sudo.clone());
+        assert_ok!(moderate_post(forum_sudo, post_id, good_rationale()));
+
+        assert_err!(
+            react_to_post(member_origin, post_id, Some(PostReaction::Like)),
+            ERROR_POST_MODERATED
+        );
+    });
+}
+
+// Thread followers
+// -----------------------------------------------------------------------------
+
+#[test]
+fn follow_and_unfollow_thread_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo);
+
+        assert_ok!(follow_thread(member_origin.clone(), thread_id));
+        assert_eq!(TestForumModule::thread_followers_count(thread_id), 1);
+        assert_err!(
+            follow_thread(member_origin.clone(), thread_id),
+            ERROR_ALREADY_FOLLOWING_THREAD
+        );
+
+        assert_create_post(member_origin.clone(), thread_id, Ok(()));
+
+        assert_ok!(unfollow_thread(member_origin.clone(), thread_id));
+        assert_eq!(TestForumModule::thread_followers_count(thread_id), 0);
+        assert_err!(
+            unfollow_thread(member_origin, thread_id),
+            ERROR_NOT_FOLLOWING_THREAD
+        );
+    });
+}
+
+#[test]
+fn cannot_follow_invalid_thread() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        assert_err!(
+            follow_thread(create_forum_member(), INVLAID_THREAD_ID),
+            ERROR_THREAD_DOES_NOT_EXIST
+        );
+    });
+}
+
+#[test]
+fn not_member_cannot_follow_thread() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(forum_sudo);
+
+        assert_err!(
+            follow_thread(NOT_MEMBER_ORIGIN, thread_id),
+            ERROR_NOT_FORUM_USER
+        );
+    });
+}
+
+// Thread title edits
+// -----------------------------------------------------------------------------
+
+#[test]
+fn edit_thread_title_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo);
+
+        let new_title = b"Edited title".to_vec();
+        assert_ok!(edit_thread_title(
+            member_origin,
+            thread_id,
+            new_title.clone()
+        ));
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert_eq!(thread.title, new_title);
+        assert_eq!(thread.title_change_history.len(), 1);
+        assert_eq!(thread.title_change_history[0].title, good_thread_title());
+    });
+}
+
+#[test]
+fn not_thread_author_cannot_edit_thread_title() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+
+        assert_err!(
+            edit_thread_title(forum_sudo, thread_id, good_thread_title()),
+            ERROR_NOT_FORUM_USER
+        );
+
+        // Another forum member
+        let other_member_id = 124;
+        registry::TestMembershipRegistryModule::add_member(&registry::Member {
+            id: other_member_id,
+            forum_user_id: FORUM_USER_ID + 1,
+        });
+
+        assert_err!(
+            edit_thread_title(
+                OriginType::Signed(other_member_id),
+                thread_id,
+                good_thread_title()
+            ),
+            ERROR_ACCOUNT_DOES_NOT_MATCH_THREAD_AUTHOR
+        );
+    });
+}
+
+#[test]
+fn cannot_edit_thread_title_with_invalid_title() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo);
+
+        assert_err!(
+            edit_thread_title(member_origin.clone(), thread_id, generate_text(1)),
+            ERROR_THREAD_TITLE_TOO_SHORT
+        );
+        assert_err!(
+            edit_thread_title(member_origin, thread_id, generate_text(100)),
+            ERROR_THREAD_TITLE_TOO_LONG
+        );
+    });
+}
+
+#[test]
+fn cannot_edit_title_of_moderated_thread() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        assert_ok!(moderate_thread(forum_sudo, thread_id, good_rationale()));
+
+        assert_err!(
+            edit_thread_title(member_origin, thread_id, good_thread_title()),
+            ERROR_THREAD_MODERATED
+        );
+    });
+}
+
+// Thread moves
+// -----------------------------------------------------------------------------
+
+#[test]
+fn move_thread_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, old_category_id, thread_id) =
+            create_root_category_and_thread(forum_sudo.clone());
+        let new_category_id = create_root_category(forum_sudo.clone());
+        assert_create_thread(member_origin.clone(), new_category_id, Ok(()));
+
+        assert_ok!(set_sticky_threads(
+            forum_sudo.clone(),
+            old_category_id,
+            vec![thread_id]
+        ));
+
+        assert_ok!(move_thread(forum_sudo, thread_id, new_category_id));
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert_eq!(thread.category_id, new_category_id);
+        assert_eq!(thread.nr_in_category, 2);
+
+        let old_category = TestForumModule::category_by_id(old_category_id);
+        assert_eq!(old_category.num_direct_unmoderated_threads, 0);
+        assert_eq!(old_category.num_direct_moved_threads, 1);
+        assert!(TestForumModule::sticky_thread_ids(old_category_id).is_empty());
+
+        let new_category = TestForumModule::category_by_id(new_category_id);
+        assert_eq!(new_category.num_direct_unmoderated_threads, 2);
+
+        // Threads added after the move keep unique numbers in both categories
+        let next_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), old_category_id, Ok(()));
+        assert_eq!(
+            TestForumModule::thread_by_id(next_thread_id).nr_in_category,
+            2
+        );
+
+        let next_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin, new_category_id, Ok(()));
+        assert_eq!(
+            TestForumModule::thread_by_id(next_thread_id).nr_in_category,
+            3
+        );
+    });
+}
+
+#[test]
+fn cannot_move_thread_to_same_or_invalid_category() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+
+        assert_err!(
+            move_thread(forum_sudo.clone(), thread_id, category_id),
+            ERROR_THREAD_ALREADY_IN_CATEGORY
+        );
+        assert_err!(
+            move_thread(forum_sudo, thread_id, INVLAID_CATEGORY_ID),
+            ERROR_CATEGORY_DOES_NOT_EXIST
+        );
+    });
+}
+
+#[test]
+fn cannot_move_thread_to_archived_category() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        let new_category_id = create_root_category(forum_sudo.clone());
+        assert_ok!(archive_category(forum_sudo.clone(), new_category_id));
+
+        assert_err!(
+            move_thread(forum_sudo, thread_id, new_category_id),
+            ERROR_ANCESTOR_CATEGORY_IMMUTABLE
+        );
+    });
+}
+
+#[test]
+fn category_moderator_moves_thread_only_between_moderated_categories() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+    let moderator = OriginType::Signed(MODERATOR_ACCOUNT_ID);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        let new_category_id = create_root_category(forum_sudo.clone());
+        assert_ok!(add_category_moderator(
+            forum_sudo.clone(),
+            category_id,
+            MODERATOR_ACCOUNT_ID
+        ));
+
+        assert_err!(
+            move_thread(moderator.clone(), thread_id, new_category_id),
+            ERROR_ORIGIN_NOT_FORUM_SUDO
+        );
+
+        assert_ok!(add_category_moderator(
+            forum_sudo,
+            new_category_id,
+            MODERATOR_ACCOUNT_ID
+        ));
+        assert_ok!(move_thread(moderator, thread_id, new_category_id));
+    });
+}
+
+// Sticky threads
+// -----------------------------------------------------------------------------
+
+#[test]
+fn set_sticky_threads_successfully() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, thread_id) =
+            create_root_category_and_thread(forum_sudo.clone());
+        let other_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin, category_id, Ok(()));
+
+        assert_ok!(set_sticky_threads(
+            forum_sudo.clone(),
+            category_id,
+            vec![other_thread_id, thread_id]
+        ));
+        assert_eq!(
+            TestForumModule::sticky_thread_ids(category_id),
+            vec![other_thread_id, thread_id]
+        );
+
+        // Moderated threads are unpinned
+        assert_ok!(moderate_thread(
+            forum_sudo.clone(),
+            other_thread_id,
+            good_rationale()
+        ));
+        assert_eq!(
+            TestForumModule::sticky_thread_ids(category_id),
+            vec![thread_id]
+        );
+
+        assert_ok!(set_sticky_threads(forum_sudo, category_id, vec![]));
+        assert!(TestForumModule::sticky_thread_ids(category_id).is_empty());
+    });
+}
+
+#[test]
+fn cannot_set_too_many_sticky_threads() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+
+        assert_err!(
+            set_sticky_threads(
+                forum_sudo,
+                category_id,
+                vec![thread_id; MaxStickyThreads::get() as usize + 1]
+            ),
+            ERROR_TOO_MANY_STICKY_THREADS
+        );
+    });
+}
+
+#[test]
+fn cannot_set_sticky_threads_of_other_category() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, _, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+        let other_category_id = create_root_category(forum_sudo.clone());
+
+        assert_err!(
+            set_sticky_threads(forum_sudo.clone(), other_category_id, vec![thread_id]),
+            ERROR_STICKY_THREAD_NOT_IN_CATEGORY
+        );
+        assert_err!(
+            set_sticky_threads(forum_sudo, other_category_id, vec![INVLAID_THREAD_ID]),
+            ERROR_THREAD_DOES_NOT_EXIST
+        );
+    });
+}
+
+#[test]
+fn cannot_set_duplicate_sticky_threads() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(forum_sudo.clone());
+
+        assert_err!(
+            set_sticky_threads(forum_sudo, category_id, vec![thread_id, thread_id]),
+            ERROR_DUPLICATE_STICKY_THREADS
+        );
+        assert!(TestForumModule::sticky_thread_ids(category_id).is_empty());
+    });
+}
+
+#[test]
+fn not_forum_sudo_cannot_set_sticky_threads() {
+    let config = default_genesis_config();
+    let forum_sudo = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, category_id, thread_id) = create_root_category_and_thread(forum_sudo);
+
+        assert_err!(
+            set_sticky_threads(NOT_FORUM_SUDO_ORIGIN, category_id, vec![thread_id]),
+            ERROR_ORIGIN_NOT_FORUM_SUDO
+        );
+    });
+}
+
+// Migrations
+// -----------------------------------------------------------------------------
+
+#[test]
+fn threads_migration_succeeds() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let thread_id: RuntimeThreadId = 1;
+        let legacy_thread = (
+            thread_id,
+            b"legacy thread".to_vec(),
+            1u64,
+            1u32,
+            None::<ModerationAction<u64, u64, u64>>,
+            1u32,
+            0u32,
+            BlockAndTime {
+                block: 1u64,
+                time: 1u64,
+            },
+            FORUM_WORKER_ACCOUNT_ID,
+        );
+        migration::put_storage_value(
+            b"Forum",
+            b"ThreadById",
+            &Blake2_128Concat::hash(&thread_id.encode()),
+            legacy_thread,
+        );
+        <StorageVersion>::put(0);
+
+        TestForumModule::migrate();
+
+        let thread = TestForumModule::thread_by_id(thread_id);
+        assert_eq!(thread.title, b"legacy thread".to_vec());
+        assert_eq!(thread.author_id, FORUM_WORKER_ACCOUNT_ID);
+        assert!(thread.poll.is_none());
+        assert!(thread.title_change_history.is_empty());
+        assert_eq!(TestForumModule::storage_version(), 2);
+
+        // The migrated threads are not migrated again
+        TestForumModule::migrate();
+
+        assert_eq!(TestForumModule::thread_by_id(thread_id), thread);
+    });
+}
+
+#[test]
+fn categories_migration_succeeds() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let category_id: CategoryId = 1;
+        let legacy_category = (
+            category_id,
+            b"legacy category".to_vec(),
+            b"legacy description".to_vec(),
+            BlockAndTime {
+                block: 1u64,
+                time: 1u64,
+            },
+            false,
+            false,
+            0u32,
+            2u32,
+            1u32,
+            None::<ChildPositionInParentCategory>,
+            FORUM_LEAD_ACCOUNT_ID,
+        );
+        migration::put_storage_value(
+            b"Forum",
+            b"CategoryById",
+            &Blake2_128Concat::hash(&category_id.encode()),
+            legacy_category,
+        );
+        <StorageVersion>::put(1);
+
+        TestForumModule::migrate();
+
+        let category = TestForumModule::category_by_id(category_id);
+        assert_eq!(category.title, b"legacy category".to_vec());
+        assert_eq!(category.num_direct_unmoderated_threads, 2);
+        assert_eq!(category.num_direct_moderated_threads, 1);
+        assert_eq!(category.num_direct_moved_threads, 0);
+        assert_eq!(TestForumModule::storage_version(), 2);
+    });
+}
@@ -0,0 +1,93 @@
+use frame_benchmarking::{account, benchmarks};
+use frame_support::StorageValue;
+use sp_std::prelude::*;
+use system::RawOrigin;
+
+use super::*;
+use crate::council::{AmountPerPayout, Call, CouncilMint, Module, Trait};
+
+const MINT_CAPACITY: u32 = 1_000_000;
+
+// Creates a funded council mint paying rewards to the new councilors.
+fn create_council_mint<T: Trait>() {
+    let mint_id = minting::Module::<T>::add_mint(MINT_CAPACITY.into(), None)
+        .expect("benchmark mint must be created");
+
+    CouncilMint::<T>::put(mint_id);
+    AmountPerPayout::<T>::put(minting::BalanceOf::<T>::from(1u32));
+}
+
+// Sets a full council with reward relationships to be replaced.
+fn set_full_council<T: Trait>() -> Vec<T::AccountId> {
+    create_council_mint::<T>();
+
+    let councilors = accounts::<T>("councilor", MAX_COUNCIL_SIZE);
+    Module::<T>::set_council(RawOrigin::Root.into(), councilors.clone())
+        .expect("council must be set");
+
+    councilors
+}
+
+benchmarks! {
+    _ { }
+
+    set_council {
+        let i in 1 .. MAX_COUNCIL_SIZE;
+
+        set_full_council::<T>();
+        let new_councilors = accounts::<T>("new_councilor", i);
+    }: _(RawOrigin::Root, new_councilors)
+    verify {
+        assert_eq!(Module::<T>::active_council().len(), i as usize);
+    }
+
+    add_council_member {
+        create_council_mint::<T>();
+        Module::<T>::set_council(
+            RawOrigin::Root.into(),
+            accounts::<T>("councilor", MAX_COUNCIL_SIZE - 1),
+        )?;
+        let new_councilor: T::AccountId = account("new_councilor", 0, SEED);
+    }: _(RawOrigin::Root, new_councilor.clone())
+    verify {
+        assert!(Module::<T>::is_councilor(&new_councilor));
+    }
+
+    remove_council_member {
+        let councilors = set_full_council::<T>();
+        let councilor = councilors[0].clone();
+    }: _(RawOrigin::Root, councilor.clone())
+    verify {
+        assert!(!Module::<T>::is_councilor(&councilor));
+    }
+
+    set_term_ends_at {
+        let ends_at = <system::Module<T>>::block_number() + 100u32.into();
+    }: _(RawOrigin::Root, ends_at)
+    verify {
+        assert_eq!(Module::<T>::term_ends_at(), ends_at);
+    }
+
+    set_council_mint_capacity {
+        create_council_mint::<T>();
+        let capacity = minting::BalanceOf::<T>::from(MINT_CAPACITY * 2);
+    }: _(RawOrigin::Root, capacity)
+    verify {
+        assert_eq!(
+            minting::Module::<T>::mints(Module::<T>::council_mint()).capacity(),
+            capacity
+        );
+    }
+
+    spend_from_council_mint {
+        create_council_mint::<T>();
+        let destination: T::AccountId = account("destination", 0, SEED);
+    }: _(RawOrigin::Root, MINT_CAPACITY.into(), destination)
+
+    set_council_rewards {
+        let amount_per_payout = minting::BalanceOf::<T>::from(10u32);
+    }: _(RawOrigin::Root, amount_per_payout, Some(100u32.into()), 1u32.into())
+    verify {
+        assert_eq!(Module::<T>::amount_per_payout(), amount_per_payout);
+    }
+}
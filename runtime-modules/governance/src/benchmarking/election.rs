@@ -0,0 +1,175 @@
+use codec::Encode;
+use frame_benchmarking::benchmarks;
+use frame_support::dispatch::UnfilteredDispatchable;
+use sp_runtime::traits::Hash;
+use sp_std::prelude::*;
+use system::RawOrigin;
+
+use super::*;
+use crate::election::{BalanceOf, Call, Module, Trait};
+use crate::election_params::ElectionParameters;
+
+const STAGE_PERIOD: u32 = 10;
+const STAKE: u32 = 100;
+
+fn election_parameters<T: Trait>() -> ElectionParameters<BalanceOf<T>, T::BlockNumber> {
+    ElectionParameters {
+        announcing_period: STAGE_PERIOD.into(),
+        voting_period: STAGE_PERIOD.into(),
+        revealing_period: STAGE_PERIOD.into(),
+        council_size: MAX_COUNCIL_SIZE,
+        candidacy_limit: MAX_COUNCIL_SIZE,
+        new_term_duration: STAGE_PERIOD.into(),
+        min_council_stake: 1u32.into(),
+        min_voting_stake: 1u32.into(),
+    }
+}
+
+fn stage_ends_at<T: Trait>() -> T::BlockNumber {
+    <system::Module<T>>::block_number() + STAGE_PERIOD.into()
+}
+
+// The stage setters are not public, so they are dispatched as root calls.
+fn dispatch_as_root<T: Trait>(call: Call<T>) {
+    call.dispatch_bypass_filter(RawOrigin::Root.into())
+        .expect("root call must succeed");
+}
+
+// Starts an election taking over the stakes of a full council.
+fn start_election<T: Trait>() {
+    crate::council::Module::<T>::set_council(
+        RawOrigin::Root.into(),
+        accounts::<T>("councilor", MAX_COUNCIL_SIZE),
+    )
+    .expect("council must be set");
+
+    Module::<T>::set_election_parameters(RawOrigin::Root.into(), election_parameters::<T>())
+        .expect("election parameters must be valid");
+    Module::<T>::force_start_election(RawOrigin::Root.into()).expect("election must start");
+}
+
+fn insert_applicant<T: Trait>(index: u32) -> T::AccountId {
+    let applicant = insert_member::<T>("applicant", index);
+
+    Module::<T>::apply(RawOrigin::Signed(applicant.clone()).into(), STAKE.into())
+        .expect("member must be able to apply");
+
+    applicant
+}
+
+fn salt(index: u32) -> Vec<u8> {
+    let mut salt = index.to_be_bytes().to_vec();
+    salt.resize(32, b's');
+    salt
+}
+
+fn commitment<T: Trait>(vote_for: &T::AccountId, salt: &[u8]) -> T::Hash {
+    let mut payload = vote_for.encode();
+    payload.extend_from_slice(salt);
+
+    T::Hashing::hash(&payload)
+}
+
+// Casts a vote for the applicant and returns the voter and the commitment.
+fn insert_vote<T: Trait>(index: u32, applicant: &T::AccountId) -> (T::AccountId, T::Hash) {
+    let voter = insert_member::<T>("voter", index);
+    let commitment = commitment::<T>(applicant, &salt(index));
+
+    Call::<T>::vote(commitment, STAKE.into())
+        .dispatch_bypass_filter(RawOrigin::Signed(voter.clone()).into())
+        .expect("member must be able to vote");
+
+    (voter, commitment)
+}
+
+benchmarks! {
+    _ { }
+
+    apply {
+        start_election::<T>();
+        let applicant = insert_member::<T>("applicant", 0);
+    }: _(RawOrigin::Signed(applicant.clone()), STAKE.into())
+    verify {
+        assert!(Module::<T>::applicants().contains(&applicant));
+    }
+
+    vote {
+        start_election::<T>();
+        let applicant = insert_applicant::<T>(0);
+        dispatch_as_root::<T>(Call::set_stage_voting(stage_ends_at::<T>()));
+        let voter = insert_member::<T>("voter", 0);
+        let commitment = commitment::<T>(&applicant, &salt(0));
+    }: _(RawOrigin::Signed(voter), commitment, STAKE.into())
+    verify {
+        assert!(Module::<T>::commitments().contains(&commitment));
+    }
+
+    reveal {
+        start_election::<T>();
+        let applicant = insert_applicant::<T>(0);
+        dispatch_as_root::<T>(Call::set_stage_voting(stage_ends_at::<T>()));
+        let (voter, commitment) = insert_vote::<T>(0, &applicant);
+        dispatch_as_root::<T>(Call::set_stage_revealing(stage_ends_at::<T>()));
+    }: _(RawOrigin::Signed(voter), commitment, applicant.clone(), salt(0))
+    verify {
+        assert_eq!(Module::<T>::votes(commitment).get_vote(), &Some(applicant));
+    }
+
+    set_stage_announcing {
+        let ends_at = stage_ends_at::<T>();
+    }: _(RawOrigin::Root, ends_at)
+    verify {
+        assert_eq!(Module::<T>::stage_ends_at(), Some(ends_at));
+    }
+
+    set_stage_revealing {
+        let ends_at = stage_ends_at::<T>();
+    }: _(RawOrigin::Root, ends_at)
+    verify {
+        assert_eq!(Module::<T>::stage_ends_at(), Some(ends_at));
+    }
+
+    set_stage_voting {
+        let ends_at = stage_ends_at::<T>();
+    }: _(RawOrigin::Root, ends_at)
+    verify {
+        assert_eq!(Module::<T>::stage_ends_at(), Some(ends_at));
+    }
+
+    set_election_parameters {
+    }: _(RawOrigin::Root, election_parameters::<T>())
+    verify {
+        assert_eq!(Module::<T>::council_size(), MAX_COUNCIL_SIZE);
+    }
+
+    force_stop_election {
+        start_election::<T>();
+        let applicants = (0..MAX_COUNCIL_SIZE)
+            .map(insert_applicant::<T>)
+            .collect::<Vec<_>>();
+        dispatch_as_root::<T>(Call::set_stage_voting(stage_ends_at::<T>()));
+        for (index, applicant) in applicants.iter().enumerate() {
+            insert_vote::<T>(index as u32, applicant);
+        }
+    }: _(RawOrigin::Root)
+    verify {
+        assert!(!Module::<T>::is_election_running());
+    }
+
+    force_start_election {
+        crate::council::Module::<T>::set_council(
+            RawOrigin::Root.into(),
+            accounts::<T>("councilor", MAX_COUNCIL_SIZE),
+        )?;
+        Module::<T>::set_election_parameters(RawOrigin::Root.into(), election_parameters::<T>())?;
+    }: _(RawOrigin::Root)
+    verify {
+        assert!(Module::<T>::is_election_running());
+    }
+
+    set_auto_start {
+    }: _(RawOrigin::Root, false)
+    verify {
+        assert!(!Module::<T>::auto_start());
+    }
+}
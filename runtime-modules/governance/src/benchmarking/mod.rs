@@ -0,0 +1,49 @@
+//! Governance modules benchmarking.
+
+mod council;
+mod election;
+
+use frame_benchmarking::account;
+use frame_support::traits::Currency;
+use frame_support::StorageValue;
+use sp_std::prelude::*;
+use system::RawOrigin;
+
+use common::currency::GovernanceCurrency;
+
+const SEED: u32 = 0;
+const FREE_BALANCE: u32 = 1_000_000;
+
+/// Largest council the benchmarks set up, matches the maximal council size allowed by the
+/// 'set election parameters' proposal.
+pub(crate) const MAX_COUNCIL_SIZE: u32 = 20;
+
+/// Creates the given number of accounts.
+pub(crate) fn accounts<T: system::Trait>(name: &'static str, count: u32) -> Vec<T::AccountId> {
+    (0..count).map(|index| account(name, index, SEED)).collect()
+}
+
+/// Registers a funded member able to take part in the council elections.
+pub(crate) fn insert_member<T: crate::election::Trait>(
+    name: &'static str,
+    index: u32,
+) -> T::AccountId {
+    let account_id: T::AccountId = account(name, index, SEED);
+
+    let mut handle = name.as_bytes().to_vec();
+    handle.extend_from_slice(&index.to_be_bytes());
+
+    membership::ScreeningAuthority::<T>::put(account_id.clone());
+    membership::Module::<T>::add_screened_member(
+        RawOrigin::Signed(account_id.clone()).into(),
+        account_id.clone(),
+        Some(handle),
+        None,
+        None,
+    )
+    .expect("benchmark member must be registered");
+
+    <T as GovernanceCurrency>::Currency::make_free_balance_be(&account_id, FREE_BALANCE.into());
+
+    account_id
+}
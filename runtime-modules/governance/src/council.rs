@@ -1,3 +1,4 @@
+use frame_support::weights::Weight;
 use frame_support::{debug, decl_event, decl_module, decl_storage, ensure};
 use sp_arithmetic::traits::{One, Zero};
 use sp_std::vec;
@@ -26,6 +27,45 @@ pub trait Trait: system::Trait + recurringrewards::Trait + GovernanceCurrency {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     type CouncilTermEnded: CouncilTermEnded;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+}
+
+/// Weight functions needed for the council module.
+pub trait WeightInfo {
+    fn set_council(i: u32) -> Weight;
+    fn add_council_member() -> Weight;
+    fn remove_council_member() -> Weight;
+    fn set_term_ends_at() -> Weight;
+    fn set_council_mint_capacity() -> Weight;
+    fn spend_from_council_mint() -> Weight;
+    fn set_council_rewards() -> Weight;
+}
+
+/// Flat weights for the test runtimes.
+impl WeightInfo for () {
+    fn set_council(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn add_council_member() -> Weight {
+        10_000_000
+    }
+    fn remove_council_member() -> Weight {
+        10_000_000
+    }
+    fn set_term_ends_at() -> Weight {
+        10_000_000
+    }
+    fn set_council_mint_capacity() -> Weight {
+        10_000_000
+    }
+    fn spend_from_council_mint() -> Weight {
+        10_000_000
+    }
+    fn set_council_rewards() -> Weight {
+        10_000_000
+    }
 }
 
 decl_storage! {
@@ -164,7 +204,7 @@ decl_module! {
         /// Existing council rewards are removed and new council members do NOT get any rewards.
         /// Avoid using this call if possible, will be deprecated. The term of the new council is
         /// not extended.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_council(accounts.len() as u32)]
         pub fn set_council(origin, accounts: Vec<T::AccountId>) {
             ensure_root(origin)?;
 
@@ -187,7 +227,7 @@ decl_module! {
         }
 
         /// Adds a zero staked council member. A member added in this way does not get a recurring reward.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_council_member()]
         fn add_council_member(origin, account: T::AccountId) {
             ensure_root(origin)?;
 
@@ -206,7 +246,7 @@ decl_module! {
         }
 
         /// Remove a single council member and their reward.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::remove_council_member()]
         fn remove_council_member(origin, account_to_remove: T::AccountId) {
             ensure_root(origin)?;
 
@@ -226,7 +266,7 @@ decl_module! {
         }
 
         /// Set blocknumber when council term will end
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_term_ends_at()]
         fn set_term_ends_at(origin, ends_at: T::BlockNumber) {
             ensure_root(origin)?;
             ensure!(ends_at > <system::Module<T>>::block_number(), "must set future block number");
@@ -235,7 +275,7 @@ decl_module! {
 
         /// Sets the capacity of the the council mint, if it doesn't exist, attempts to
         /// create a new one.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_council_mint_capacity()]
         pub fn set_council_mint_capacity(origin, capacity: minting::BalanceOf<T>) {
             ensure_root(origin)?;
 
@@ -243,7 +283,7 @@ decl_module! {
         }
 
         /// Attempts to mint and transfer amount to destination account
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::spend_from_council_mint()]
         fn spend_from_council_mint(origin, amount: minting::BalanceOf<T>, destination: T::AccountId) {
             ensure_root(origin)?;
 
@@ -252,7 +292,7 @@ decl_module! {
         }
 
         /// Sets the council rewards which is only applied on new council being elected.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_council_rewards()]
         fn set_council_rewards(
             origin,
             amount_per_payout: minting::BalanceOf<T>,
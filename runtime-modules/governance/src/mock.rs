@@ -63,11 +63,13 @@ impl council::Trait for Test {
     type Event = ();
 
     type CouncilTermEnded = (Election,);
+    type WeightInfo = ();
 }
 impl election::Trait for Test {
     type Event = ();
 
     type CouncilElected = (Council,);
+    type WeightInfo = ();
 }
 impl membership::Trait for Test {
     type Event = ();
@@ -75,6 +77,7 @@ impl membership::Trait for Test {
     type SubscriptionId = u32;
     type PaidTermId = u32;
     type ActorId = u32;
+    type WeightInfo = ();
 }
 impl minting::Trait for Test {
     type Currency = Balances;
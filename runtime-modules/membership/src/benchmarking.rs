@@ -0,0 +1,509 @@
+//! Membership module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use sp_std::prelude::*;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+
+// Builds a unique handle of the given length.
+fn handle_of_length(id: u32, len: u32) -> Vec<u8> {
+    let mut handle = b"member".to_vec();
+    handle.extend_from_slice(&id.to_be_bytes());
+    handle.resize(len as usize, b'x');
+    handle
+}
+
+fn max_avatar<T: Trait>() -> Vec<u8> {
+    vec![b'a'; Module::<T>::max_avatar_uri_length() as usize]
+}
+
+fn max_about<T: Trait>() -> Vec<u8> {
+    vec![b'b'; Module::<T>::max_about_text_length() as usize]
+}
+
+/// Registers a new member through the genesis path and returns its id and account.
+fn insert_benchmark_member<T: Trait>(id: u32) -> (T::MemberId, T::AccountId) {
+    let account_id: T::AccountId = account("member", id, SEED);
+    let user_info = ValidatedUserInfo {
+        handle: handle_of_length(id, Module::<T>::max_handle_length()),
+        avatar_uri: max_avatar::<T>(),
+        about: max_about::<T>(),
+    };
+
+    let member_id = Module::<T>::insert_member(
+        &account_id,
+        &account_id,
+        &user_info,
+        EntryMethod::Genesis,
+        <system::Module<T>>::block_number(),
+        <pallet_timestamp::Module<T>>::now(),
+    )
+    .expect("benchmark member must be valid");
+
+    (member_id, account_id)
+}
+
+benchmarks! {
+    _ { }
+
+    buy_membership {
+        let (referrer_id, _) = insert_benchmark_member::<T>(1);
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let paid_terms_id = T::PaidTermId::from(DEFAULT_PAID_TERM_ID);
+        let fee = BalanceOf::<T>::from(1000u32);
+        <PaidMembershipTermsById<T>>::insert(paid_terms_id, PaidMembershipTerms {
+            fee,
+            text: Vec::new(),
+        });
+        <ActivePaidMembershipTerms<T>>::put(vec![paid_terms_id]);
+        <ReferralCutByPaidTermId<T>>::insert(paid_terms_id, Percent::from_percent(50));
+        T::Currency::make_free_balance_be(&caller, fee);
+        let handle = handle_of_length(0, Module::<T>::max_handle_length());
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        paid_terms_id,
+        Some(handle.clone()),
+        Some(max_avatar::<T>()),
+        Some(max_about::<T>()),
+        Some(referrer_id)
+    )
+    verify {
+        assert!(<MemberIdByHandle<T>>::contains_key(handle));
+        assert!(<MemberIdsByControllerAccountId<T>>::contains_key(caller));
+    }
+
+    change_member_about_text {
+        let (member_id, account_id) = insert_benchmark_member::<T>(0);
+        let text = vec![b'c'; Module::<T>::max_about_text_length() as usize];
+    }: _(RawOrigin::Signed(account_id), member_id, text.clone())
+    verify {
+        assert_eq!(Module::<T>::membership(member_id).about, text);
+    }
+
+    change_member_avatar {
+        let (member_id, account_id) = insert_benchmark_member::<T>(0);
+        let uri = vec![b'c'; Module::<T>::max_avatar_uri_length() as usize];
+    }: _(RawOrigin::Signed(account_id), member_id, uri.clone())
+    verify {
+        assert_eq!(Module::<T>::membership(member_id).avatar_uri, uri);
+    }
+
+    change_member_handle {
+        let (member_id, account_id) = insert_benchmark_member::<T>(0);
+        let handle = handle_of_length(1, Module::<T>::max_handle_length());
+    }: _(RawOrigin::Signed(account_id), member_id, handle.clone())
+    verify {
+        assert_eq!(Module::<T>::membership(member_id).handle, handle);
+    }
+
+    update_membership {
+        let (member_id, account_id) = insert_benchmark_member::<T>(0);
+        let handle = handle_of_length(1, Module::<T>::max_handle_length());
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        Some(handle.clone()),
+        Some(max_avatar::<T>()),
+        Some(max_about::<T>())
+    )
+    verify {
+        assert_eq!(Module::<T>::membership(member_id).handle, handle);
+    }
+
+    set_controller_account {
+        let (member_id, account_id) = insert_benchmark_member::<T>(0);
+        let new_controller_account: T::AccountId = account("controller", 0, SEED);
+    }: _(RawOrigin::Signed(account_id), member_id, new_controller_account.clone())
+    verify {
+        assert_eq!(Module::<T>::membership(member_id).controller_account, new_controller_account);
+    }
+
+    set_root_account {
+        let (member_id, account_id) = insert_benchmark_member::<T>(0);
+        let new_root_account: T::AccountId = account("root", 0, SEED);
+    }: _(RawOrigin::Signed(account_id), member_id, new_root_account.clone())
+    verify {
+        assert_eq!(Module::<T>::membership(member_id).root_account, new_root_account);
+    }
+
+    add_screened_member {
+        let screener: T::AccountId = account("screener", 0, SEED);
+        <ScreeningAuthority<T>>::put(screener.clone());
+        let new_member_account: T::AccountId = account("member", 0, SEED);
+        let handle = handle_of_length(0, Module::<T>::max_handle_length());
+    }: _(
+        RawOrigin::Signed(screener),
+        new_member_account,
+        Some(handle.clone()),
+        Some(max_avatar::<T>()),
+        Some(max_about::<T>())
+    )
+    verify {
+        assert!(<MemberIdByHandle<T>>::contains_key(handle));
+    }
+
+    set_screening_authority {
+        let authority: T::AccountId = account("screener", 0, SEED);
+    }: _(RawOrigin::Root, authority.clone())
+    verify {
+        assert_eq!(Module::<T>::screening_authority(), authority);
+    }
+
+    add_verifier {
+        let verifier: T::AccountId = account("verifier", 0, SEED);
+    }: _(RawOrigin::Root, verifier.clone())
+    verify {
+        assert!(Module::<T>::is_verifier(verifier));
+    }
+
+    remove_verifier {
+        let verifier: T::AccountId = account("verifier", 0, SEED);
+        <Verifiers<T>>::insert(&verifier, true);
+    }: _(RawOrigin::Root, verifier.clone())
+    verify {
+        assert!(!Module::<T>::is_verifier(verifier));
+    }
+
+    attest_member {
+        let (member_id, _) = insert_benchmark_member::<T>(0);
+        let verifier: T::AccountId = account("verifier", 0, SEED);
+        <Verifiers<T>>::insert(&verifier, true);
+        let data = vec![b'd'; MAX_ATTESTATION_DATA_LENGTH];
+        let expires_at = <system::Module<T>>::block_number() + T::BlockNumber::from(1000u32);
+    }: _(
+        RawOrigin::Signed(verifier),
+        member_id,
+        AttestationKind::SocialAccount,
+        data,
+        Some(expires_at)
+    )
+    verify {
+        assert!(Module::<T>::has_valid_attestation(&member_id, AttestationKind::SocialAccount));
+    }
+
+    revoke_attestation {
+        let (member_id, _) = insert_benchmark_member::<T>(0);
+        let verifier: T::AccountId = account("verifier", 0, SEED);
+        <Verifiers<T>>::insert(&verifier, true);
+        <Attestations<T>>::insert(member_id, AttestationKind::KycLight, Attestation {
+            verifier: verifier.clone(),
+            data: Vec::new(),
+            attested_at: <system::Module<T>>::block_number(),
+            expires_at: None,
+        });
+    }: _(RawOrigin::Signed(verifier), member_id, AttestationKind::KycLight)
+    verify {
+        assert!(!<Attestations<T>>::contains_key(member_id, AttestationKind::KycLight));
+    }
+
+    invite_member {
+        let (inviter_id, inviter_account) = insert_benchmark_member::<T>(0);
+        let invited_member_balance = BalanceOf::<T>::from(1000u32);
+        <CurrentInvitationTerms<T>>::put(InvitationTerms {
+            invites_per_member: 1,
+            invited_member_balance,
+            invited_member_lock_period: T::BlockNumber::from(1000u32),
+        });
+        <InvitesByMemberId<T>>::insert(inviter_id, 1);
+        T::Currency::make_free_balance_be(&inviter_account, invited_member_balance * 10u32.into());
+        let new_member_account: T::AccountId = account("invited", 0, SEED);
+        let handle = handle_of_length(1, Module::<T>::max_handle_length());
+    }: _(
+        RawOrigin::Signed(inviter_account),
+        inviter_id,
+        new_member_account.clone(),
+        Some(handle.clone()),
+        Some(max_avatar::<T>()),
+        Some(max_about::<T>())
+    )
+    verify {
+        assert!(<MemberIdByHandle<T>>::contains_key(handle));
+        assert!(<InvitationLockExpiresAt<T>>::contains_key(new_member_account));
+    }
+
+    set_member_invites_count {
+        let (member_id, _) = insert_benchmark_member::<T>(0);
+    }: _(RawOrigin::Root, member_id, 10)
+    verify {
+        assert_eq!(Module::<T>::invites_by_member_id(member_id), 10);
+    }
+
+    set_invitation_terms {
+        let terms = InvitationTerms {
+            invites_per_member: 5,
+            invited_member_balance: BalanceOf::<T>::from(1000u32),
+            invited_member_lock_period: T::BlockNumber::from(1000u32),
+        };
+    }: _(RawOrigin::Root, terms.clone())
+    verify {
+        assert_eq!(Module::<T>::invitation_terms(), terms);
+    }
+
+    set_referral_cut {
+        let paid_terms_id = T::PaidTermId::from(DEFAULT_PAID_TERM_ID);
+        <PaidMembershipTermsById<T>>::insert(paid_terms_id, PaidMembershipTerms::default());
+    }: _(RawOrigin::Root, paid_terms_id, Percent::from_percent(10))
+    verify {
+        assert_eq!(
+            Module::<T>::referral_cut_by_paid_term_id(paid_terms_id),
+            Percent::from_percent(10)
+        );
+    }
+
+    release_handle {
+        let (member_id, account_id) = insert_benchmark_member::<T>(0);
+        let handle = Module::<T>::membership(member_id).handle;
+    }: _(RawOrigin::Signed(account_id), member_id)
+    verify {
+        assert!(!<MemberIdByHandle<T>>::contains_key(handle));
+    }
+
+    approve_handle_transfer {
+        let (member_id, account_id) = insert_benchmark_member::<T>(0);
+        let (recipient_member_id, _) = insert_benchmark_member::<T>(1);
+    }: _(RawOrigin::Signed(account_id), member_id, recipient_member_id)
+    verify {
+        assert_eq!(
+            Module::<T>::handle_transfer_approval(member_id),
+            Some(recipient_member_id)
+        );
+    }
+
+    transfer_handle {
+        let (owner_member_id, _) = insert_benchmark_member::<T>(0);
+        let (recipient_member_id, recipient_account_id) = insert_benchmark_member::<T>(1);
+        <HandleTransferApprovals<T>>::insert(owner_member_id, recipient_member_id);
+        let handle = Module::<T>::membership(owner_member_id).handle;
+    }: _(RawOrigin::Signed(recipient_account_id), owner_member_id, recipient_member_id)
+    verify {
+        assert_eq!(Module::<T>::handles(handle), recipient_member_id);
+    }
+
+    reserve_handle {
+        let handle = handle_of_length(0, Module::<T>::max_handle_length());
+    }: _(RawOrigin::Root, handle.clone())
+    verify {
+        assert!(Module::<T>::is_reserved_handle(Module::<T>::normalize_handle(&handle)));
+    }
+
+    unreserve_handle {
+        let handle = handle_of_length(0, Module::<T>::max_handle_length());
+        ReservedHandles::insert(Module::<T>::normalize_handle(&handle), true);
+    }: _(RawOrigin::Root, handle.clone())
+    verify {
+        assert!(!Module::<T>::is_reserved_handle(Module::<T>::normalize_handle(&handle)));
+    }
+
+    set_validation_limits {
+        let limits = ValidationLimits {
+            min_handle_length: 3,
+            max_handle_length: 50,
+            max_avatar_uri_length: 512,
+            max_about_text_length: 4096,
+        };
+    }: _(RawOrigin::Root, limits)
+    verify {
+        assert_eq!(Module::<T>::validation_limits(), limits);
+    }
+
+    remove_invitation_lock {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let locked_account: T::AccountId = account("invited", 0, SEED);
+        let locked_balance = BalanceOf::<T>::from(1000u32);
+        T::Currency::make_free_balance_be(&locked_account, locked_balance);
+        T::Currency::set_lock(
+            INVITED_MEMBER_LOCK_ID,
+            &locked_account,
+            locked_balance,
+            WithdrawReasons::all()
+        );
+        <InvitationLockExpiresAt<T>>::insert(&locked_account, <system::Module<T>>::block_number());
+        <system::Module<T>>::set_block_number(
+            <system::Module<T>>::block_number() + T::BlockNumber::from(1u32)
+        );
+    }: _(RawOrigin::Signed(caller), locked_account.clone())
+    verify {
+        assert!(!<InvitationLockExpiresAt<T>>::contains_key(locked_account));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{Test, TestExternalitiesBuilder};
+    use frame_support::assert_ok;
+
+    fn build_test_externalities() -> sp_io::TestExternalities {
+        TestExternalitiesBuilder::<Test>::default().build()
+    }
+
+    #[test]
+    fn test_buy_membership() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_buy_membership::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_change_member_about_text() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_change_member_about_text::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_change_member_avatar() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_change_member_avatar::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_change_member_handle() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_change_member_handle::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_update_membership() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_update_membership::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_set_controller_account() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_controller_account::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_set_root_account() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_root_account::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_add_screened_member() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_add_screened_member::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_set_screening_authority() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_screening_authority::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_add_verifier() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_add_verifier::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_remove_verifier() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_remove_verifier::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_attest_member() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_attest_member::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_revoke_attestation() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_revoke_attestation::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_invite_member() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_invite_member::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_set_member_invites_count() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_member_invites_count::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_set_invitation_terms() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_invitation_terms::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_set_referral_cut() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_referral_cut::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_release_handle() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_release_handle::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_approve_handle_transfer() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_approve_handle_transfer::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_transfer_handle() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_transfer_handle::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_reserve_handle() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_reserve_handle::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_unreserve_handle() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_unreserve_handle::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_set_validation_limits() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_set_validation_limits::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_remove_invitation_lock() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(test_benchmark_remove_invitation_lock::<Test>());
+        });
+    }
+}
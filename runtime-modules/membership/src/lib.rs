@@ -5,14 +5,23 @@
 // Example:  pub PaidMembershipTermsById get(paid_membership_terms_by_id) build(|config: &GenesisConfig<T>| {}
 #![allow(clippy::redundant_closure_call)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 pub mod genesis;
 pub(crate) mod mock;
 mod tests;
 
 use codec::{Codec, Decode, Encode};
-use frame_support::traits::Currency;
-use frame_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
-use sp_arithmetic::traits::{BaseArithmetic, One};
+use frame_support::storage::IterableStorageMap;
+use frame_support::traits::{
+    Currency, ExistenceRequirement, Imbalance, LockIdentifier, LockableCurrency, WithdrawReasons,
+};
+use frame_support::weights::Weight;
+use frame_support::{debug, decl_event, decl_module, decl_storage, ensure, Parameter};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
+use sp_arithmetic::Percent;
 use sp_runtime::traits::{MaybeSerialize, Member};
 use sp_std::borrow::ToOwned;
 use sp_std::vec;
@@ -65,6 +74,113 @@ pub trait Trait: system::Trait + GovernanceCurrency + pallet_timestamp::Trait {
         + MaybeSerialize
         + PartialEq
         + Ord;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+}
+
+/// Weight functions needed for the membership module.
+pub trait WeightInfo {
+    fn buy_membership() -> Weight;
+    fn change_member_about_text() -> Weight;
+    fn change_member_avatar() -> Weight;
+    fn change_member_handle() -> Weight;
+    fn update_membership() -> Weight;
+    fn set_controller_account() -> Weight;
+    fn set_root_account() -> Weight;
+    fn add_screened_member() -> Weight;
+    fn set_screening_authority() -> Weight;
+    fn add_verifier() -> Weight;
+    fn remove_verifier() -> Weight;
+    fn attest_member() -> Weight;
+    fn revoke_attestation() -> Weight;
+    fn invite_member() -> Weight;
+    fn set_member_invites_count() -> Weight;
+    fn set_invitation_terms() -> Weight;
+    fn remove_invitation_lock() -> Weight;
+    fn set_referral_cut() -> Weight;
+    fn release_handle() -> Weight;
+    fn approve_handle_transfer() -> Weight;
+    fn transfer_handle() -> Weight;
+    fn reserve_handle() -> Weight;
+    fn unreserve_handle() -> Weight;
+    fn set_validation_limits() -> Weight;
+}
+
+/// Flat weights for the test runtimes.
+impl WeightInfo for () {
+    fn buy_membership() -> Weight {
+        10_000_000
+    }
+    fn change_member_about_text() -> Weight {
+        10_000_000
+    }
+    fn change_member_avatar() -> Weight {
+        10_000_000
+    }
+    fn change_member_handle() -> Weight {
+        10_000_000
+    }
+    fn update_membership() -> Weight {
+        10_000_000
+    }
+    fn set_controller_account() -> Weight {
+        10_000_000
+    }
+    fn set_root_account() -> Weight {
+        10_000_000
+    }
+    fn add_screened_member() -> Weight {
+        10_000_000
+    }
+    fn set_screening_authority() -> Weight {
+        10_000_000
+    }
+    fn add_verifier() -> Weight {
+        10_000_000
+    }
+    fn remove_verifier() -> Weight {
+        10_000_000
+    }
+    fn attest_member() -> Weight {
+        10_000_000
+    }
+    fn revoke_attestation() -> Weight {
+        10_000_000
+    }
+    fn invite_member() -> Weight {
+        10_000_000
+    }
+    fn set_member_invites_count() -> Weight {
+        10_000_000
+    }
+    fn set_invitation_terms() -> Weight {
+        10_000_000
+    }
+    fn remove_invitation_lock() -> Weight {
+        10_000_000
+    }
+    fn set_referral_cut() -> Weight {
+        10_000_000
+    }
+    fn release_handle() -> Weight {
+        10_000_000
+    }
+    fn approve_handle_transfer() -> Weight {
+        10_000_000
+    }
+    fn transfer_handle() -> Weight {
+        10_000_000
+    }
+    fn reserve_handle() -> Weight {
+        10_000_000
+    }
+    fn unreserve_handle() -> Weight {
+        10_000_000
+    }
+    fn set_validation_limits() -> Weight {
+        10_000_000
+    }
 }
 
 const FIRST_PAID_TERMS_ID: u8 = 1;
@@ -78,6 +194,32 @@ const DEFAULT_MAX_HANDLE_LENGTH: u32 = 40;
 const DEFAULT_MAX_AVATAR_URI_LENGTH: u32 = 1024;
 const DEFAULT_MAX_ABOUT_TEXT_LENGTH: u32 = 2048;
 
+/// User input validation limits.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct ValidationLimits {
+    /// Minimum length of the member handle.
+    pub min_handle_length: u32,
+
+    /// Maximum length of the member handle.
+    pub max_handle_length: u32,
+
+    /// Maximum length of the member avatar uri.
+    pub max_avatar_uri_length: u32,
+
+    /// Maximum length of the member about text.
+    pub max_about_text_length: u32,
+}
+
+/// Lock identifier for the initial balance of the invited members.
+pub const INVITED_MEMBER_LOCK_ID: LockIdentifier = *b"invitemb";
+
+/// Maximum length of the attestation data.
+pub const MAX_ATTESTATION_DATA_LENGTH: usize = 256;
+
+// Version of the storage layout. Increased with every migration added to the `migrate()`.
+const STORAGE_VERSION: u32 = 2;
+
 /// Public membership object alias.
 pub type Membership<T> = MembershipObject<
     <T as system::Trait>::BlockNumber,
@@ -85,11 +227,12 @@ pub type Membership<T> = MembershipObject<
     <T as Trait>::PaidTermId,
     <T as Trait>::SubscriptionId,
     <T as system::Trait>::AccountId,
+    <T as Trait>::MemberId,
 >;
 
 #[derive(Encode, Decode, Default)]
 /// Stored information about a registered user
-pub struct MembershipObject<BlockNumber, Moment, PaidTermId, SubscriptionId, AccountId> {
+pub struct MembershipObject<BlockNumber, Moment, PaidTermId, SubscriptionId, AccountId, MemberId> {
     /// The unique handle chosen by member
     pub handle: Vec<u8>,
 
@@ -106,7 +249,7 @@ pub struct MembershipObject<BlockNumber, Moment, PaidTermId, SubscriptionId, Acc
     pub registered_at_time: Moment,
 
     /// How the member was registered
-    pub entry: EntryMethod<PaidTermId, AccountId>,
+    pub entry: EntryMethod<PaidTermId, AccountId, MemberId>,
 
     /// Whether the member is suspended or not.
     pub suspended: bool,
@@ -134,20 +277,69 @@ struct ValidatedUserInfo {
 }
 
 #[derive(Encode, Decode, Debug, PartialEq)]
-pub enum EntryMethod<PaidTermId, AccountId> {
+pub enum EntryMethod<PaidTermId, AccountId, MemberId> {
     Paid(PaidTermId),
     Screening(AccountId),
     Genesis,
+    /// Invited by the member.
+    Invited(MemberId),
 }
 
 /// Must be default constructible because it indirectly is a value in a storage map.
 /// ***SHOULD NEVER ACTUALLY GET CALLED, IS REQUIRED TO DUE BAD STORAGE MODEL IN SUBSTRATE***
-impl<PaidTermId, AccountId> Default for EntryMethod<PaidTermId, AccountId> {
+impl<PaidTermId, AccountId, MemberId> Default for EntryMethod<PaidTermId, AccountId, MemberId> {
     fn default() -> Self {
         Self::Genesis
     }
 }
 
+/// Terms of the member invitations.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, Default)]
+pub struct InvitationTerms<Balance, BlockNumber> {
+    /// Number of invites given to every new member.
+    pub invites_per_member: u32,
+
+    /// Balance transferred by the inviter to the invited member account.
+    pub invited_member_balance: Balance,
+
+    /// Number of blocks the invited member balance stays locked.
+    pub invited_member_lock_period: BlockNumber,
+}
+
+/// Public invitation terms alias.
+pub type InvitationTermsOf<T> = InvitationTerms<BalanceOf<T>, <T as system::Trait>::BlockNumber>;
+
+/// Kind of the member attestation.
+#[derive(Encode, Decode, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttestationKind {
+    /// Member owns the social account described by the attestation data.
+    SocialAccount,
+    /// Member passed the lightweight identity check.
+    KycLight,
+    /// Member is a founding member of the platform.
+    FoundingMember,
+}
+
+/// Public attestation alias.
+pub type AttestationOf<T> =
+    Attestation<<T as system::Trait>::AccountId, <T as system::Trait>::BlockNumber>;
+
+/// Attestation attached to a member by a verifier.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct Attestation<AccountId, BlockNumber> {
+    /// Verifier account which attached the attestation.
+    pub verifier: AccountId,
+
+    /// Attestation details (e.g. the verified social account).
+    pub data: Vec<u8>,
+
+    /// Block number when the attestation was attached.
+    pub attested_at: BlockNumber,
+
+    /// Block number after which the attestation is no longer valid. None for the permanent ones.
+    pub expires_at: Option<BlockNumber>,
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, Default)]
 pub struct PaidMembershipTerms<Balance> {
     /// Quantity of native tokens which must be provably burned
@@ -178,6 +370,19 @@ decl_storage! {
         pub MemberIdByHandle get(fn handles) : map hasher(blake2_128_concat)
             Vec<u8> => T::MemberId;
 
+        /// Registered handles in the case-insensitive form and their mapping to their owner.
+        /// Used to check the handle uniqueness.
+        pub MemberIdByNormalizedHandle get(fn member_id_by_normalized_handle) :
+            map hasher(blake2_128_concat) Vec<u8> => Option<T::MemberId>;
+
+        /// Handles (in the case-insensitive form) blocked from the registration.
+        pub ReservedHandles get(fn is_reserved_handle) : map hasher(blake2_128_concat)
+            Vec<u8> => bool;
+
+        /// Handle transfers approved by the current handle owner: owner => recipient.
+        pub HandleTransferApprovals get(fn handle_transfer_approval) : map hasher(blake2_128_concat)
+            T::MemberId => Option<T::MemberId>;
+
         /// Next paid membership terms id
         pub NextPaidMembershipTermsId get(fn next_paid_membership_terms_id) :
             T::PaidTermId = T::PaidTermId::from(FIRST_PAID_TERMS_ID);
@@ -201,18 +406,47 @@ decl_storage! {
         pub ActivePaidMembershipTerms get(fn active_paid_membership_terms) :
             Vec<T::PaidTermId> = vec![T::PaidTermId::from(DEFAULT_PAID_TERM_ID)];
 
+        /// Share of the paid membership terms fee rewarded to the referrer of the new member.
+        pub ReferralCutByPaidTermId get(fn referral_cut_by_paid_term_id) : map hasher(blake2_128_concat)
+            T::PaidTermId => Percent;
+
         /// Is the platform is accepting new members or not
         pub NewMembershipsAllowed get(fn new_memberships_allowed) : bool = true;
 
         pub ScreeningAuthority get(fn screening_authority) : T::AccountId;
 
-        // User Input Validation parameters - do these really need to be state variables
-        // I don't see a need to adjust these in future?
+        /// Terms of the member invitations.
+        pub CurrentInvitationTerms get(fn invitation_terms) : InvitationTermsOf<T>;
+
+        /// Number of the invites left for the member.
+        pub InvitesByMemberId get(fn invites_by_member_id) : map hasher(blake2_128_concat)
+            T::MemberId => u32;
+
+        /// Members invited by the member (invite tree edges). The inviter of the member is
+        /// stored in the member entry method.
+        pub InvitedMemberIds get(fn invited_member_ids) : map hasher(blake2_128_concat)
+            T::MemberId => Vec<T::MemberId>;
+
+        /// Block after which the invited member balance lock of the account can be removed.
+        pub InvitationLockExpiresAt get(fn invitation_lock_expires_at) : map hasher(blake2_128_concat)
+            T::AccountId => Option<T::BlockNumber>;
+
+        /// Accounts allowed to attach and revoke the member attestations.
+        pub Verifiers get(fn is_verifier) : map hasher(blake2_128_concat) T::AccountId => bool;
+
+        /// Attestations of the members by their kind.
+        pub Attestations get(fn attestation) : double_map hasher(blake2_128_concat)
+            T::MemberId, hasher(blake2_128_concat) AttestationKind => Option<AttestationOf<T>>;
+
+        // User Input Validation parameters - adjustable by root with set_validation_limits().
+        // Tightened limits apply to the new input only: existing member profiles are kept as is.
         pub MinHandleLength get(fn min_handle_length) : u32 = DEFAULT_MIN_HANDLE_LENGTH;
         pub MaxHandleLength get(fn max_handle_length) : u32 = DEFAULT_MAX_HANDLE_LENGTH;
         pub MaxAvatarUriLength get(fn max_avatar_uri_length) : u32 = DEFAULT_MAX_AVATAR_URI_LENGTH;
         pub MaxAboutTextLength get(fn max_about_text_length) : u32 = DEFAULT_MAX_ABOUT_TEXT_LENGTH;
 
+        /// Version of the storage layout. New chains start with the current version.
+        pub StorageVersion get(fn storage_version) build(|_: &GenesisConfig<T>| STORAGE_VERSION): u32;
     }
     add_extra_genesis {
         config(default_paid_membership_fee): BalanceOf<T>;
@@ -245,6 +479,8 @@ decl_event! {
     pub enum Event<T> where
       <T as system::Trait>::AccountId,
       <T as Trait>::MemberId,
+      <T as Trait>::PaidTermId,
+      Balance = BalanceOf<T>,
     {
         MemberRegistered(MemberId, AccountId),
         MemberUpdatedAboutText(MemberId),
@@ -252,6 +488,25 @@ decl_event! {
         MemberUpdatedHandle(MemberId),
         MemberSetRootAccount(MemberId, AccountId),
         MemberSetControllerAccount(MemberId, AccountId),
+        VerifierAdded(AccountId),
+        VerifierRemoved(AccountId),
+        MemberAttested(MemberId, AttestationKind, AccountId),
+        AttestationRevoked(MemberId, AttestationKind, AccountId),
+        MemberInvited(MemberId, MemberId),
+        MemberInvitesCountUpdated(MemberId, u32),
+        InvitationTermsUpdated,
+        InvitationLockRemoved(AccountId),
+        ReferralCutUpdated(PaidTermId, Percent),
+        ReferralRewardPaid(MemberId, MemberId, Balance),
+        MemberReleasedHandle(MemberId),
+        HandleTransferApproved(MemberId, MemberId),
+        HandleTransferred(MemberId, MemberId),
+        HandleReserved(Vec<u8>),
+        HandleUnreserved(Vec<u8>),
+        /// Emits on the handles migration for the member whose handle differs only in the case
+        /// from the handle of the member indexed first. Params: member id, indexed member id.
+        NormalizedHandleCollision(MemberId, MemberId),
+        ValidationLimitsUpdated(ValidationLimits),
     }
 }
 
@@ -259,14 +514,16 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
-        /// Non-members can buy membership
-        #[weight = 10_000_000] // TODO: adjust weight
+        /// Non-members can buy membership. The referrer member (if any) is rewarded with the
+        /// referral cut of the paid terms fee.
+        #[weight = T::WeightInfo::buy_membership()]
         pub fn buy_membership(
             origin,
             paid_terms_id: T::PaidTermId,
             handle: Option<Vec<u8>>,
             avatar_uri: Option<Vec<u8>>,
-            about: Option<Vec<u8>>
+            about: Option<Vec<u8>>,
+            referrer_id: Option<T::MemberId>
         ) {
             let who = ensure_signed(origin)?;
 
@@ -279,6 +536,11 @@ decl_module! {
             // ensure enough free balance to cover terms fees
             ensure!(T::Currency::can_slash(&who, terms.fee), "not enough balance to buy membership");
 
+            let referrer = match referrer_id {
+                Some(referrer_id) => Some((referrer_id, Self::ensure_membership(referrer_id)?)),
+                None => None,
+            };
+
             let user_info = Self::check_user_registration_info(handle, avatar_uri, about)?;
 
             let member_id = Self::insert_member(
@@ -293,10 +555,36 @@ decl_module! {
             let _ = T::Currency::slash(&who, terms.fee);
 
             Self::deposit_event(RawEvent::MemberRegistered(member_id, who));
+
+            if let Some((referrer_id, referrer)) = referrer {
+                let reward = Self::referral_cut_by_paid_term_id(paid_terms_id) * terms.fee;
+
+                // Rewards below the existential deposit are dropped for the new referrer accounts.
+                let paid_reward =
+                    T::Currency::deposit_creating(&referrer.controller_account, reward).peek();
+
+                if !paid_reward.is_zero() {
+                    Self::deposit_event(RawEvent::ReferralRewardPaid(referrer_id, member_id, paid_reward));
+                }
+            }
+        }
+
+        /// Sets the share of the paid terms fee rewarded to the referrer. Requires root privileges.
+        #[weight = T::WeightInfo::set_referral_cut()]
+        pub fn set_referral_cut(origin, paid_terms_id: T::PaidTermId, referral_cut: Percent) {
+            ensure_root(origin)?;
+
+            ensure!(
+                <PaidMembershipTermsById<T>>::contains_key(paid_terms_id),
+                "paid membership term id does not exist"
+            );
+
+            <ReferralCutByPaidTermId<T>>::insert(paid_terms_id, referral_cut);
+            Self::deposit_event(RawEvent::ReferralCutUpdated(paid_terms_id, referral_cut));
         }
 
         /// Change member's about text
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::change_member_about_text()]
         pub fn change_member_about_text(origin, member_id: T::MemberId, text: Vec<u8>) {
             let sender = ensure_signed(origin)?;
 
@@ -308,7 +596,7 @@ decl_module! {
         }
 
         /// Change member's avatar
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::change_member_avatar()]
         pub fn change_member_avatar(origin, member_id: T::MemberId, uri: Vec<u8>) {
             let sender = ensure_signed(origin)?;
 
@@ -321,7 +609,7 @@ decl_module! {
 
         /// Change member's handle. Will ensure new handle is unique and old one will be available
         /// for other members to use.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::change_member_handle()]
         pub fn change_member_handle(origin, member_id: T::MemberId, handle: Vec<u8>) {
             let sender = ensure_signed(origin)?;
 
@@ -332,8 +620,121 @@ decl_module! {
             Self::_change_member_handle(member_id, handle)?;
         }
 
+        /// Release member's handle, making it available for the registration.
+        #[weight = T::WeightInfo::release_handle()]
+        pub fn release_handle(origin, member_id: T::MemberId) {
+            let sender = ensure_signed(origin)?;
+
+            let mut membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == sender, "only controller account can update member handle");
+
+            ensure!(!membership.handle.is_empty(), "member has no handle");
+
+            Self::unregister_handle(member_id, &membership.handle);
+            membership.handle = Vec::new();
+            <MembershipById<T>>::insert(member_id, membership);
+
+            Self::deposit_event(RawEvent::MemberReleasedHandle(member_id));
+        }
+
+        /// Approve the transfer of the member's handle to the recipient member. The transfer is
+        /// completed by the recipient with the transfer_handle() call.
+        #[weight = T::WeightInfo::approve_handle_transfer()]
+        pub fn approve_handle_transfer(
+            origin,
+            member_id: T::MemberId,
+            recipient_member_id: T::MemberId
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == sender, "only controller account can update member handle");
+
+            ensure!(!membership.handle.is_empty(), "member has no handle");
+
+            ensure!(member_id != recipient_member_id, "cannot transfer handle to the same member");
+
+            Self::ensure_membership(recipient_member_id)?;
+
+            <HandleTransferApprovals<T>>::insert(member_id, recipient_member_id);
+
+            Self::deposit_event(RawEvent::HandleTransferApproved(member_id, recipient_member_id));
+        }
+
+        /// Transfer the handle from the owner member to the recipient member. Requires the
+        /// transfer approval of the owner and the recipient controller account signature.
+        /// Recipient's current handle is released.
+        #[weight = T::WeightInfo::transfer_handle()]
+        pub fn transfer_handle(
+            origin,
+            owner_member_id: T::MemberId,
+            recipient_member_id: T::MemberId
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let mut recipient = Self::ensure_membership(recipient_member_id)?;
+
+            ensure!(recipient.controller_account == sender, "only controller account can update member handle");
+
+            ensure!(
+                Self::handle_transfer_approval(owner_member_id) == Some(recipient_member_id),
+                "handle transfer not approved"
+            );
+
+            let mut owner = Self::ensure_membership(owner_member_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <HandleTransferApprovals<T>>::remove(owner_member_id);
+
+            if !recipient.handle.is_empty() {
+                Self::unregister_handle(recipient_member_id, &recipient.handle);
+            }
+
+            Self::unregister_handle(owner_member_id, &owner.handle);
+            Self::register_handle(recipient_member_id, &owner.handle);
+
+            recipient.handle = owner.handle;
+            owner.handle = Vec::new();
+
+            <MembershipById<T>>::insert(owner_member_id, owner);
+            <MembershipById<T>>::insert(recipient_member_id, recipient);
+
+            Self::deposit_event(RawEvent::HandleTransferred(owner_member_id, recipient_member_id));
+        }
+
+        /// Block the handle from the registration. Requires root privileges.
+        #[weight = T::WeightInfo::reserve_handle()]
+        pub fn reserve_handle(origin, handle: Vec<u8>) {
+            ensure_root(origin)?;
+
+            Self::validate_handle(&handle)?;
+
+            ReservedHandles::insert(Self::normalize_handle(&handle), true);
+
+            Self::deposit_event(RawEvent::HandleReserved(handle));
+        }
+
+        /// Unblock the reserved handle. Requires root privileges.
+        #[weight = T::WeightInfo::unreserve_handle()]
+        pub fn unreserve_handle(origin, handle: Vec<u8>) {
+            ensure_root(origin)?;
+
+            let normalized_handle = Self::normalize_handle(&handle);
+
+            ensure!(Self::is_reserved_handle(&normalized_handle), "handle not reserved");
+
+            ReservedHandles::remove(normalized_handle);
+
+            Self::deposit_event(RawEvent::HandleUnreserved(handle));
+        }
+
         /// Update member's all or some of handle, avatar and about text.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_membership()]
         pub fn update_membership(
             origin,
             member_id: T::MemberId,
@@ -358,7 +759,7 @@ decl_module! {
             }
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_controller_account()]
         pub fn set_controller_account(origin, member_id: T::MemberId, new_controller_account: T::AccountId) {
             let sender = ensure_signed(origin)?;
 
@@ -382,7 +783,7 @@ decl_module! {
             }
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_root_account()]
         pub fn set_root_account(origin, member_id: T::MemberId, new_root_account: T::AccountId) {
             let sender = ensure_signed(origin)?;
 
@@ -406,7 +807,7 @@ decl_module! {
             }
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_screened_member()]
         pub fn add_screened_member(
             origin,
             new_member_account: T::AccountId,
@@ -441,11 +842,208 @@ decl_module! {
             Self::deposit_event(RawEvent::MemberRegistered(member_id, new_member_account));
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_screening_authority()]
         pub fn set_screening_authority(origin, authority: T::AccountId) {
             ensure_root(origin)?;
             <ScreeningAuthority<T>>::put(authority);
         }
+
+        /// Sets the user input validation limits. Requires root privileges.
+        #[weight = T::WeightInfo::set_validation_limits()]
+        pub fn set_validation_limits(origin, limits: ValidationLimits) {
+            ensure_root(origin)?;
+
+            Self::ensure_valid_validation_limits(&limits)?;
+
+            MinHandleLength::put(limits.min_handle_length);
+            MaxHandleLength::put(limits.max_handle_length);
+            MaxAvatarUriLength::put(limits.max_avatar_uri_length);
+            MaxAboutTextLength::put(limits.max_about_text_length);
+
+            Self::deposit_event(RawEvent::ValidationLimitsUpdated(limits));
+        }
+
+        /// Registers the new member invited by the existing one. Consumes the inviter invite
+        /// instead of the membership fee. The invited member balance (if any) is transferred from
+        /// the inviter controller account and stays locked for the invitation lock period.
+        #[weight = T::WeightInfo::invite_member()]
+        pub fn invite_member(
+            origin,
+            inviter_id: T::MemberId,
+            new_member_account: T::AccountId,
+            handle: Option<Vec<u8>>,
+            avatar_uri: Option<Vec<u8>>,
+            about: Option<Vec<u8>>
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let inviter = Self::ensure_membership(inviter_id)?;
+
+            ensure!(inviter.controller_account == sender, "only controller account can invite members");
+
+            // make sure we are accepting new memberships
+            ensure!(Self::new_memberships_allowed(), "new members not allowed");
+
+            ensure!(Self::invites_by_member_id(inviter_id) > 0, "no invites left");
+
+            let user_info = Self::check_user_registration_info(handle, avatar_uri, about)?;
+            Self::ensure_unique_handle(&user_info.handle)?;
+
+            let terms = Self::invitation_terms();
+
+            ensure!(
+                T::Currency::free_balance(&sender) >= terms.invited_member_balance,
+                "not enough balance to invite member"
+            );
+
+            // The transfer fails on the locked and reserved balance or below the existential
+            // deposit, so it goes first and the member is registered only after it succeeds.
+            if !terms.invited_member_balance.is_zero() {
+                T::Currency::transfer(
+                    &sender,
+                    &new_member_account,
+                    terms.invited_member_balance,
+                    ExistenceRequirement::AllowDeath
+                )?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let now = <system::Module<T>>::block_number();
+
+            let member_id = Self::insert_member(
+                &new_member_account,
+                &new_member_account,
+                &user_info,
+                EntryMethod::Invited(inviter_id),
+                now,
+                <pallet_timestamp::Module<T>>::now()
+            )?;
+
+            if !terms.invited_member_balance.is_zero() {
+                T::Currency::set_lock(
+                    INVITED_MEMBER_LOCK_ID,
+                    &new_member_account,
+                    terms.invited_member_balance,
+                    WithdrawReasons::all()
+                );
+
+                <InvitationLockExpiresAt<T>>::insert(
+                    &new_member_account,
+                    now + terms.invited_member_lock_period
+                );
+            }
+
+            <InvitesByMemberId<T>>::mutate(inviter_id, |invites| *invites -= 1);
+            <InvitedMemberIds<T>>::mutate(inviter_id, |ids| ids.push(member_id));
+
+            Self::deposit_event(RawEvent::MemberRegistered(member_id, new_member_account));
+            Self::deposit_event(RawEvent::MemberInvited(member_id, inviter_id));
+        }
+
+        /// Removes the invited member balance lock after the invitation lock period.
+        #[weight = T::WeightInfo::remove_invitation_lock()]
+        pub fn remove_invitation_lock(origin, account: T::AccountId) {
+            ensure_signed(origin)?;
+
+            let expires_at = Self::invitation_lock_expires_at(&account)
+                .ok_or("invitation lock not found")?;
+
+            ensure!(<system::Module<T>>::block_number() > expires_at, "invitation lock not expired");
+
+            T::Currency::remove_lock(INVITED_MEMBER_LOCK_ID, &account);
+            <InvitationLockExpiresAt<T>>::remove(&account);
+
+            Self::deposit_event(RawEvent::InvitationLockRemoved(account));
+        }
+
+        /// Sets the number of the invites left for the member. Requires root privileges.
+        #[weight = T::WeightInfo::set_member_invites_count()]
+        pub fn set_member_invites_count(origin, member_id: T::MemberId, invites_count: u32) {
+            ensure_root(origin)?;
+
+            Self::ensure_membership(member_id)?;
+
+            <InvitesByMemberId<T>>::insert(member_id, invites_count);
+            Self::deposit_event(RawEvent::MemberInvitesCountUpdated(member_id, invites_count));
+        }
+
+        /// Sets the terms of the member invitations. Requires root privileges.
+        #[weight = T::WeightInfo::set_invitation_terms()]
+        pub fn set_invitation_terms(origin, terms: InvitationTermsOf<T>) {
+            ensure_root(origin)?;
+
+            <CurrentInvitationTerms<T>>::put(terms);
+            Self::deposit_event(RawEvent::InvitationTermsUpdated);
+        }
+
+        /// Allows the account (e.g. a working group lead role account) to attest the members.
+        /// Requires root privileges.
+        #[weight = T::WeightInfo::add_verifier()]
+        pub fn add_verifier(origin, verifier: T::AccountId) {
+            ensure_root(origin)?;
+
+            <Verifiers<T>>::insert(&verifier, true);
+            Self::deposit_event(RawEvent::VerifierAdded(verifier));
+        }
+
+        /// Disallows the account to attest the members. Existing attestations are kept.
+        /// Requires root privileges.
+        #[weight = T::WeightInfo::remove_verifier()]
+        pub fn remove_verifier(origin, verifier: T::AccountId) {
+            ensure_root(origin)?;
+
+            ensure!(Self::is_verifier(&verifier), "not verifier");
+
+            <Verifiers<T>>::remove(&verifier);
+            Self::deposit_event(RawEvent::VerifierRemoved(verifier));
+        }
+
+        /// Attaches the attestation to the member or replaces the existing one of the same kind.
+        #[weight = T::WeightInfo::attest_member()]
+        pub fn attest_member(
+            origin,
+            member_id: T::MemberId,
+            kind: AttestationKind,
+            data: Vec<u8>,
+            expires_at: Option<T::BlockNumber>
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Self::is_verifier(&sender), "not verifier");
+
+            Self::ensure_membership(member_id)?;
+
+            ensure!(data.len() <= MAX_ATTESTATION_DATA_LENGTH, "attestation data too long");
+
+            let now = <system::Module<T>>::block_number();
+            if let Some(expires_at) = expires_at {
+                ensure!(expires_at > now, "attestation expiration must be in the future");
+            }
+
+            <Attestations<T>>::insert(member_id, kind, Attestation {
+                verifier: sender.clone(),
+                data,
+                attested_at: now,
+                expires_at,
+            });
+            Self::deposit_event(RawEvent::MemberAttested(member_id, kind, sender));
+        }
+
+        /// Revokes the member attestation.
+        #[weight = T::WeightInfo::revoke_attestation()]
+        pub fn revoke_attestation(origin, member_id: T::MemberId, kind: AttestationKind) {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Self::is_verifier(&sender), "not verifier");
+
+            ensure!(<Attestations<T>>::contains_key(member_id, kind), "attestation not found");
+
+            <Attestations<T>>::remove(member_id, kind);
+            Self::deposit_event(RawEvent::AttestationRevoked(member_id, kind, sender));
+        }
     }
 }
 
@@ -498,6 +1096,28 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Returns true if the member has the attestation of the given kind which is not expired.
+    pub fn has_valid_attestation(member_id: &T::MemberId, kind: AttestationKind) -> bool {
+        Self::attestation(member_id, kind).map_or(false, |attestation| {
+            attestation.expires_at.map_or(true, |expires_at| {
+                <system::Module<T>>::block_number() <= expires_at
+            })
+        })
+    }
+
+    /// Ensures that the member has the valid attestation of the given kind. Allows other modules
+    /// to require verified members for certain roles.
+    pub fn ensure_member_attested(
+        member_id: &T::MemberId,
+        kind: AttestationKind,
+    ) -> DispatchResult {
+        ensure!(
+            Self::has_valid_attestation(member_id, kind),
+            "member has no valid attestation"
+        );
+        Ok(())
+    }
+
     /// Returns true if account is either a member's root or controller account
     pub fn is_member_account(who: &T::AccountId) -> bool {
         <MemberIdsByRootAccountId<T>>::contains_key(who)
@@ -520,15 +1140,142 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Current user input validation limits.
+    pub fn validation_limits() -> ValidationLimits {
+        ValidationLimits {
+            min_handle_length: Self::min_handle_length(),
+            max_handle_length: Self::max_handle_length(),
+            max_avatar_uri_length: Self::max_avatar_uri_length(),
+            max_about_text_length: Self::max_about_text_length(),
+        }
+    }
+
+    /// Checks the consistency of the user input validation limits.
+    pub fn ensure_valid_validation_limits(limits: &ValidationLimits) -> DispatchResult {
+        ensure!(
+            limits.min_handle_length > 0,
+            "min handle length must be positive"
+        );
+        ensure!(
+            limits.min_handle_length <= limits.max_handle_length,
+            "min handle length exceeds max handle length"
+        );
+        Ok(())
+    }
+
+    /// Migrates the storage to the current layout version. Runs only the migrations
+    /// the storage has not passed yet. Returns the weight of the migration.
+    pub fn migrate() -> Weight {
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+        if Self::storage_version() < 1 {
+            weight = weight.saturating_add(Self::migrate_member_invites());
+        }
+
+        if Self::storage_version() < 2 {
+            weight = weight.saturating_add(Self::migrate_normalized_handles());
+        }
+
+        <StorageVersion>::put(STORAGE_VERSION);
+
+        weight
+    }
+
+    // Gives the invites of the current invitation terms to the members registered before the
+    // invitations were introduced. The terms are expected to be set by the same runtime upgrade.
+    fn migrate_member_invites() -> Weight {
+        let invites_per_member = Self::invitation_terms().invites_per_member;
+        let mut members_count: Weight = 0;
+
+        for (member_id, _) in <MembershipById<T>>::iter() {
+            if !<InvitesByMemberId<T>>::contains_key(member_id) {
+                <InvitesByMemberId<T>>::insert(member_id, invites_per_member);
+            }
+
+            members_count += 1;
+        }
+
+        T::DbWeight::get().reads_writes(
+            members_count.saturating_mul(2).saturating_add(1),
+            members_count,
+        )
+    }
+
+    // Indexes the registered handles in the case-insensitive form. Handles differing only in
+    // the case keep the first indexed owner. The other members keep their handles, which are
+    // logged and reported with the `NormalizedHandleCollision` event.
+    fn migrate_normalized_handles() -> Weight {
+        let mut migrated_handles_count: Weight = 0;
+
+        for (handle, member_id) in <MemberIdByHandle<T>>::iter() {
+            let normalized_handle = Self::normalize_handle(&handle);
+
+            match Self::member_id_by_normalized_handle(&normalized_handle) {
+                None => <MemberIdByNormalizedHandle<T>>::insert(normalized_handle, member_id),
+                Some(indexed_member_id) if indexed_member_id != member_id => {
+                    debug::warn!(
+                        "Handle of the member {:?} collides with the handle of the member {:?}",
+                        member_id,
+                        indexed_member_id
+                    );
+
+                    Self::deposit_event(RawEvent::NormalizedHandleCollision(
+                        member_id,
+                        indexed_member_id,
+                    ));
+                }
+                Some(_) => {}
+            }
+
+            migrated_handles_count += 1;
+        }
+
+        T::DbWeight::get().reads_writes(
+            migrated_handles_count.saturating_mul(2),
+            migrated_handles_count,
+        )
+    }
+
     #[allow(clippy::ptr_arg)] // cannot change to the "&[u8]" suggested by clippy
     fn ensure_unique_handle(handle: &Vec<u8>) -> DispatchResult {
         ensure!(
             !<MemberIdByHandle<T>>::contains_key(handle),
             "handle already registered"
         );
+
+        let normalized_handle = Self::normalize_handle(handle);
+        ensure!(
+            !<MemberIdByNormalizedHandle<T>>::contains_key(&normalized_handle),
+            "handle already registered"
+        );
+        ensure!(
+            !Self::is_reserved_handle(&normalized_handle),
+            "handle reserved"
+        );
         Ok(())
     }
 
+    // Handles are unique regardless of the ASCII letters case.
+    fn normalize_handle(handle: &[u8]) -> Vec<u8> {
+        handle.iter().map(u8::to_ascii_lowercase).collect()
+    }
+
+    fn register_handle(member_id: T::MemberId, handle: &[u8]) {
+        <MemberIdByHandle<T>>::insert(handle.to_owned(), member_id);
+        <MemberIdByNormalizedHandle<T>>::insert(Self::normalize_handle(handle), member_id);
+    }
+
+    fn unregister_handle(member_id: T::MemberId, handle: &[u8]) {
+        <MemberIdByHandle<T>>::remove(handle);
+
+        let normalized_handle = Self::normalize_handle(handle);
+        if Self::member_id_by_normalized_handle(&normalized_handle) == Some(member_id) {
+            <MemberIdByNormalizedHandle<T>>::remove(normalized_handle);
+        }
+
+        <HandleTransferApprovals<T>>::remove(member_id);
+    }
+
     fn validate_handle(handle: &[u8]) -> DispatchResult {
         ensure!(
             handle.len() >= Self::min_handle_length() as usize,
@@ -580,7 +1327,7 @@ impl<T: Trait> Module<T> {
         root_account: &T::AccountId,
         controller_account: &T::AccountId,
         user_info: &ValidatedUserInfo,
-        entry_method: EntryMethod<T::PaidTermId, T::AccountId>,
+        entry_method: EntryMethod<T::PaidTermId, T::AccountId, T::MemberId>,
         registered_at_block: T::BlockNumber,
         registered_at_time: T::Moment,
     ) -> Result<T::MemberId, &'static str> {
@@ -609,7 +1356,8 @@ impl<T: Trait> Module<T> {
         });
 
         <MembershipById<T>>::insert(new_member_id, membership);
-        <MemberIdByHandle<T>>::insert(user_info.handle.clone(), new_member_id);
+        Self::register_handle(new_member_id, &user_info.handle);
+        <InvitesByMemberId<T>>::insert(new_member_id, Self::invitation_terms().invites_per_member);
 
         <NextMemberId<T>>::put(new_member_id + One::one());
         Ok(new_member_id)
@@ -637,8 +1385,10 @@ impl<T: Trait> Module<T> {
         let mut membership = Self::ensure_membership(id)?;
         Self::validate_handle(&handle)?;
         Self::ensure_unique_handle(&handle)?;
-        <MemberIdByHandle<T>>::remove(&membership.handle);
-        <MemberIdByHandle<T>>::insert(handle.clone(), id);
+        if !membership.handle.is_empty() {
+            Self::unregister_handle(id, &membership.handle);
+        }
+        Self::register_handle(id, &handle);
         membership.handle = handle;
         Self::deposit_event(RawEvent::MemberUpdatedHandle(id));
         <MembershipById<T>>::insert(id, membership);
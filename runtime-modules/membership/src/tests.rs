@@ -3,7 +3,9 @@
 use super::genesis;
 use super::mock::*;
 
+use frame_support::traits::{LockableCurrency, WithdrawReasons};
 use frame_support::*;
+use sp_arithmetic::Percent;
 
 fn get_membership_by_id(member_id: u64) -> crate::Membership<Test> {
     if <crate::MembershipById<Test>>::contains_key(member_id) {
@@ -60,6 +62,7 @@ fn buy_default_membership_as_alice() -> crate::DispatchResult {
         info.handle,
         info.avatar_uri,
         info.about,
+        None,
     )
     .map_err(|err| err.into())
 }
@@ -156,6 +159,75 @@ fn buy_membership_fails_without_enough_balance() {
         });
 }
 
+#[test]
+fn buy_membership_with_referrer() {
+    const DEFAULT_FEE: u64 = 500;
+    const REFERRER_ACCOUNT_ID: u64 = 2;
+    let initial_members = [(0, REFERRER_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .default_paid_membership_fee(DEFAULT_FEE)
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let referrer_id = 0;
+            let paid_terms_id = DEFAULT_PAID_TERM_ID as u32;
+            set_alice_free_balance(DEFAULT_FEE);
+
+            // privileged method should fail if not from root origin
+            assert!(Members::set_referral_cut(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                paid_terms_id,
+                Percent::from_percent(20)
+            )
+            .is_err());
+            assert_ok!(Members::set_referral_cut(
+                system::RawOrigin::Root.into(),
+                paid_terms_id,
+                Percent::from_percent(20)
+            ));
+
+            let info = get_alice_info();
+
+            // Unknown referrer
+            assert_dispatch_error_message(
+                Members::buy_membership(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    paid_terms_id,
+                    info.handle.clone(),
+                    info.avatar_uri.clone(),
+                    info.about.clone(),
+                    Some(10),
+                )
+                .map_err(|err| err.into()),
+                "member profile not found",
+            );
+
+            let next_member_id = Members::members_created();
+
+            assert_ok!(Members::buy_membership(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                paid_terms_id,
+                info.handle,
+                info.avatar_uri,
+                info.about,
+                Some(referrer_id),
+            ));
+
+            let reward = DEFAULT_FEE / 5;
+            assert_eq!(Balances::free_balance(&ALICE_ACCOUNT_ID), 0);
+            assert_eq!(Balances::free_balance(&REFERRER_ACCOUNT_ID), reward);
+            assert_eq!(
+                get_membership_by_id(next_member_id).entry,
+                crate::EntryMethod::Paid(paid_terms_id)
+            );
+        });
+}
+
 #[test]
 fn new_memberships_allowed_flag() {
     const DEFAULT_FEE: u64 = 500;
@@ -337,3 +409,884 @@ fn set_root_account() {
             assert!(<crate::MemberIdsByRootAccountId<Test>>::get(&ALICE_ACCOUNT_ID).is_empty());
         });
 }
+
+#[test]
+fn add_and_remove_verifier() {
+    const VERIFIER_ACCOUNT_ID: u64 = 10;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            assert!(
+                Members::add_verifier(Origin::signed(ALICE_ACCOUNT_ID), VERIFIER_ACCOUNT_ID)
+                    .is_err()
+            );
+
+            assert_ok!(Members::add_verifier(
+                system::RawOrigin::Root.into(),
+                VERIFIER_ACCOUNT_ID
+            ));
+            assert!(Members::is_verifier(VERIFIER_ACCOUNT_ID));
+
+            assert_ok!(Members::remove_verifier(
+                system::RawOrigin::Root.into(),
+                VERIFIER_ACCOUNT_ID
+            ));
+            assert!(!Members::is_verifier(VERIFIER_ACCOUNT_ID));
+
+            assert_dispatch_error_message(
+                Members::remove_verifier(system::RawOrigin::Root.into(), VERIFIER_ACCOUNT_ID)
+                    .map_err(|err| err.into()),
+                "not verifier",
+            );
+        });
+}
+
+#[test]
+fn attest_member() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+    const VERIFIER_ACCOUNT_ID: u64 = 10;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let member_id = 0;
+            let data = b"twitter:alice".to_vec();
+            let expires_at = 100;
+            System::set_block_number(10);
+
+            // Only verifiers can attest the members
+            assert_dispatch_error_message(
+                Members::attest_member(
+                    Origin::signed(VERIFIER_ACCOUNT_ID),
+                    member_id,
+                    crate::AttestationKind::SocialAccount,
+                    data.clone(),
+                    Some(expires_at),
+                )
+                .map_err(|err| err.into()),
+                "not verifier",
+            );
+
+            assert_ok!(Members::add_verifier(
+                system::RawOrigin::Root.into(),
+                VERIFIER_ACCOUNT_ID
+            ));
+
+            assert_dispatch_error_message(
+                Members::attest_member(
+                    Origin::signed(VERIFIER_ACCOUNT_ID),
+                    member_id + 1,
+                    crate::AttestationKind::SocialAccount,
+                    data.clone(),
+                    Some(expires_at),
+                )
+                .map_err(|err| err.into()),
+                "member profile not found",
+            );
+
+            assert_dispatch_error_message(
+                Members::attest_member(
+                    Origin::signed(VERIFIER_ACCOUNT_ID),
+                    member_id,
+                    crate::AttestationKind::SocialAccount,
+                    vec![b'd'; crate::MAX_ATTESTATION_DATA_LENGTH + 1],
+                    Some(expires_at),
+                )
+                .map_err(|err| err.into()),
+                "attestation data too long",
+            );
+
+            assert_dispatch_error_message(
+                Members::attest_member(
+                    Origin::signed(VERIFIER_ACCOUNT_ID),
+                    member_id,
+                    crate::AttestationKind::SocialAccount,
+                    data.clone(),
+                    Some(10),
+                )
+                .map_err(|err| err.into()),
+                "attestation expiration must be in the future",
+            );
+
+            assert_ok!(Members::attest_member(
+                Origin::signed(VERIFIER_ACCOUNT_ID),
+                member_id,
+                crate::AttestationKind::SocialAccount,
+                data.clone(),
+                Some(expires_at),
+            ));
+
+            assert_eq!(
+                Members::attestation(member_id, crate::AttestationKind::SocialAccount),
+                Some(crate::Attestation {
+                    verifier: VERIFIER_ACCOUNT_ID,
+                    data,
+                    attested_at: 10,
+                    expires_at: Some(expires_at),
+                })
+            );
+            assert!(Members::has_valid_attestation(
+                &member_id,
+                crate::AttestationKind::SocialAccount
+            ));
+            assert!(!Members::has_valid_attestation(
+                &member_id,
+                crate::AttestationKind::KycLight
+            ));
+            assert_ok!(Members::ensure_member_attested(
+                &member_id,
+                crate::AttestationKind::SocialAccount
+            ));
+
+            // Attestation expires
+            System::set_block_number(expires_at + 1);
+            assert!(!Members::has_valid_attestation(
+                &member_id,
+                crate::AttestationKind::SocialAccount
+            ));
+            assert_dispatch_error_message(
+                Members::ensure_member_attested(&member_id, crate::AttestationKind::SocialAccount),
+                "member has no valid attestation",
+            );
+
+            // Permanent attestation
+            assert_ok!(Members::attest_member(
+                Origin::signed(VERIFIER_ACCOUNT_ID),
+                member_id,
+                crate::AttestationKind::FoundingMember,
+                Vec::new(),
+                None,
+            ));
+            System::set_block_number(expires_at * 100);
+            assert!(Members::has_valid_attestation(
+                &member_id,
+                crate::AttestationKind::FoundingMember
+            ));
+        });
+}
+
+#[test]
+fn revoke_attestation() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+    const VERIFIER_ACCOUNT_ID: u64 = 10;
+    const OTHER_VERIFIER_ACCOUNT_ID: u64 = 11;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let member_id = 0;
+
+            assert_ok!(Members::add_verifier(
+                system::RawOrigin::Root.into(),
+                VERIFIER_ACCOUNT_ID
+            ));
+            assert_ok!(Members::add_verifier(
+                system::RawOrigin::Root.into(),
+                OTHER_VERIFIER_ACCOUNT_ID
+            ));
+            assert_ok!(Members::attest_member(
+                Origin::signed(VERIFIER_ACCOUNT_ID),
+                member_id,
+                crate::AttestationKind::KycLight,
+                Vec::new(),
+                None,
+            ));
+
+            // Only verifiers can revoke the attestations
+            assert_dispatch_error_message(
+                Members::revoke_attestation(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    member_id,
+                    crate::AttestationKind::KycLight,
+                )
+                .map_err(|err| err.into()),
+                "not verifier",
+            );
+
+            assert_ok!(Members::revoke_attestation(
+                Origin::signed(OTHER_VERIFIER_ACCOUNT_ID),
+                member_id,
+                crate::AttestationKind::KycLight,
+            ));
+            assert!(!Members::has_valid_attestation(
+                &member_id,
+                crate::AttestationKind::KycLight
+            ));
+
+            assert_dispatch_error_message(
+                Members::revoke_attestation(
+                    Origin::signed(VERIFIER_ACCOUNT_ID),
+                    member_id,
+                    crate::AttestationKind::KycLight,
+                )
+                .map_err(|err| err.into()),
+                "attestation not found",
+            );
+        });
+}
+
+#[test]
+fn invite_member() {
+    const INVITED_MEMBER_BALANCE: u64 = 100;
+    const INVITED_MEMBER_LOCK_PERIOD: u64 = 50;
+    const BOB_ACCOUNT_ID: u64 = 2;
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let inviter_id = 0;
+            let invites_per_member = 3;
+            set_alice_free_balance(INVITED_MEMBER_BALANCE);
+            System::set_block_number(10);
+
+            assert_ok!(Members::set_invitation_terms(
+                system::RawOrigin::Root.into(),
+                crate::InvitationTerms {
+                    invites_per_member,
+                    invited_member_balance: INVITED_MEMBER_BALANCE,
+                    invited_member_lock_period: INVITED_MEMBER_LOCK_PERIOD,
+                }
+            ));
+
+            let info = get_bob_info();
+
+            // Genesis member has no invites
+            assert_dispatch_error_message(
+                Members::invite_member(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    inviter_id,
+                    BOB_ACCOUNT_ID,
+                    info.handle.clone(),
+                    info.avatar_uri.clone(),
+                    info.about.clone(),
+                )
+                .map_err(|err| err.into()),
+                "no invites left",
+            );
+
+            assert_ok!(Members::set_member_invites_count(
+                system::RawOrigin::Root.into(),
+                inviter_id,
+                1
+            ));
+
+            // Only the inviter controller account can invite members
+            assert_dispatch_error_message(
+                Members::invite_member(
+                    Origin::signed(BOB_ACCOUNT_ID),
+                    inviter_id,
+                    BOB_ACCOUNT_ID,
+                    info.handle.clone(),
+                    info.avatar_uri.clone(),
+                    info.about.clone(),
+                )
+                .map_err(|err| err.into()),
+                "only controller account can invite members",
+            );
+
+            let next_member_id = Members::members_created();
+
+            assert_ok!(Members::invite_member(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                inviter_id,
+                BOB_ACCOUNT_ID,
+                info.handle.clone(),
+                info.avatar_uri.clone(),
+                info.about.clone(),
+            ));
+
+            let profile = get_membership_by_id(next_member_id);
+
+            assert_eq!(Some(profile.handle), info.handle);
+            assert_eq!(profile.root_account, BOB_ACCOUNT_ID);
+            assert_eq!(crate::EntryMethod::Invited(inviter_id), profile.entry);
+
+            assert_eq!(Members::invites_by_member_id(inviter_id), 0);
+            assert_eq!(
+                Members::invites_by_member_id(next_member_id),
+                invites_per_member
+            );
+            assert_eq!(
+                Members::invited_member_ids(inviter_id),
+                vec![next_member_id]
+            );
+
+            assert_eq!(Balances::free_balance(&ALICE_ACCOUNT_ID), 0);
+            assert_eq!(
+                Balances::free_balance(&BOB_ACCOUNT_ID),
+                INVITED_MEMBER_BALANCE
+            );
+            assert_eq!(Balances::locks(&BOB_ACCOUNT_ID).len(), 1);
+            assert_eq!(
+                Members::invitation_lock_expires_at(&BOB_ACCOUNT_ID),
+                Some(10 + INVITED_MEMBER_LOCK_PERIOD)
+            );
+        });
+}
+
+#[test]
+fn invite_member_fails_without_enough_balance() {
+    const BOB_ACCOUNT_ID: u64 = 2;
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let inviter_id = 0;
+
+            assert_ok!(Members::set_invitation_terms(
+                system::RawOrigin::Root.into(),
+                crate::InvitationTerms {
+                    invites_per_member: 1,
+                    invited_member_balance: 100,
+                    invited_member_lock_period: 50,
+                }
+            ));
+            assert_ok!(Members::set_member_invites_count(
+                system::RawOrigin::Root.into(),
+                inviter_id,
+                1
+            ));
+
+            let next_member_id = Members::members_created();
+            let info = get_bob_info();
+
+            assert_dispatch_error_message(
+                Members::invite_member(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    inviter_id,
+                    BOB_ACCOUNT_ID,
+                    info.handle.clone(),
+                    info.avatar_uri.clone(),
+                    info.about.clone(),
+                )
+                .map_err(|err| err.into()),
+                "not enough balance to invite member",
+            );
+
+            assert_eq!(Members::members_created(), next_member_id);
+            assert_eq!(Members::invites_by_member_id(inviter_id), 1);
+        });
+}
+
+#[test]
+fn invite_member_fails_with_locked_balance() {
+    const BOB_ACCOUNT_ID: u64 = 2;
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let inviter_id = 0;
+            let invited_member_balance = 100;
+            set_alice_free_balance(invited_member_balance);
+            Balances::set_lock(
+                *b"testlock",
+                &ALICE_ACCOUNT_ID,
+                invited_member_balance,
+                WithdrawReasons::all(),
+            );
+
+            assert_ok!(Members::set_invitation_terms(
+                system::RawOrigin::Root.into(),
+                crate::InvitationTerms {
+                    invites_per_member: 1,
+                    invited_member_balance,
+                    invited_member_lock_period: 50,
+                }
+            ));
+            assert_ok!(Members::set_member_invites_count(
+                system::RawOrigin::Root.into(),
+                inviter_id,
+                1
+            ));
+
+            let next_member_id = Members::members_created();
+            let info = get_bob_info();
+
+            assert!(Members::invite_member(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                inviter_id,
+                BOB_ACCOUNT_ID,
+                info.handle.clone(),
+                info.avatar_uri.clone(),
+                info.about.clone(),
+            )
+            .is_err());
+
+            assert_eq!(Members::members_created(), next_member_id);
+            assert_eq!(Members::invites_by_member_id(inviter_id), 1);
+            assert!(<crate::MemberIdsByRootAccountId<Test>>::get(&BOB_ACCOUNT_ID).is_empty());
+            assert_eq!(Balances::free_balance(&BOB_ACCOUNT_ID), 0);
+        });
+}
+
+#[test]
+fn migrate_member_invites() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID), (1, ALICE_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let invites_per_member = 2;
+            let legacy_member_id = 0;
+            let member_id = 1;
+
+            // Member registered before the invitations were introduced
+            <crate::InvitesByMemberId<Test>>::remove(legacy_member_id);
+            <crate::InvitesByMemberId<Test>>::insert(member_id, 1);
+
+            assert_ok!(Members::set_invitation_terms(
+                system::RawOrigin::Root.into(),
+                crate::InvitationTerms {
+                    invites_per_member,
+                    invited_member_balance: 0,
+                    invited_member_lock_period: 0,
+                }
+            ));
+
+            <crate::StorageVersion>::put(0);
+            Members::migrate();
+
+            assert_eq!(
+                Members::invites_by_member_id(legacy_member_id),
+                invites_per_member
+            );
+            assert_eq!(Members::invites_by_member_id(member_id), 1);
+            assert_eq!(Members::storage_version(), 2);
+
+            // The migration runs once
+            <crate::InvitesByMemberId<Test>>::remove(legacy_member_id);
+            Members::migrate();
+
+            assert!(!<crate::InvitesByMemberId<Test>>::contains_key(
+                legacy_member_id
+            ));
+        });
+}
+
+#[test]
+fn migrate_normalized_handles() {
+    let initial_members = [
+        (0, ALICE_ACCOUNT_ID),
+        (1, ALICE_ACCOUNT_ID),
+        (2, ALICE_ACCOUNT_ID),
+    ];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            for (member_id, _) in initial_members.iter() {
+                let handle = get_membership_by_id(*member_id).handle;
+                <crate::MemberIdByHandle<Test>>::remove(&handle);
+                <crate::MemberIdByNormalizedHandle<Test>>::remove(&handle);
+            }
+
+            // Handles registered before the case-insensitive index
+            let legacy_handles: [(u64, &str); 3] = [(0, "Handle"), (1, "HANDLE"), (2, "other")];
+            for (member_id, handle) in legacy_handles.iter() {
+                <crate::MemberIdByHandle<Test>>::insert(handle.as_bytes().to_vec(), member_id);
+            }
+
+            <crate::StorageVersion>::put(1);
+            Members::migrate();
+
+            // Colliding handles keep the first indexed owner
+            let indexed_member_id = Members::member_id_by_normalized_handle(b"handle".to_vec());
+            assert!(indexed_member_id == Some(0) || indexed_member_id == Some(1));
+            assert_eq!(
+                Members::member_id_by_normalized_handle(b"other".to_vec()),
+                Some(2)
+            );
+            assert_eq!(Members::storage_version(), 2);
+        });
+}
+
+#[test]
+fn remove_invitation_lock() {
+    const INVITED_MEMBER_BALANCE: u64 = 100;
+    const INVITED_MEMBER_LOCK_PERIOD: u64 = 50;
+    const BOB_ACCOUNT_ID: u64 = 2;
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let inviter_id = 0;
+            set_alice_free_balance(INVITED_MEMBER_BALANCE);
+            System::set_block_number(1);
+
+            <crate::CurrentInvitationTerms<Test>>::put(crate::InvitationTerms {
+                invites_per_member: 0,
+                invited_member_balance: INVITED_MEMBER_BALANCE,
+                invited_member_lock_period: INVITED_MEMBER_LOCK_PERIOD,
+            });
+            <crate::InvitesByMemberId<Test>>::insert(inviter_id, 1);
+
+            let info = get_bob_info();
+            assert_ok!(Members::invite_member(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                inviter_id,
+                BOB_ACCOUNT_ID,
+                info.handle,
+                info.avatar_uri,
+                info.about,
+            ));
+
+            System::set_block_number(1 + INVITED_MEMBER_LOCK_PERIOD);
+            assert_dispatch_error_message(
+                Members::remove_invitation_lock(Origin::signed(ALICE_ACCOUNT_ID), BOB_ACCOUNT_ID)
+                    .map_err(|err| err.into()),
+                "invitation lock not expired",
+            );
+
+            System::set_block_number(2 + INVITED_MEMBER_LOCK_PERIOD);
+            assert_ok!(Members::remove_invitation_lock(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                BOB_ACCOUNT_ID
+            ));
+
+            assert!(Balances::locks(&BOB_ACCOUNT_ID).is_empty());
+            assert!(Members::invitation_lock_expires_at(&BOB_ACCOUNT_ID).is_none());
+
+            assert_dispatch_error_message(
+                Members::remove_invitation_lock(Origin::signed(ALICE_ACCOUNT_ID), BOB_ACCOUNT_ID)
+                    .map_err(|err| err.into()),
+                "invitation lock not found",
+            );
+        });
+}
+
+#[test]
+fn handles_are_unique_regardless_of_case() {
+    const DEFAULT_FEE: u64 = 500;
+    const BOB_ACCOUNT_ID: u64 = 2;
+    let initial_members = [(0, BOB_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .default_paid_membership_fee(DEFAULT_FEE)
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let bob_member_id = 0;
+            set_alice_free_balance(DEFAULT_FEE);
+
+            assert_ok!(Members::change_member_handle(
+                Origin::signed(BOB_ACCOUNT_ID),
+                bob_member_id,
+                b"ALICE".to_vec()
+            ));
+
+            assert_dispatch_error_message(
+                buy_default_membership_as_alice(),
+                "handle already registered",
+            );
+
+            // Released handle is available again
+            assert_ok!(Members::change_member_handle(
+                Origin::signed(BOB_ACCOUNT_ID),
+                bob_member_id,
+                get_bob_info().handle.unwrap()
+            ));
+            assert!(Members::member_id_by_normalized_handle(b"alice".to_vec()).is_none());
+            assert_ok!(buy_default_membership_as_alice());
+        });
+}
+
+#[test]
+fn reserved_handles() {
+    const DEFAULT_FEE: u64 = 500;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .default_paid_membership_fee(DEFAULT_FEE)
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            set_alice_free_balance(DEFAULT_FEE);
+
+            // privileged method should fail if not from root origin
+            assert!(
+                Members::reserve_handle(Origin::signed(ALICE_ACCOUNT_ID), b"Alice".to_vec())
+                    .is_err()
+            );
+            assert_ok!(Members::reserve_handle(
+                system::RawOrigin::Root.into(),
+                b"Alice".to_vec()
+            ));
+            assert!(Members::is_reserved_handle(b"alice".to_vec()));
+
+            assert_dispatch_error_message(buy_default_membership_as_alice(), "handle reserved");
+
+            assert_ok!(Members::unreserve_handle(
+                system::RawOrigin::Root.into(),
+                b"ALICE".to_vec()
+            ));
+            assert_dispatch_error_message(
+                Members::unreserve_handle(system::RawOrigin::Root.into(), b"alice".to_vec())
+                    .map_err(|err| err.into()),
+                "handle not reserved",
+            );
+
+            assert_ok!(buy_default_membership_as_alice());
+        });
+}
+
+#[test]
+fn release_handle() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let member_id = 0;
+            let handle = get_membership_by_id(member_id).handle;
+
+            assert_dispatch_error_message(
+                Members::release_handle(Origin::signed(2), member_id).map_err(|err| err.into()),
+                "only controller account can update member handle",
+            );
+
+            assert_ok!(Members::release_handle(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id
+            ));
+
+            assert!(get_membership_by_id(member_id).handle.is_empty());
+            assert!(!<crate::MemberIdByHandle<Test>>::contains_key(&handle));
+            assert!(Members::member_id_by_normalized_handle(&handle).is_none());
+
+            assert_dispatch_error_message(
+                Members::release_handle(Origin::signed(ALICE_ACCOUNT_ID), member_id)
+                    .map_err(|err| err.into()),
+                "member has no handle",
+            );
+
+            // Member can pick a new handle
+            assert_ok!(Members::change_member_handle(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                get_alice_info().handle.unwrap()
+            ));
+        });
+}
+
+#[test]
+fn transfer_handle() {
+    const BOB_ACCOUNT_ID: u64 = 2;
+    let initial_members = [(0, ALICE_ACCOUNT_ID), (1, BOB_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let alice_member_id = 0;
+            let bob_member_id = 1;
+            let alice_handle = get_membership_by_id(alice_member_id).handle;
+            let bob_handle = get_membership_by_id(bob_member_id).handle;
+
+            assert_dispatch_error_message(
+                Members::transfer_handle(
+                    Origin::signed(BOB_ACCOUNT_ID),
+                    alice_member_id,
+                    bob_member_id,
+                )
+                .map_err(|err| err.into()),
+                "handle transfer not approved",
+            );
+
+            // Only the handle owner can approve the transfer
+            assert_dispatch_error_message(
+                Members::approve_handle_transfer(
+                    Origin::signed(BOB_ACCOUNT_ID),
+                    alice_member_id,
+                    bob_member_id,
+                )
+                .map_err(|err| err.into()),
+                "only controller account can update member handle",
+            );
+
+            assert_ok!(Members::approve_handle_transfer(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                alice_member_id,
+                bob_member_id
+            ));
+
+            // Only the recipient can complete the transfer
+            assert_dispatch_error_message(
+                Members::transfer_handle(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    alice_member_id,
+                    bob_member_id,
+                )
+                .map_err(|err| err.into()),
+                "only controller account can update member handle",
+            );
+
+            assert_ok!(Members::transfer_handle(
+                Origin::signed(BOB_ACCOUNT_ID),
+                alice_member_id,
+                bob_member_id
+            ));
+
+            assert!(get_membership_by_id(alice_member_id).handle.is_empty());
+            assert_eq!(get_membership_by_id(bob_member_id).handle, alice_handle);
+            assert_eq!(Members::handles(&alice_handle), bob_member_id);
+            assert_eq!(
+                Members::member_id_by_normalized_handle(&alice_handle),
+                Some(bob_member_id)
+            );
+            assert!(!<crate::MemberIdByHandle<Test>>::contains_key(&bob_handle));
+            assert!(Members::handle_transfer_approval(alice_member_id).is_none());
+        });
+}
+
+#[test]
+fn set_validation_limits() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let member_id = 0;
+            let info = get_alice_info();
+            assert_ok!(Members::update_membership(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                info.handle.clone(),
+                info.avatar_uri.clone(),
+                info.about.clone()
+            ));
+
+            let limits = crate::ValidationLimits {
+                min_handle_length: 1,
+                max_handle_length: 3,
+                max_avatar_uri_length: 4,
+                max_about_text_length: 5,
+            };
+
+            // privileged method should fail if not from root origin
+            assert!(
+                Members::set_validation_limits(Origin::signed(ALICE_ACCOUNT_ID), limits).is_err()
+            );
+
+            assert_dispatch_error_message(
+                Members::set_validation_limits(
+                    system::RawOrigin::Root.into(),
+                    crate::ValidationLimits {
+                        min_handle_length: 0,
+                        ..limits
+                    },
+                )
+                .map_err(|err| err.into()),
+                "min handle length must be positive",
+            );
+            assert_dispatch_error_message(
+                Members::set_validation_limits(
+                    system::RawOrigin::Root.into(),
+                    crate::ValidationLimits {
+                        min_handle_length: 4,
+                        ..limits
+                    },
+                )
+                .map_err(|err| err.into()),
+                "min handle length exceeds max handle length",
+            );
+
+            assert_ok!(Members::set_validation_limits(
+                system::RawOrigin::Root.into(),
+                limits
+            ));
+            assert_eq!(Members::validation_limits(), limits);
+
+            // Existing member profile exceeding the new limits remains loadable and updatable
+            let profile = get_membership_by_id(member_id);
+            assert_eq!(Some(profile.handle), info.handle);
+            assert_eq!(Some(profile.avatar_uri), info.avatar_uri);
+            assert_eq!(Some(profile.about), info.about);
+
+            assert_ok!(Members::set_controller_account(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                ALICE_ACCOUNT_ID
+            ));
+
+            // New input is validated against the new limits
+            assert_dispatch_error_message(
+                Members::change_member_handle(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    member_id,
+                    b"four".to_vec(),
+                )
+                .map_err(|err| err.into()),
+                "handle too long",
+            );
+            assert_ok!(Members::change_member_handle(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                b"bob".to_vec()
+            ));
+        });
+}
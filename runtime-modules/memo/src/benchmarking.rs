@@ -0,0 +1,181 @@
+//! Memo module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::StorageValue;
+use sp_arithmetic::traits::One;
+use sp_std::prelude::*;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+const FREE_BALANCE: u32 = 1_000_000;
+
+/// Registers a funded member controlled by the returned account.
+fn create_member<T: Trait>(id: u32) -> (T::MemberId, T::AccountId) {
+    let account_id: T::AccountId = account("member", id, SEED);
+    let member_id = membership::Module::<T>::members_created();
+
+    let mut handle = b"member".to_vec();
+    handle.extend_from_slice(&id.to_be_bytes());
+
+    membership::ScreeningAuthority::<T>::put(account_id.clone());
+    membership::Module::<T>::add_screened_member(
+        RawOrigin::Signed(account_id.clone()).into(),
+        account_id.clone(),
+        Some(handle),
+        None,
+        None,
+    )
+    .expect("benchmark member must be registered");
+
+    T::Currency::make_free_balance_be(&account_id, FREE_BALANCE.into());
+
+    (member_id, account_id)
+}
+
+// Fills the recipient inbox with the given number of one byte messages.
+fn fill_inbox<T: Trait>(
+    sender_id: T::MemberId,
+    sender_account: &T::AccountId,
+    recipient_id: T::MemberId,
+    count: u32,
+) {
+    for _ in 0..count {
+        Module::<T>::send_message(
+            RawOrigin::Signed(sender_account.clone()).into(),
+            sender_id,
+            recipient_id,
+            vec![b'x'],
+        )
+        .expect("member can send messages");
+    }
+}
+
+benchmarks! {
+    _ { }
+
+    update_memo {
+        let i in 1 .. Module::<T>::max_memo_length();
+        let (_, account_id) = create_member::<T>(0);
+        let memo = vec![b'x'; i as usize];
+    }: _(RawOrigin::Signed(account_id.clone()), memo.clone())
+    verify {
+        assert_eq!(Module::<T>::memo(account_id), memo);
+    }
+
+    clear_memo {
+        let (_, account_id) = create_member::<T>(0);
+        Module::<T>::update_memo(
+            RawOrigin::Signed(account_id.clone()).into(),
+            vec![b'x'; Module::<T>::max_memo_length() as usize],
+        )
+        .expect("account can publish its memo");
+    }: _(RawOrigin::Signed(account_id.clone()))
+    verify {
+        assert!(Module::<T>::memo(account_id).is_empty());
+    }
+
+    set_max_memo_length {
+        let max_memo_length = 1024;
+    }: _(RawOrigin::Root, max_memo_length)
+    verify {
+        assert_eq!(Module::<T>::max_memo_length(), max_memo_length);
+    }
+
+    send_message {
+        let i in 1 .. T::MaxMessageLength::get();
+        let (sender_id, sender_account) = create_member::<T>(0);
+        let (recipient_id, _) = create_member::<T>(1);
+        fill_inbox::<T>(sender_id, &sender_account, recipient_id, T::MaxInboxMessages::get() - 1);
+        let message_id = Module::<T>::next_message_id();
+    }: _(RawOrigin::Signed(sender_account), sender_id, recipient_id, vec![b'x'; i as usize])
+    verify {
+        assert_eq!(Module::<T>::message(message_id).unwrap().payload.len(), i as usize);
+    }
+
+    acknowledge_message {
+        let (sender_id, sender_account) = create_member::<T>(0);
+        let (recipient_id, recipient_account) = create_member::<T>(1);
+        fill_inbox::<T>(sender_id, &sender_account, recipient_id, T::MaxInboxMessages::get());
+    }: _(RawOrigin::Signed(recipient_account), recipient_id, 0)
+    verify {
+        assert!(Module::<T>::message(0).is_none());
+    }
+
+    delete_message {
+        let (sender_id, sender_account) = create_member::<T>(0);
+        let (recipient_id, recipient_account) = create_member::<T>(1);
+        fill_inbox::<T>(sender_id, &sender_account, recipient_id, T::MaxInboxMessages::get());
+    }: _(RawOrigin::Signed(recipient_account), recipient_id, 0)
+    verify {
+        assert!(Module::<T>::message(0).is_none());
+    }
+
+    prune_expired_message {
+        let (sender_id, sender_account) = create_member::<T>(0);
+        let (recipient_id, _) = create_member::<T>(1);
+        fill_inbox::<T>(sender_id, &sender_account, recipient_id, T::MaxInboxMessages::get());
+
+        let expired_at = <system::Module<T>>::block_number() + T::MessageLifetime::get() + One::one();
+        <system::Module<T>>::set_block_number(expired_at);
+    }: _(RawOrigin::Signed(sender_account), 0)
+    verify {
+        assert!(Module::<T>::message(0).is_none());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{initial_test_ext, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn test_update_memo() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_update_memo::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_clear_memo() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_clear_memo::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_set_max_memo_length() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_set_max_memo_length::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_send_message() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_send_message::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_acknowledge_message() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_acknowledge_message::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_delete_message() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_delete_message::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_prune_expired_message() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_prune_expired_message::<Test>());
+        });
+    }
+}
@@ -1,30 +1,236 @@
+//! # Memo module
+//! Memo module for the Joystream platform lets an account publish a public memo about itself
+//! and lets members send sealed (encrypted by the sender for the recipient) messages to the
+//! inboxes of other members.
+//!
+//! ## Supported extrinsics
+//!
+//! - [update_memo](./struct.Module.html#method.update_memo) - Publishes the memo of the account.
+//! - [clear_memo](./struct.Module.html#method.clear_memo) - Deletes the memo of the account.
+//! - [set_max_memo_length](./struct.Module.html#method.set_max_memo_length) - Sets the maximum memo length.
+//! - [send_message](./struct.Module.html#method.send_message) - Sends a sealed message to the member inbox.
+//! - [acknowledge_message](./struct.Module.html#method.acknowledge_message) - Acknowledges the message and deletes it from the member inbox.
+//! - [delete_message](./struct.Module.html#method.delete_message) - Deletes the message from the member inbox.
+//! - [prune_expired_message](./struct.Module.html#method.prune_expired_message) - Deletes the expired message from any inbox.
+//!
+//! Accounts reserve a deposit proportional to their memo length, which is returned when the memo
+//! is shortened or cleared. Memos published before the deposits were introduced have no deposit
+//! and are exempt until their accounts update them, at which point the full deposit is reserved. Senders pay a deposit per message, which is returned only when the
+//! recipient acknowledges the message. The deposit is burned when the recipient deletes the message
+//! without acknowledging it or when the expired message is pruned, so unwanted messages are not free.
+
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::traits::Currency;
-use frame_support::{decl_event, decl_module, decl_storage, ensure};
-use sp_arithmetic::traits::Zero;
+mod mock;
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+use codec::{Decode, Encode};
+use frame_support::traits::{Currency, Get, ReservableCurrency};
+use frame_support::weights::Weight;
+use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure};
+use sp_arithmetic::traits::Saturating;
 use sp_std::vec::Vec;
-use system::ensure_signed;
+use system::{ensure_root, ensure_signed};
 
-use common::currency::GovernanceCurrency;
+use common::currency::{BalanceOf, GovernanceCurrency};
 
-pub trait Trait: system::Trait + GovernanceCurrency {
+pub trait Trait: system::Trait + GovernanceCurrency + membership::Trait {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// Deposit reserved for every byte of the memo.
+    type MemoDepositPerByte: Get<BalanceOf<Self>>;
+
+    /// Deposit reserved from the sender account for every message until it leaves the inbox.
+    type MessageDeposit: Get<BalanceOf<Self>>;
+
+    /// Maximum length of the sealed message payload.
+    type MaxMessageLength: Get<u32>;
+
+    /// Maximum number of the messages in the member inbox.
+    type MaxInboxMessages: Get<u32>;
+
+    /// Maximum total length of the message payloads in the member inbox.
+    type MaxInboxSize: Get<u32>;
+
+    /// Number of blocks after which anyone can prune the message from the inbox.
+    type MessageLifetime: Get<Self::BlockNumber>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+}
+
+/// Weight functions needed for the memo module.
+pub trait WeightInfo {
+    fn update_memo(i: u32) -> Weight;
+    fn clear_memo() -> Weight;
+    fn set_max_memo_length() -> Weight;
+    fn send_message(i: u32) -> Weight;
+    fn acknowledge_message() -> Weight;
+    fn delete_message() -> Weight;
+    fn prune_expired_message() -> Weight;
+}
+
+/// Flat weights for the test runtimes.
+impl WeightInfo for () {
+    fn update_memo(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn clear_memo() -> Weight {
+        10_000_000
+    }
+    fn set_max_memo_length() -> Weight {
+        10_000_000
+    }
+    fn send_message(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn acknowledge_message() -> Weight {
+        10_000_000
+    }
+    fn delete_message() -> Weight {
+        10_000_000
+    }
+    fn prune_expired_message() -> Weight {
+        10_000_000
+    }
 }
 
 pub type MemoText = Vec<u8>;
 
+/// Message payload sealed by the sender with the recipient public key.
+pub type SealedPayload = Vec<u8>;
+
+/// Message identifier.
+pub type MessageId = u64;
+
+/// Message in the member inbox.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct Message<MemberId, AccountId, Balance, BlockNumber> {
+    /// Sender member id.
+    pub sender: MemberId,
+    /// Recipient member id.
+    pub recipient: MemberId,
+    /// Account which paid the message deposit.
+    pub deposit_account: AccountId,
+    /// Reserved message deposit.
+    pub deposit: Balance,
+    /// Sealed message payload.
+    pub payload: SealedPayload,
+    /// Block at which the message was sent.
+    pub sent_at: BlockNumber,
+}
+
+/// Message type alias for the module.
+pub type MessageOf<T> = Message<
+    <T as membership::Trait>::MemberId,
+    <T as system::Trait>::AccountId,
+    BalanceOf<T>,
+    <T as system::Trait>::BlockNumber,
+>;
+
 decl_storage! {
     trait Store for Module<T: Trait> as Memo {
         Memo get(fn memo) : map hasher(blake2_128_concat) T::AccountId => MemoText;
         MaxMemoLength get(fn max_memo_length) : u32 = 4096;
+
+        /// Deposit reserved for the account memo. Legacy memos have no deposit.
+        pub MemoDeposit get(fn memo_deposit): map hasher(blake2_128_concat) T::AccountId => BalanceOf<T>;
+
+        /// Next message id.
+        pub NextMessageId get(fn next_message_id): MessageId;
+
+        /// Messages by their ids.
+        pub Messages get(fn message): map hasher(twox_64_concat) MessageId => Option<MessageOf<T>>;
+
+        /// Message ids in the member inbox.
+        pub InboxMessageIds get(fn inbox_message_ids):
+            map hasher(blake2_128_concat) T::MemberId => Vec<MessageId>;
+
+        /// Total length of the message payloads in the member inbox.
+        pub InboxSize get(fn inbox_size): map hasher(blake2_128_concat) T::MemberId => u32;
     }
 }
 
 decl_event! {
-    pub enum Event<T> where <T as system::Trait>::AccountId {
+    pub enum Event<T> where
+        <T as system::Trait>::AccountId,
+        MemberId = <T as membership::Trait>::MemberId
+    {
         MemoUpdated(AccountId),
+
+        /// Emits on deleting the account memo.
+        /// Params:
+        /// - Account id.
+        MemoCleared(AccountId),
+
+        /// Emits on setting the maximum memo length.
+        /// Params:
+        /// - New maximum memo length.
+        MaxMemoLengthUpdated(u32),
+
+        /// Emits on sending the message.
+        /// Params:
+        /// - Message id.
+        /// - Sender member id.
+        /// - Recipient member id.
+        MessageSent(MessageId, MemberId, MemberId),
+
+        /// Emits on acknowledging the message and returning its deposit to the sender.
+        /// Params:
+        /// - Message id.
+        /// - Recipient member id.
+        MessageAcknowledged(MessageId, MemberId),
+
+        /// Emits on deleting the message from the inbox. The message deposit is burned.
+        /// Params:
+        /// - Message id.
+        /// - Recipient member id.
+        MessageDeleted(MessageId, MemberId),
+    }
+}
+
+decl_error! {
+    /// Memo module predefined errors
+    pub enum Error for Module<T: Trait> {
+        /// Memo is longer than MaxMemoLength.
+        MemoTooLong,
+
+        /// Account cannot reserve the memo deposit.
+        InsufficientBalanceForMemoDeposit,
+
+        /// Origin is not the controller account of the member.
+        MemberControllerAccountRequired,
+
+        /// Recipient member doesn't exist.
+        RecipientMemberNotFound,
+
+        /// Message payload cannot be empty.
+        EmptyMessage,
+
+        /// Message payload is longer than MaxMessageLength.
+        MessageTooLong,
+
+        /// Recipient inbox has MaxInboxMessages messages.
+        InboxMessagesLimitReached,
+
+        /// Message doesn't fit into the MaxInboxSize of the recipient inbox.
+        InboxSizeLimitExceeded,
+
+        /// Sender account cannot reserve the message deposit.
+        InsufficientBalanceForMessageDeposit,
+
+        /// Message doesn't exist.
+        MessageNotFound,
+
+        /// Message is not in the inbox of the member.
+        MessageNotInInbox,
+
+        /// Message is not expired yet.
+        MessageNotExpired,
     }
 }
 
@@ -32,15 +238,230 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        /// Predefined errors.
+        type Error = Error<T>;
+
+        /// Publishes the memo of the account. Reserves the deposit for the longer memo or returns
+        /// the deposit excess for the shorter one.
+        #[weight = T::WeightInfo::update_memo(memo.len() as u32)]
         fn update_memo(origin, memo: MemoText) {
             let sender = ensure_signed(origin)?;
 
-            ensure!(!T::Currency::total_balance(&sender).is_zero(), "account must have a balance");
-            ensure!(memo.len() as u32 <= Self::max_memo_length(), "memo too long");
+            ensure!(memo.len() as u32 <= Self::max_memo_length(), Error::<T>::MemoTooLong);
+
+            let current_deposit = Self::memo_deposit(&sender);
+            let deposit = Self::memo_deposit_for(&memo);
+
+            if deposit > current_deposit {
+                ensure!(
+                    T::Currency::can_reserve(&sender, deposit - current_deposit),
+                    Error::<T>::InsufficientBalanceForMemoDeposit
+                );
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if deposit > current_deposit {
+                T::Currency::reserve(&sender, deposit - current_deposit)?;
+            } else {
+                T::Currency::unreserve(&sender, current_deposit - deposit);
+            }
 
+            <MemoDeposit<T>>::insert(&sender, deposit);
             <Memo<T>>::insert(&sender, memo);
             Self::deposit_event(RawEvent::MemoUpdated(sender));
         }
+
+        /// Deletes the memo of the account and returns its deposit.
+        #[weight = T::WeightInfo::clear_memo()]
+        pub fn clear_memo(origin) {
+            let sender = ensure_signed(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            T::Currency::unreserve(&sender, Self::memo_deposit(&sender));
+
+            <MemoDeposit<T>>::remove(&sender);
+            <Memo<T>>::remove(&sender);
+            Self::deposit_event(RawEvent::MemoCleared(sender));
+        }
+
+        /// Sets the maximum memo length. Existing memos are kept. Requires root privileges.
+        #[weight = T::WeightInfo::set_max_memo_length()]
+        pub fn set_max_memo_length(origin, max_memo_length: u32) {
+            ensure_root(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            MaxMemoLength::put(max_memo_length);
+            Self::deposit_event(RawEvent::MaxMemoLengthUpdated(max_memo_length));
+        }
+
+        /// Sends the sealed message to the recipient member inbox. Reserves the message deposit
+        /// from the sender controller account.
+        #[weight = T::WeightInfo::send_message(payload.len() as u32)]
+        pub fn send_message(
+            origin,
+            sender_id: T::MemberId,
+            recipient_id: T::MemberId,
+            payload: SealedPayload,
+        ) {
+            let sender_account = Self::ensure_member_controller_account(origin, &sender_id)?;
+
+            ensure!(
+                <membership::MembershipById<T>>::contains_key(recipient_id),
+                Error::<T>::RecipientMemberNotFound
+            );
+
+            ensure!(!payload.is_empty(), Error::<T>::EmptyMessage);
+
+            let payload_length = payload.len() as u32;
+            ensure!(payload_length <= T::MaxMessageLength::get(), Error::<T>::MessageTooLong);
+
+            ensure!(
+                (Self::inbox_message_ids(recipient_id).len() as u32) < T::MaxInboxMessages::get(),
+                Error::<T>::InboxMessagesLimitReached
+            );
+
+            let inbox_size = Self::inbox_size(recipient_id).saturating_add(payload_length);
+            ensure!(inbox_size <= T::MaxInboxSize::get(), Error::<T>::InboxSizeLimitExceeded);
+
+            let deposit = T::MessageDeposit::get();
+            ensure!(
+                T::Currency::can_reserve(&sender_account, deposit),
+                Error::<T>::InsufficientBalanceForMessageDeposit
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            T::Currency::reserve(&sender_account, deposit)?;
+
+            let message_id = Self::next_message_id();
+            NextMessageId::put(message_id + 1);
+
+            <Messages<T>>::insert(message_id, Message {
+                sender: sender_id,
+                recipient: recipient_id,
+                deposit_account: sender_account,
+                deposit,
+                payload,
+                sent_at: <system::Module<T>>::block_number(),
+            });
+            <InboxMessageIds<T>>::mutate(recipient_id, |ids| ids.push(message_id));
+            <InboxSize<T>>::insert(recipient_id, inbox_size);
+
+            Self::deposit_event(RawEvent::MessageSent(message_id, sender_id, recipient_id));
+        }
+
+        /// Acknowledges the message and deletes it from the recipient member inbox. Returns
+        /// the message deposit to the sender.
+        #[weight = T::WeightInfo::acknowledge_message()]
+        pub fn acknowledge_message(origin, recipient_id: T::MemberId, message_id: MessageId) {
+            let message = Self::ensure_inbox_message(origin, recipient_id, message_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            T::Currency::unreserve(&message.deposit_account, message.deposit);
+
+            Self::remove_message(message_id, &message);
+            Self::deposit_event(RawEvent::MessageAcknowledged(message_id, recipient_id));
+        }
+
+        /// Deletes the message from the recipient member inbox without acknowledging it.
+        /// Burns the message deposit.
+        #[weight = T::WeightInfo::delete_message()]
+        pub fn delete_message(origin, recipient_id: T::MemberId, message_id: MessageId) {
+            let message = Self::ensure_inbox_message(origin, recipient_id, message_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::burn_message_deposit(message_id, message);
+        }
+
+        /// Deletes the expired message from any inbox. Burns the message deposit.
+        #[weight = T::WeightInfo::prune_expired_message()]
+        pub fn prune_expired_message(origin, message_id: MessageId) {
+            ensure_signed(origin)?;
+
+            let message = Self::ensure_message_exists(message_id)?;
+
+            let expires_at = message.sent_at + T::MessageLifetime::get();
+            ensure!(
+                <system::Module<T>>::block_number() > expires_at,
+                Error::<T>::MessageNotExpired
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::burn_message_deposit(message_id, message);
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Deposit required for the memo.
+    pub fn memo_deposit_for(memo: &[u8]) -> BalanceOf<T> {
+        T::MemoDepositPerByte::get().saturating_mul((memo.len() as u32).into())
+    }
+
+    fn ensure_member_controller_account(
+        origin: T::Origin,
+        member_id: &T::MemberId,
+    ) -> Result<T::AccountId, Error<T>> {
+        <membership::Module<T>>::ensure_member_controller_account_signed(origin, member_id)
+            .map_err(|_| Error::<T>::MemberControllerAccountRequired)
+    }
+
+    fn ensure_message_exists(message_id: MessageId) -> Result<MessageOf<T>, Error<T>> {
+        Self::message(message_id).ok_or(Error::<T>::MessageNotFound)
+    }
+
+    // Ensures the origin is the recipient controller account and the message is in its inbox.
+    fn ensure_inbox_message(
+        origin: T::Origin,
+        recipient_id: T::MemberId,
+        message_id: MessageId,
+    ) -> Result<MessageOf<T>, Error<T>> {
+        Self::ensure_member_controller_account(origin, &recipient_id)?;
+
+        let message = Self::ensure_message_exists(message_id)?;
+        ensure!(
+            message.recipient == recipient_id,
+            Error::<T>::MessageNotInInbox
+        );
+
+        Ok(message)
+    }
+
+    // Removes the message from the inbox and burns its deposit.
+    fn burn_message_deposit(message_id: MessageId, message: MessageOf<T>) {
+        // Dropping the slashed imbalance reduces the total issuance.
+        let _ = T::Currency::slash_reserved(&message.deposit_account, message.deposit);
+
+        Self::remove_message(message_id, &message);
+        Self::deposit_event(RawEvent::MessageDeleted(message_id, message.recipient));
+    }
+
+    // Removes the message from the inbox.
+    fn remove_message(message_id: MessageId, message: &MessageOf<T>) {
+        <Messages<T>>::remove(message_id);
+        <InboxMessageIds<T>>::mutate(message.recipient, |ids| ids.retain(|id| *id != message_id));
+        <InboxSize<T>>::mutate(message.recipient, |size| {
+            *size = size.saturating_sub(message.payload.len() as u32)
+        });
     }
 }
@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+pub use crate::*;
+
+use frame_support::{impl_outer_event, impl_outer_origin, parameter_types};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    Perbill,
+};
+
+mod membership_mod {
+    pub use membership::Event;
+}
+
+mod memo_mod {
+    pub use crate::Event;
+}
+
+impl_outer_origin! {
+    pub enum Origin for Test {}
+}
+
+impl_outer_event! {
+    pub enum MetaEvent for Test {
+        memo_mod<T>,
+        balances<T>,
+        membership_mod<T>,
+        system<T>,
+    }
+}
+
+// Workaround for https://github.com/rust-lang/rust/issues/26925 . Remove when sorted.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Test;
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: u32 = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+    pub const MinimumPeriod: u64 = 5;
+    pub const ExistentialDeposit: u32 = 0;
+}
+
+impl system::Trait for Test {
+    type BaseCallFilter = ();
+    type Origin = Origin;
+    type Call = ();
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = MetaEvent;
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type MaximumExtrinsicWeight = ();
+    type MaximumBlockLength = MaximumBlockLength;
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type Version = ();
+    type ModuleToIndex = ();
+    type AccountData = balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+}
+
+impl pallet_timestamp::Trait for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+}
+
+impl balances::Trait for Test {
+    type Balance = u64;
+    type DustRemoval = ();
+    type Event = MetaEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+}
+
+impl common::currency::GovernanceCurrency for Test {
+    type Currency = Balances;
+}
+
+impl membership::Trait for Test {
+    type Event = MetaEvent;
+    type MemberId = u64;
+    type PaidTermId = u64;
+    type SubscriptionId = u64;
+    type ActorId = u64;
+    type WeightInfo = ();
+}
+
+pub(crate) const MEMO_DEPOSIT_PER_BYTE: u64 = 2;
+pub(crate) const MESSAGE_DEPOSIT: u64 = 10;
+pub(crate) const MAX_MESSAGE_LENGTH: u32 = 100;
+pub(crate) const MAX_INBOX_MESSAGES: u32 = 3;
+pub(crate) const MAX_INBOX_SIZE: u32 = 250;
+pub(crate) const MESSAGE_LIFETIME: u64 = 100;
+
+parameter_types! {
+    pub const MemoDepositPerByte: u64 = MEMO_DEPOSIT_PER_BYTE;
+    pub const MessageDeposit: u64 = MESSAGE_DEPOSIT;
+    pub const MaxMessageLength: u32 = MAX_MESSAGE_LENGTH;
+    pub const MaxInboxMessages: u32 = MAX_INBOX_MESSAGES;
+    pub const MaxInboxSize: u32 = MAX_INBOX_SIZE;
+    pub const MessageLifetime: u64 = MESSAGE_LIFETIME;
+}
+
+impl Trait for Test {
+    type Event = MetaEvent;
+    type MemoDepositPerByte = MemoDepositPerByte;
+    type MessageDeposit = MessageDeposit;
+    type MaxMessageLength = MaxMessageLength;
+    type MaxInboxMessages = MaxInboxMessages;
+    type MaxInboxSize = MaxInboxSize;
+    type MessageLifetime = MessageLifetime;
+    type WeightInfo = ();
+}
+
+pub(crate) const SENDER_MEMBER_ID: u64 = 0;
+pub(crate) const SENDER_ACCOUNT_ID: u64 = 1;
+pub(crate) const RECIPIENT_MEMBER_ID: u64 = 1;
+pub(crate) const RECIPIENT_ACCOUNT_ID: u64 = 2;
+pub(crate) const INITIAL_BALANCE: u64 = 1000;
+
+pub fn initial_test_ext() -> sp_io::TestExternalities {
+    let mut t = system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+
+    membership::genesis::GenesisConfigBuilder::<Test>::default()
+        .members(vec![
+            (SENDER_MEMBER_ID, SENDER_ACCOUNT_ID),
+            (RECIPIENT_MEMBER_ID, RECIPIENT_ACCOUNT_ID),
+        ])
+        .build()
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+    balances::GenesisConfig::<Test> {
+        balances: vec![
+            (SENDER_ACCOUNT_ID, INITIAL_BALANCE),
+            (RECIPIENT_ACCOUNT_ID, INITIAL_BALANCE),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    t.into()
+}
+
+pub type Balances = balances::Module<Test>;
+pub type System = system::Module<Test>;
+pub type MemoModule = Module<Test>;
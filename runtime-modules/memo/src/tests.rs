@@ -0,0 +1,449 @@
+#![cfg(test)]
+
+use super::mock::*;
+
+use frame_support::traits::{Currency, ReservableCurrency};
+use system::{EventRecord, Phase, RawOrigin};
+
+#[test]
+fn update_memo_reserves_deposit() {
+    initial_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let memo = b"memo text".to_vec();
+        assert!(MemoModule::update_memo(Origin::signed(SENDER_ACCOUNT_ID), memo.clone()).is_ok());
+
+        let deposit = MEMO_DEPOSIT_PER_BYTE * memo.len() as u64;
+        assert_eq!(MemoModule::memo(SENDER_ACCOUNT_ID), memo);
+        assert_eq!(MemoModule::memo_deposit(SENDER_ACCOUNT_ID), deposit);
+        assert_eq!(Balances::reserved_balance(SENDER_ACCOUNT_ID), deposit);
+
+        assert_eq!(
+            *System::events().last().unwrap(),
+            EventRecord {
+                phase: Phase::Initialization,
+                event: MetaEvent::memo_mod(RawEvent::MemoUpdated(SENDER_ACCOUNT_ID)),
+                topics: vec![]
+            }
+        );
+
+        // Shorter memo returns the deposit excess.
+        let short_memo = b"memo".to_vec();
+        assert!(
+            MemoModule::update_memo(Origin::signed(SENDER_ACCOUNT_ID), short_memo.clone()).is_ok()
+        );
+
+        let short_memo_deposit = MEMO_DEPOSIT_PER_BYTE * short_memo.len() as u64;
+        assert_eq!(
+            MemoModule::memo_deposit(SENDER_ACCOUNT_ID),
+            short_memo_deposit
+        );
+        assert_eq!(
+            Balances::reserved_balance(SENDER_ACCOUNT_ID),
+            short_memo_deposit
+        );
+        assert_eq!(
+            Balances::free_balance(SENDER_ACCOUNT_ID),
+            INITIAL_BALANCE - short_memo_deposit
+        );
+    });
+}
+
+#[test]
+fn update_memo_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        let too_long_memo = vec![1; MemoModule::max_memo_length() as usize + 1];
+        assert_eq!(
+            MemoModule::update_memo(Origin::signed(SENDER_ACCOUNT_ID), too_long_memo),
+            Err(Error::<Test>::MemoTooLong.into())
+        );
+
+        let unaffordable_memo = vec![1; (INITIAL_BALANCE / MEMO_DEPOSIT_PER_BYTE) as usize + 1];
+        assert_eq!(
+            MemoModule::update_memo(Origin::signed(SENDER_ACCOUNT_ID), unaffordable_memo),
+            Err(Error::<Test>::InsufficientBalanceForMemoDeposit.into())
+        );
+
+        assert!(MemoModule::memo(SENDER_ACCOUNT_ID).is_empty());
+        assert_eq!(Balances::reserved_balance(SENDER_ACCOUNT_ID), 0);
+    });
+}
+
+#[test]
+fn clear_memo() {
+    initial_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert!(
+            MemoModule::update_memo(Origin::signed(SENDER_ACCOUNT_ID), b"memo text".to_vec())
+                .is_ok()
+        );
+
+        assert!(MemoModule::clear_memo(Origin::signed(SENDER_ACCOUNT_ID)).is_ok());
+
+        assert!(!<MemoDeposit<Test>>::contains_key(SENDER_ACCOUNT_ID));
+        assert!(MemoModule::memo(SENDER_ACCOUNT_ID).is_empty());
+        assert_eq!(Balances::reserved_balance(SENDER_ACCOUNT_ID), 0);
+        assert_eq!(Balances::free_balance(SENDER_ACCOUNT_ID), INITIAL_BALANCE);
+
+        assert_eq!(
+            *System::events().last().unwrap(),
+            EventRecord {
+                phase: Phase::Initialization,
+                event: MetaEvent::memo_mod(RawEvent::MemoCleared(SENDER_ACCOUNT_ID)),
+                topics: vec![]
+            }
+        );
+    });
+}
+
+#[test]
+fn legacy_memo_is_exempt_until_updated() {
+    initial_test_ext().execute_with(|| {
+        // Memo published before the deposits were introduced
+        <Memo<Test>>::insert(SENDER_ACCOUNT_ID, b"legacy memo text".to_vec());
+
+        // Updating the legacy memo reserves the full deposit, even for a shorter memo
+        let memo = b"memo".to_vec();
+        assert!(MemoModule::update_memo(Origin::signed(SENDER_ACCOUNT_ID), memo.clone()).is_ok());
+
+        let deposit = MEMO_DEPOSIT_PER_BYTE * memo.len() as u64;
+        assert_eq!(MemoModule::memo_deposit(SENDER_ACCOUNT_ID), deposit);
+        assert_eq!(Balances::reserved_balance(SENDER_ACCOUNT_ID), deposit);
+
+        // Clearing the legacy memo returns nothing
+        <Memo<Test>>::insert(RECIPIENT_ACCOUNT_ID, b"legacy memo text".to_vec());
+        assert!(MemoModule::clear_memo(Origin::signed(RECIPIENT_ACCOUNT_ID)).is_ok());
+
+        assert!(MemoModule::memo(RECIPIENT_ACCOUNT_ID).is_empty());
+        assert_eq!(
+            Balances::free_balance(RECIPIENT_ACCOUNT_ID),
+            INITIAL_BALANCE
+        );
+    });
+}
+
+#[test]
+fn set_max_memo_length() {
+    initial_test_ext().execute_with(|| {
+        let max_memo_length = 5;
+
+        // privileged method should fail if not from root origin
+        assert!(MemoModule::set_max_memo_length(
+            Origin::signed(SENDER_ACCOUNT_ID),
+            max_memo_length
+        )
+        .is_err());
+
+        assert!(MemoModule::set_max_memo_length(RawOrigin::Root.into(), max_memo_length).is_ok());
+        assert_eq!(MemoModule::max_memo_length(), max_memo_length);
+
+        assert_eq!(
+            MemoModule::update_memo(
+                Origin::signed(SENDER_ACCOUNT_ID),
+                vec![1; max_memo_length as usize + 1]
+            ),
+            Err(Error::<Test>::MemoTooLong.into())
+        );
+    });
+}
+
+fn send_test_message(payload: Vec<u8>) -> sp_runtime::DispatchResult {
+    MemoModule::send_message(
+        Origin::signed(SENDER_ACCOUNT_ID),
+        SENDER_MEMBER_ID,
+        RECIPIENT_MEMBER_ID,
+        payload,
+    )
+}
+
+#[test]
+fn send_message() {
+    initial_test_ext().execute_with(|| {
+        let current_block_number = 10;
+        System::set_block_number(current_block_number);
+
+        let payload = b"sealed payload".to_vec();
+        assert!(send_test_message(payload.clone()).is_ok());
+
+        let message_id = 0;
+        assert_eq!(
+            MemoModule::message(message_id),
+            Some(Message {
+                sender: SENDER_MEMBER_ID,
+                recipient: RECIPIENT_MEMBER_ID,
+                deposit_account: SENDER_ACCOUNT_ID,
+                deposit: MESSAGE_DEPOSIT,
+                payload: payload.clone(),
+                sent_at: current_block_number,
+            })
+        );
+        assert_eq!(MemoModule::next_message_id(), message_id + 1);
+        assert_eq!(
+            MemoModule::inbox_message_ids(RECIPIENT_MEMBER_ID),
+            vec![message_id]
+        );
+        assert_eq!(
+            MemoModule::inbox_size(RECIPIENT_MEMBER_ID),
+            payload.len() as u32
+        );
+        assert_eq!(
+            Balances::reserved_balance(SENDER_ACCOUNT_ID),
+            MESSAGE_DEPOSIT
+        );
+
+        assert_eq!(
+            *System::events().last().unwrap(),
+            EventRecord {
+                phase: Phase::Initialization,
+                event: MetaEvent::memo_mod(RawEvent::MessageSent(
+                    message_id,
+                    SENDER_MEMBER_ID,
+                    RECIPIENT_MEMBER_ID
+                )),
+                topics: vec![]
+            }
+        );
+    });
+}
+
+#[test]
+fn send_message_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        let payload = b"sealed payload".to_vec();
+
+        // Not a controller account of the sender
+        assert_eq!(
+            MemoModule::send_message(
+                Origin::signed(RECIPIENT_ACCOUNT_ID),
+                SENDER_MEMBER_ID,
+                RECIPIENT_MEMBER_ID,
+                payload.clone(),
+            ),
+            Err(Error::<Test>::MemberControllerAccountRequired.into())
+        );
+
+        // Unknown recipient
+        let unknown_member_id = 10;
+        assert_eq!(
+            MemoModule::send_message(
+                Origin::signed(SENDER_ACCOUNT_ID),
+                SENDER_MEMBER_ID,
+                unknown_member_id,
+                payload,
+            ),
+            Err(Error::<Test>::RecipientMemberNotFound.into())
+        );
+
+        assert_eq!(
+            send_test_message(Vec::new()),
+            Err(Error::<Test>::EmptyMessage.into())
+        );
+
+        assert_eq!(
+            send_test_message(vec![1; MAX_MESSAGE_LENGTH as usize + 1]),
+            Err(Error::<Test>::MessageTooLong.into())
+        );
+
+        assert!(MemoModule::inbox_message_ids(RECIPIENT_MEMBER_ID).is_empty());
+    });
+}
+
+#[test]
+fn send_message_fails_with_full_inbox() {
+    initial_test_ext().execute_with(|| {
+        // Inbox size limit
+        let long_payload = vec![1; MAX_MESSAGE_LENGTH as usize];
+        assert!(send_test_message(long_payload.clone()).is_ok());
+        assert!(send_test_message(long_payload.clone()).is_ok());
+        assert_eq!(
+            send_test_message(long_payload),
+            Err(Error::<Test>::InboxSizeLimitExceeded.into())
+        );
+
+        // Inbox messages limit
+        assert!(send_test_message(vec![1]).is_ok());
+        assert_eq!(
+            send_test_message(vec![1]),
+            Err(Error::<Test>::InboxMessagesLimitReached.into())
+        );
+    });
+}
+
+#[test]
+fn send_message_fails_with_insufficient_balance() {
+    initial_test_ext().execute_with(|| {
+        let _ = Balances::slash(&SENDER_ACCOUNT_ID, INITIAL_BALANCE - MESSAGE_DEPOSIT + 1);
+
+        assert_eq!(
+            send_test_message(b"sealed payload".to_vec()),
+            Err(Error::<Test>::InsufficientBalanceForMessageDeposit.into())
+        );
+    });
+}
+
+#[test]
+fn acknowledge_message() {
+    initial_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert!(send_test_message(b"sealed payload".to_vec()).is_ok());
+
+        let message_id = 0;
+
+        // Only the recipient can acknowledge the message
+        assert_eq!(
+            MemoModule::acknowledge_message(
+                Origin::signed(SENDER_ACCOUNT_ID),
+                SENDER_MEMBER_ID,
+                message_id
+            ),
+            Err(Error::<Test>::MessageNotInInbox.into())
+        );
+
+        assert!(MemoModule::acknowledge_message(
+            Origin::signed(RECIPIENT_ACCOUNT_ID),
+            RECIPIENT_MEMBER_ID,
+            message_id
+        )
+        .is_ok());
+
+        assert!(MemoModule::message(message_id).is_none());
+        assert!(MemoModule::inbox_message_ids(RECIPIENT_MEMBER_ID).is_empty());
+        assert_eq!(MemoModule::inbox_size(RECIPIENT_MEMBER_ID), 0);
+
+        // The deposit is returned to the sender
+        assert_eq!(Balances::reserved_balance(SENDER_ACCOUNT_ID), 0);
+        assert_eq!(Balances::free_balance(SENDER_ACCOUNT_ID), INITIAL_BALANCE);
+
+        assert_eq!(
+            *System::events().last().unwrap(),
+            EventRecord {
+                phase: Phase::Initialization,
+                event: MetaEvent::memo_mod(RawEvent::MessageAcknowledged(
+                    message_id,
+                    RECIPIENT_MEMBER_ID
+                )),
+                topics: vec![]
+            }
+        );
+    });
+}
+
+#[test]
+fn delete_message() {
+    initial_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert!(send_test_message(b"first".to_vec()).is_ok());
+        assert!(send_test_message(b"second".to_vec()).is_ok());
+
+        let message_id = 0;
+
+        // Only the recipient can delete the message
+        assert_eq!(
+            MemoModule::delete_message(
+                Origin::signed(SENDER_ACCOUNT_ID),
+                RECIPIENT_MEMBER_ID,
+                message_id
+            ),
+            Err(Error::<Test>::MemberControllerAccountRequired.into())
+        );
+        assert_eq!(
+            MemoModule::delete_message(
+                Origin::signed(SENDER_ACCOUNT_ID),
+                SENDER_MEMBER_ID,
+                message_id
+            ),
+            Err(Error::<Test>::MessageNotInInbox.into())
+        );
+
+        assert!(MemoModule::delete_message(
+            Origin::signed(RECIPIENT_ACCOUNT_ID),
+            RECIPIENT_MEMBER_ID,
+            message_id
+        )
+        .is_ok());
+
+        assert!(MemoModule::message(message_id).is_none());
+        assert_eq!(MemoModule::inbox_message_ids(RECIPIENT_MEMBER_ID), vec![1]);
+        assert_eq!(
+            MemoModule::inbox_size(RECIPIENT_MEMBER_ID),
+            b"second".len() as u32
+        );
+
+        // The deposit of the deleted message is burned
+        assert_eq!(
+            Balances::reserved_balance(SENDER_ACCOUNT_ID),
+            MESSAGE_DEPOSIT
+        );
+        assert_eq!(
+            Balances::free_balance(SENDER_ACCOUNT_ID),
+            INITIAL_BALANCE - 2 * MESSAGE_DEPOSIT
+        );
+        assert_eq!(
+            Balances::total_issuance(),
+            2 * INITIAL_BALANCE - MESSAGE_DEPOSIT
+        );
+
+        assert_eq!(
+            *System::events().last().unwrap(),
+            EventRecord {
+                phase: Phase::Initialization,
+                event: MetaEvent::memo_mod(RawEvent::MessageDeleted(
+                    message_id,
+                    RECIPIENT_MEMBER_ID
+                )),
+                topics: vec![]
+            }
+        );
+
+        assert_eq!(
+            MemoModule::delete_message(
+                Origin::signed(RECIPIENT_ACCOUNT_ID),
+                RECIPIENT_MEMBER_ID,
+                message_id
+            ),
+            Err(Error::<Test>::MessageNotFound.into())
+        );
+    });
+}
+
+#[test]
+fn prune_expired_message() {
+    initial_test_ext().execute_with(|| {
+        let sent_at = 1;
+        System::set_block_number(sent_at);
+
+        assert!(send_test_message(b"sealed payload".to_vec()).is_ok());
+
+        let message_id = 0;
+        let any_account_id = 100;
+
+        System::set_block_number(sent_at + MESSAGE_LIFETIME);
+        assert_eq!(
+            MemoModule::prune_expired_message(Origin::signed(any_account_id), message_id),
+            Err(Error::<Test>::MessageNotExpired.into())
+        );
+
+        System::set_block_number(sent_at + MESSAGE_LIFETIME + 1);
+        assert!(
+            MemoModule::prune_expired_message(Origin::signed(any_account_id), message_id).is_ok()
+        );
+
+        assert!(MemoModule::message(message_id).is_none());
+        assert!(MemoModule::inbox_message_ids(RECIPIENT_MEMBER_ID).is_empty());
+        assert_eq!(MemoModule::inbox_size(RECIPIENT_MEMBER_ID), 0);
+
+        // The deposit of the expired message is burned
+        assert_eq!(Balances::reserved_balance(SENDER_ACCOUNT_ID), 0);
+        assert_eq!(
+            Balances::free_balance(SENDER_ACCOUNT_ID),
+            INITIAL_BALANCE - MESSAGE_DEPOSIT
+        );
+        assert_eq!(
+            Balances::total_issuance(),
+            2 * INITIAL_BALANCE - MESSAGE_DEPOSIT
+        );
+    });
+}
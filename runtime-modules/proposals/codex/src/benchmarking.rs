@@ -0,0 +1,321 @@
+//! Proposals codex module benchmarking.
+
+use super::*;
+use crate::proposal_types::parameters;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::StorageValue;
+use sp_std::prelude::*;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+const FREE_BALANCE: u32 = 10_000_000;
+
+/// Registers a funded member able to create proposals.
+fn create_proposer<T: Trait>() -> (MemberId<T>, T::AccountId) {
+    let account_id: T::AccountId = account("proposer", 0, SEED);
+    let member_id = membership::Module::<T>::members_created();
+
+    membership::ScreeningAuthority::<T>::put(account_id.clone());
+    membership::Module::<T>::add_screened_member(
+        RawOrigin::Signed(account_id.clone()).into(),
+        account_id.clone(),
+        Some(b"proposer".to_vec()),
+        None,
+        None,
+    )
+    .expect("benchmark member must be registered");
+
+    CurrencyOf::<T>::make_free_balance_be(&account_id, FREE_BALANCE.into());
+
+    (member_id, account_id)
+}
+
+// The title is shared by the proposal and its discussion thread.
+fn max_title<T: Trait>() -> Vec<u8> {
+    let max_length = T::TitleMaxLength::get().min(T::ThreadTitleLengthLimit::get());
+
+    vec![b't'; max_length as usize]
+}
+
+fn max_description<T: Trait>() -> Vec<u8> {
+    vec![b'd'; T::DescriptionMaxLength::get() as usize]
+}
+
+fn assert_proposal_created<T: Trait>() {
+    assert_eq!(proposals_engine::Module::<T>::active_proposal_count(), 1);
+}
+
+fn election_parameters<T: Trait>(
+) -> ElectionParameters<BalanceOfGovernanceCurrency<T>, T::BlockNumber> {
+    ElectionParameters {
+        announcing_period: ELECTION_PARAMETERS_ANNOUNCING_PERIOD_MIN_VALUE.into(),
+        voting_period: ELECTION_PARAMETERS_VOTING_PERIOD_MIN_VALUE.into(),
+        revealing_period: ELECTION_PARAMETERS_REVEALING_PERIOD_MIN_VALUE.into(),
+        council_size: ELECTION_PARAMETERS_COUNCIL_SIZE_MIN_VALUE,
+        candidacy_limit: ELECTION_PARAMETERS_CANDIDACY_LIMIT_MIN_VALUE,
+        new_term_duration: ELECTION_PARAMETERS_NEW_TERM_DURATION_MIN_VALUE.into(),
+        min_council_stake: ELECTION_PARAMETERS_MIN_COUNCIL_STAKE_MIN_VALUE.into(),
+        min_voting_stake: ELECTION_PARAMETERS_MIN_STAKE_MIN_VALUE.into(),
+    }
+}
+
+benchmarks! {
+    _ { }
+
+    create_text_proposal {
+        let i in 1 .. T::TextProposalMaxLength::get();
+
+        let (member_id, account_id) = create_proposer::<T>();
+        let stake = parameters::text_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        max_title::<T>(),
+        max_description::<T>(),
+        stake,
+        vec![b'x'; i as usize]
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_runtime_upgrade_proposal {
+        let i in 1 .. T::RuntimeUpgradeWasmProposalMaxLength::get();
+
+        let (member_id, account_id) = create_proposer::<T>();
+        let stake = parameters::runtime_upgrade_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        max_title::<T>(),
+        max_description::<T>(),
+        stake,
+        vec![b'x'; i as usize]
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_election_parameters_proposal {
+        let (member_id, account_id) = create_proposer::<T>();
+        let stake = parameters::set_election_parameters_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        max_title::<T>(),
+        max_description::<T>(),
+        stake,
+        election_parameters::<T>()
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_spending_proposal {
+        let (member_id, account_id) = create_proposer::<T>();
+        let stake = parameters::spending_proposal::<T>().required_stake;
+        let destination: T::AccountId = account("destination", 0, SEED);
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        max_title::<T>(),
+        max_description::<T>(),
+        stake,
+        MAX_SPENDING_PROPOSAL_VALUE.into(),
+        destination
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_validator_count_proposal {
+        let (member_id, account_id) = create_proposer::<T>();
+        let stake = parameters::set_validator_count_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        max_title::<T>(),
+        max_description::<T>(),
+        stake,
+        MAX_VALIDATOR_COUNT
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_add_working_group_leader_opening_proposal {
+        let (member_id, account_id) = create_proposer::<T>();
+        let stake = parameters::add_working_group_leader_opening_proposal::<T>().required_stake;
+        let add_opening_parameters = AddOpeningParameters {
+            activate_at: hiring::ActivateOpeningAt::CurrentBlock,
+            commitment: working_group::OpeningPolicyCommitment::default(),
+            human_readable_text: max_description::<T>(),
+            working_group: WorkingGroup::Storage,
+        };
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        max_title::<T>(),
+        max_description::<T>(),
+        stake,
+        add_opening_parameters
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_begin_review_working_group_leader_applications_proposal {
+        let (member_id, account_id) = create_proposer::<T>();
+        let stake =
+            parameters::begin_review_working_group_leader_applications_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        max_title::<T>(),
+        max_description::<T>(),
+        stake,
+        Default::default(),
+        WorkingGroup::Storage
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_fill_working_group_leader_opening_proposal {
+        let (member_id, account_id) = create_proposer::<T>();
+        let stake = parameters::fill_working_group_leader_opening_proposal::<T>().required_stake;
+        let fill_opening_parameters = FillOpeningParameters {
+            opening_id: Default::default(),
+            successful_application_id: Default::default(),
+            reward_policy: Some(working_group::RewardPolicy {
+                amount_per_payout: 1u32.into(),
+                next_payment_at_block: <system::Module<T>>::block_number(),
+                payout_interval: None,
+            }),
+            working_group: WorkingGroup::Storage,
+        };
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        max_title::<T>(),
+        max_description::<T>(),
+        stake,
+        fill_opening_parameters
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_working_group_mint_capacity_proposal {
+        let (member_id, account_id) = create_proposer::<T>();
+        let stake = parameters::set_working_group_mint_capacity_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        max_title::<T>(),
+        max_description::<T>(),
+        stake,
+        WORKING_GROUP_MINT_CAPACITY_MAX_VALUE.into(),
+        WorkingGroup::Storage
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_decrease_working_group_leader_stake_proposal {
+        let (member_id, account_id) = create_proposer::<T>();
+        let stake = parameters::decrease_working_group_leader_stake_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        max_title::<T>(),
+        max_description::<T>(),
+        stake,
+        Default::default(),
+        1u32.into(),
+        WorkingGroup::Storage
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_slash_working_group_leader_stake_proposal {
+        let (member_id, account_id) = create_proposer::<T>();
+        let stake = parameters::slash_working_group_leader_stake_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        max_title::<T>(),
+        max_description::<T>(),
+        stake,
+        Default::default(),
+        1u32.into(),
+        WorkingGroup::Storage
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_working_group_leader_reward_proposal {
+        let (member_id, account_id) = create_proposer::<T>();
+        let stake = parameters::set_working_group_leader_reward_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        max_title::<T>(),
+        max_description::<T>(),
+        stake,
+        Default::default(),
+        1u32.into(),
+        WorkingGroup::Storage
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_terminate_working_group_leader_role_proposal {
+        let (member_id, account_id) = create_proposer::<T>();
+        let stake = parameters::terminate_working_group_leader_role_proposal::<T>().required_stake;
+        let terminate_role_parameters = TerminateRoleParameters {
+            worker_id: Default::default(),
+            rationale: max_description::<T>(),
+            slash: true,
+            working_group: WorkingGroup::Storage,
+        };
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        max_title::<T>(),
+        max_description::<T>(),
+        stake,
+        terminate_role_parameters
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_membership_validation_limits_proposal {
+        let (member_id, account_id) = create_proposer::<T>();
+        let stake = parameters::set_membership_validation_limits_proposal::<T>().required_stake;
+        let validation_limits = membership::ValidationLimits {
+            min_handle_length: 5,
+            max_handle_length: 40,
+            max_avatar_uri_length: 1024,
+            max_about_text_length: 2048,
+        };
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        max_title::<T>(),
+        max_description::<T>(),
+        stake,
+        validation_limits
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    execute_text_proposal {
+        let i in 1 .. T::TextProposalMaxLength::get();
+    }: _(RawOrigin::Root, vec![b'x'; i as usize])
+}
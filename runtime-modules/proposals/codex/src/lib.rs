@@ -16,6 +16,7 @@
 //! - [create_text_proposal](./struct.Module.html#method.create_text_proposal)
 //! - [create_runtime_upgrade_proposal](./struct.Module.html#method.create_runtime_upgrade_proposal)
 //! - [create_set_validator_count_proposal](./struct.Module.html#method.create_set_validator_count_proposal)
+//! - [create_set_membership_validation_limits_proposal](./struct.Module.html#method.create_set_membership_validation_limits_proposal)
 //!
 //! ### Council and election proposals
 //! - [create_set_election_parameters_proposal](./struct.Module.html#method.create_set_election_parameters_proposal)
@@ -56,6 +57,8 @@
 // Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
 // #![warn(missing_docs)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 mod proposal_types;
 
 #[cfg(test)]
@@ -63,6 +66,7 @@ mod tests;
 
 use frame_support::dispatch::DispatchResult;
 use frame_support::traits::{Currency, Get};
+use frame_support::weights::{DispatchClass, Weight};
 use frame_support::{decl_error, decl_module, decl_storage, ensure, print};
 use sp_arithmetic::traits::Zero;
 use sp_std::clone::Clone;
@@ -73,6 +77,7 @@ use system::ensure_root;
 use common::origin::ActorOriginValidator;
 use common::working_group::WorkingGroup;
 use governance::election_params::ElectionParameters;
+use membership::ValidationLimits;
 use proposals_engine::ProposalParameters;
 
 pub use crate::proposal_types::{
@@ -157,6 +162,77 @@ pub trait Trait:
 
     /// Encodes the proposal usint its details
     type ProposalEncoder: ProposalEncoder<Self>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+}
+
+/// Weight functions needed for the proposals codex module.
+pub trait WeightInfo {
+    fn create_text_proposal(i: u32) -> Weight;
+    fn create_runtime_upgrade_proposal(i: u32) -> Weight;
+    fn create_set_election_parameters_proposal() -> Weight;
+    fn create_spending_proposal() -> Weight;
+    fn create_set_validator_count_proposal() -> Weight;
+    fn create_add_working_group_leader_opening_proposal() -> Weight;
+    fn create_begin_review_working_group_leader_applications_proposal() -> Weight;
+    fn create_fill_working_group_leader_opening_proposal() -> Weight;
+    fn create_set_working_group_mint_capacity_proposal() -> Weight;
+    fn create_decrease_working_group_leader_stake_proposal() -> Weight;
+    fn create_slash_working_group_leader_stake_proposal() -> Weight;
+    fn create_set_working_group_leader_reward_proposal() -> Weight;
+    fn create_terminate_working_group_leader_role_proposal() -> Weight;
+    fn create_set_membership_validation_limits_proposal() -> Weight;
+    fn execute_text_proposal(i: u32) -> Weight;
+}
+
+/// Flat weights for the test runtimes.
+impl WeightInfo for () {
+    fn create_text_proposal(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn create_runtime_upgrade_proposal(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn create_set_election_parameters_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_spending_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_set_validator_count_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_add_working_group_leader_opening_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_begin_review_working_group_leader_applications_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_fill_working_group_leader_opening_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_set_working_group_mint_capacity_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_decrease_working_group_leader_stake_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_slash_working_group_leader_stake_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_set_working_group_leader_reward_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_terminate_working_group_leader_role_proposal() -> Weight {
+        10_000_000
+    }
+    fn create_set_membership_validation_limits_proposal() -> Weight {
+        10_000_000
+    }
+    fn execute_text_proposal(_i: u32) -> Weight {
+        10_000_000
+    }
 }
 
 /// Balance alias for `stake` module
@@ -244,6 +320,9 @@ decl_error! {
 
         /// Invalid 'decrease stake proposal' parameter - cannot decrease by zero balance.
         DecreasingStakeIsZero,
+
+        /// Invalid 'set membership validation limits' proposal parameter - inconsistent limits.
+        InvalidMembershipValidationLimits,
     }
 }
 
@@ -350,6 +429,14 @@ decl_storage! {
         /// Grace period for the 'terminate working group leader role' proposal
         pub TerminateWorkingGroupLeaderRoleProposalGracePeriod get(fn terminate_working_group_leader_role_proposal_grace_period)
             config(): T::BlockNumber;
+
+        /// Voting period for the 'set membership validation limits' proposal
+        pub SetMembershipValidationLimitsProposalVotingPeriod get(fn set_membership_validation_limits_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'set membership validation limits' proposal
+        pub SetMembershipValidationLimitsProposalGracePeriod get(fn set_membership_validation_limits_proposal_grace_period)
+            config(): T::BlockNumber;
     }
 }
 
@@ -366,7 +453,7 @@ decl_module! {
         const RuntimeUpgradeWasmProposalMaxLength: u32 = T::RuntimeUpgradeWasmProposalMaxLength::get();
 
         /// Create 'Text (signal)' proposal type.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_text_proposal(text.len() as u32)]
         pub fn create_text_proposal(
             origin,
             member_id: MemberId<T>,
@@ -396,7 +483,7 @@ decl_module! {
 
         /// Create 'Runtime upgrade' proposal type. Runtime upgrade can be initiated only by
         /// members from the hardcoded list `RuntimeUpgradeProposalAllowedProposers`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_runtime_upgrade_proposal(wasm.len() as u32)]
         pub fn create_runtime_upgrade_proposal(
             origin,
             member_id: MemberId<T>,
@@ -426,7 +513,7 @@ decl_module! {
 
         /// Create 'Set election parameters' proposal type. This proposal uses `set_election_parameters()`
         /// extrinsic from the `governance::election module`.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_set_election_parameters_proposal()]
         pub fn create_set_election_parameters_proposal(
             origin,
             member_id: MemberId<T>,
@@ -456,7 +543,7 @@ decl_module! {
 
         /// Create 'Spending' proposal type.
         /// This proposal uses `spend_from_council_mint()` extrinsic from the `governance::council`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_spending_proposal()]
         pub fn create_spending_proposal(
             origin,
             member_id: MemberId<T>,
@@ -489,7 +576,7 @@ decl_module! {
 
         /// Create 'Evict storage provider' proposal type.
         /// This proposal uses `set_validator_count()` extrinsic from the Substrate `staking`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_set_validator_count_proposal()]
         pub fn create_set_validator_count_proposal(
             origin,
             member_id: MemberId<T>,
@@ -523,9 +610,41 @@ decl_module! {
             Self::create_proposal(params)?;
         }
 
+        /// Create 'Set membership validation limits' proposal type.
+        /// This proposal uses `set_validation_limits()` extrinsic from the Joystream `membership` module.
+        #[weight = <T as Trait>::WeightInfo::create_set_membership_validation_limits_proposal()]
+        pub fn create_set_membership_validation_limits_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            validation_limits: ValidationLimits,
+        ) {
+            ensure!(
+                <membership::Module<T>>::ensure_valid_validation_limits(&validation_limits).is_ok(),
+                Error::<T>::InvalidMembershipValidationLimits
+            );
+
+            let proposal_details = ProposalDetails::SetMembershipValidationLimits(validation_limits);
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters:
+                    proposal_types::parameters::set_membership_validation_limits_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
         /// Create 'Add working group leader opening' proposal type.
         /// This proposal uses `add_opening()` extrinsic from the Joystream `working group` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_add_working_group_leader_opening_proposal()]
         pub fn create_add_working_group_leader_opening_proposal(
             origin,
             member_id: MemberId<T>,
@@ -552,7 +671,7 @@ decl_module! {
 
         /// Create 'Begin review working group leader applications' proposal type.
         /// This proposal uses `begin_applicant_review()` extrinsic from the Joystream `working group` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_begin_review_working_group_leader_applications_proposal()]
         pub fn create_begin_review_working_group_leader_applications_proposal(
             origin,
             member_id: MemberId<T>,
@@ -580,7 +699,7 @@ decl_module! {
 
         /// Create 'Fill working group leader opening' proposal type.
         /// This proposal uses `fill_opening()` extrinsic from the Joystream `working group` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_fill_working_group_leader_opening_proposal()]
         pub fn create_fill_working_group_leader_opening_proposal(
             origin,
             member_id: MemberId<T>,
@@ -612,7 +731,7 @@ decl_module! {
 
         /// Create 'Set working group mint capacity' proposal type.
         /// This proposal uses `set_mint_capacity()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_set_working_group_mint_capacity_proposal()]
         pub fn create_set_working_group_mint_capacity_proposal(
             origin,
             member_id: MemberId<T>,
@@ -644,7 +763,7 @@ decl_module! {
 
         /// Create 'decrease working group leader stake' proposal type.
         /// This proposal uses `decrease_stake()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_decrease_working_group_leader_stake_proposal()]
         pub fn create_decrease_working_group_leader_stake_proposal(
             origin,
             member_id: MemberId<T>,
@@ -680,7 +799,7 @@ decl_module! {
 
         /// Create 'slash working group leader stake' proposal type.
         /// This proposal uses `slash_stake()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_slash_working_group_leader_stake_proposal()]
         pub fn create_slash_working_group_leader_stake_proposal(
             origin,
             member_id: MemberId<T>,
@@ -716,7 +835,7 @@ decl_module! {
 
         /// Create 'set working group leader reward' proposal type.
         /// This proposal uses `update_reward_amount()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_set_working_group_leader_reward_proposal()]
         pub fn create_set_working_group_leader_reward_proposal(
             origin,
             member_id: MemberId<T>,
@@ -750,7 +869,7 @@ decl_module! {
 
         /// Create 'terminate working group leader rolw' proposal type.
         /// This proposal uses `terminate_role()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_terminate_working_group_leader_role_proposal()]
         pub fn create_terminate_working_group_leader_role_proposal(
             origin,
             member_id: MemberId<T>,
@@ -779,7 +898,7 @@ decl_module! {
 // *************** Extrinsic to execute
 
         /// Text proposal extrinsic. Should be used as callable object to pass to the `engine` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::execute_text_proposal(text.len() as u32)]
         pub fn execute_text_proposal(
             origin,
             text: Vec<u8>,
@@ -794,7 +913,7 @@ decl_module! {
 
         /// Runtime upgrade proposal extrinsic.
         /// Should be used as callable object to pass to the `engine` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = (T::MaximumBlockWeight::get(), DispatchClass::Operational)]
         pub fn execute_runtime_upgrade_proposal(
             origin,
             wasm: Vec<u8>,
@@ -1034,5 +1153,11 @@ impl<T: Trait> Module<T> {
         <TerminateWorkingGroupLeaderRoleProposalGracePeriod<T>>::put(T::BlockNumber::from(
             p.terminate_working_group_leader_role_proposal_grace_period,
         ));
+        <SetMembershipValidationLimitsProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_membership_validation_limits_proposal_voting_period,
+        ));
+        <SetMembershipValidationLimitsProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_membership_validation_limits_proposal_grace_period,
+        ));
     }
 }
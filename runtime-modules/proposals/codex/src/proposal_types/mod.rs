@@ -9,6 +9,7 @@ use sp_std::vec::Vec;
 
 use crate::ElectionParameters;
 use common::working_group::WorkingGroup;
+use membership::ValidationLimits;
 
 /// Encodes proposal using its details information.
 pub trait ProposalEncoder<T: crate::Trait> {
@@ -103,6 +104,9 @@ pub enum ProposalDetails<
 
     /// Fire the working group leader with possible slashing.
     TerminateWorkingGroupLeaderRole(TerminateRoleParameters<WorkerId>),
+
+    /// User input validation limits for the `set membership validation limits` proposal
+    SetMembershipValidationLimits(ValidationLimits),
 }
 
 impl<
@@ -309,6 +313,12 @@ pub struct ProposalsConfigParameters {
 
     /// 'Terminate working group leader role' proposal grace period
     pub terminate_working_group_leader_role_proposal_grace_period: u32,
+
+    /// 'Set membership validation limits' proposal voting period
+    pub set_membership_validation_limits_proposal_voting_period: u32,
+
+    /// 'Set membership validation limits' proposal grace period
+    pub set_membership_validation_limits_proposal_grace_period: u32,
 }
 
 impl Default for ProposalsConfigParameters {
@@ -342,6 +352,8 @@ impl Default for ProposalsConfigParameters {
             set_working_group_leader_reward_proposal_grace_period: 0u32,
             terminate_working_group_leader_role_proposal_voting_period: 72200u32,
             terminate_working_group_leader_role_proposal_grace_period: 0u32,
+            set_membership_validation_limits_proposal_voting_period: 43200u32,
+            set_membership_validation_limits_proposal_grace_period: 0u32,
         }
     }
 }
@@ -380,6 +392,8 @@ impl ProposalsConfigParameters {
             set_working_group_leader_reward_proposal_grace_period: 0,
             terminate_working_group_leader_role_proposal_voting_period: voting_period,
             terminate_working_group_leader_role_proposal_grace_period: 0,
+            set_membership_validation_limits_proposal_voting_period: voting_period,
+            set_membership_validation_limits_proposal_grace_period: 0,
         }
     }
 }
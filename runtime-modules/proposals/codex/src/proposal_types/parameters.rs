@@ -182,3 +182,17 @@ pub(crate) fn terminate_working_group_leader_role_proposal<T: crate::Trait>(
         required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
     }
 }
+
+// Proposal parameters for the 'Set membership validation limits' proposal
+pub(crate) fn set_membership_validation_limits_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::set_membership_validation_limits_proposal_voting_period(),
+        grace_period: <Module<T>>::set_membership_validation_limits_proposal_grace_period(),
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+    }
+}
@@ -50,6 +50,7 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 parameter_types! {
@@ -109,6 +110,7 @@ impl minting::Trait for Test {
 impl governance::council::Trait for Test {
     type Event = ();
     type CouncilTermEnded = ();
+    type WeightInfo = ();
 }
 
 impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
@@ -152,6 +154,7 @@ parameter_types! {
 impl governance::election::Trait for Test {
     type Event = ();
     type CouncilElected = ();
+    type WeightInfo = ();
 }
 
 // The content directory working group instance alias.
@@ -167,11 +170,17 @@ parameter_types! {
 impl working_group::Trait<ContentDirectoryWorkingGroupInstance> for Test {
     type Event = ();
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
+    type WorkerExitHandler = ();
+    type WorkerRoleAccountUpdateHandler = ();
 }
 
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = ();
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
+    type WorkerExitHandler = ();
+    type WorkerRoleAccountUpdateHandler = ();
 }
 
 impl recurring_rewards::Trait for Test {
@@ -255,6 +264,7 @@ impl crate::Trait for Test {
     type RuntimeUpgradeWasmProposalMaxLength = RuntimeUpgradeWasmProposalMaxLength;
     type MembershipOriginValidator = ();
     type ProposalEncoder = ();
+    type WeightInfo = ();
 }
 
 impl ProposalEncoder<Test> for () {
@@ -8,6 +8,7 @@ use system::RawOrigin;
 use common::working_group::WorkingGroup;
 use governance::election_params::ElectionParameters;
 use hiring::ActivateOpeningAt;
+use membership::ValidationLimits;
 use proposals_engine::ProposalParameters;
 use working_group::OpeningPolicyCommitment;
 
@@ -761,6 +762,14 @@ fn set_default_proposal_parameters_succeeded() {
             <TerminateWorkingGroupLeaderRoleProposalGracePeriod<Test>>::get(),
             p.terminate_working_group_leader_role_proposal_grace_period as u64
         );
+        assert_eq!(
+            <SetMembershipValidationLimitsProposalVotingPeriod<Test>>::get(),
+            p.set_membership_validation_limits_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetMembershipValidationLimitsProposalGracePeriod<Test>>::get(),
+            p.set_membership_validation_limits_proposal_grace_period as u64
+        );
     });
 }
 
@@ -1445,3 +1454,92 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
         proposal_fixture.check_all();
     });
 }
+
+fn test_validation_limits() -> ValidationLimits {
+    ValidationLimits {
+        min_handle_length: 3,
+        max_handle_length: 50,
+        max_avatar_uri_length: 512,
+        max_about_text_length: 4096,
+    }
+}
+
+#[test]
+fn create_set_membership_validation_limits_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_membership_validation_limits_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    test_validation_limits(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_membership_validation_limits_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    test_validation_limits(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_membership_validation_limits_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    test_validation_limits(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_membership_validation_limits_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    test_validation_limits(),
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::set_membership_validation_limits_proposal::<
+                    Test,
+                >(),
+            proposal_details: ProposalDetails::SetMembershipValidationLimits(
+                test_validation_limits(),
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_membership_validation_limits_proposal_failed_with_invalid_limits() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalCodex::create_set_membership_validation_limits_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                ValidationLimits {
+                    min_handle_length: 60,
+                    ..test_validation_limits()
+                },
+            ),
+            Err(Error::<Test>::InvalidMembershipValidationLimits.into())
+        );
+    });
+}
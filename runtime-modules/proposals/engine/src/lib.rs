@@ -584,6 +584,48 @@ impl<T: Trait> Module<T> {
             });
         });
     }
+
+    /// Projects the decision for the proposal: the final decision of a finalized proposal or the
+    /// decision the current voting results lead to for an active proposal (None if the voting is
+    /// still in progress). Returns None for a non-existing proposal.
+    pub fn projected_decision_status(proposal_id: T::ProposalId) -> Option<ProposalDecisionStatus> {
+        if !<Proposals<T>>::contains_key(proposal_id) {
+            return None;
+        }
+
+        let proposal = Self::proposals(proposal_id);
+
+        match proposal.status.clone() {
+            ProposalStatus::Active(_) => proposal.define_proposal_decision_status(
+                T::TotalVotersCounter::total_voters_count(),
+                Self::current_block(),
+            ),
+            ProposalStatus::Finalized(finalization_data) => Some(finalization_data.proposal_status),
+        }
+    }
+
+    /// Returns the block at which the voting period of an active proposal or the grace period of
+    /// an approved proposal pending execution ends. Returns None for other proposals.
+    pub fn period_ends_at(proposal_id: T::ProposalId) -> Option<T::BlockNumber> {
+        if !<Proposals<T>>::contains_key(proposal_id) {
+            return None;
+        }
+
+        let proposal = Self::proposals(proposal_id);
+
+        match proposal.status {
+            ProposalStatus::Active(_) => {
+                Some(proposal.created_at + proposal.parameters.voting_period)
+            }
+            ProposalStatus::Finalized(FinalizationData {
+                proposal_status:
+                    ProposalDecisionStatus::Approved(ApprovedProposalStatus::PendingExecution),
+                finalized_at,
+                ..
+            }) => Some(finalized_at + proposal.parameters.grace_period),
+            ProposalStatus::Finalized(_) => None,
+        }
+    }
 }
 
 impl<T: Trait> Module<T> {
@@ -836,8 +878,8 @@ type ApprovedProposal<T> = ApprovedProposalData<
     <T as system::Trait>::AccountId,
 >;
 
-// Simplification of the 'Proposal' type
-type ProposalOf<T> = Proposal<
+/// Simplification of the 'Proposal' type
+pub type ProposalOf<T> = Proposal<
     <T as system::Trait>::BlockNumber,
     MemberId<T>,
     types::BalanceOf<T>,
@@ -90,6 +90,7 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 impl crate::Trait for Test {
@@ -1602,3 +1602,73 @@ fn slash_balance_is_calculated_correctly() {
         assert_eq!(slash_balance_with_stake, stake);
     });
 }
+
+#[test]
+fn projected_decision_status_and_period_end_for_active_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        assert_eq!(
+            ProposalsEngine::projected_decision_status(proposal_id),
+            None
+        );
+        assert_eq!(ProposalsEngine::period_ends_at(proposal_id), Some(3));
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        assert_eq!(
+            ProposalsEngine::projected_decision_status(proposal_id),
+            Some(ProposalDecisionStatus::Approved(
+                ApprovedProposalStatus::PendingExecution
+            ))
+        );
+        assert_eq!(ProposalsEngine::period_ends_at(proposal_id), Some(3));
+    });
+}
+
+#[test]
+fn projected_decision_status_and_period_end_for_finalized_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default().with_grace_period(3);
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        assert_eq!(
+            ProposalsEngine::projected_decision_status(proposal_id),
+            Some(ProposalDecisionStatus::Approved(
+                ApprovedProposalStatus::PendingExecution
+            ))
+        );
+        assert_eq!(ProposalsEngine::period_ends_at(proposal_id), Some(3));
+
+        VetoProposalFixture::new(proposal_id).veto_and_assert(Ok(()));
+
+        assert_eq!(
+            ProposalsEngine::projected_decision_status(proposal_id),
+            Some(ProposalDecisionStatus::Vetoed)
+        );
+        assert_eq!(ProposalsEngine::period_ends_at(proposal_id), None);
+    });
+}
+
+#[test]
+fn projected_decision_status_and_period_end_for_absent_proposal_are_none() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(ProposalsEngine::projected_decision_status(1), None);
+        assert_eq!(ProposalsEngine::period_ends_at(1), None);
+    });
+}
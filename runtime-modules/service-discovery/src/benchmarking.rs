@@ -0,0 +1,176 @@
+//! Service discovery module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{StorageMap, StorageValue};
+use sp_std::prelude::*;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+
+// CIDv1 form of the IPNS identity: the longest decoding path of the identity normalization.
+const IPNS_IDENTITY: &[u8] = b"bafzbeie5745rpv2m6tjyuugywy4d5ewrqgqqhfnf445he3omzpjbx5xqxe";
+
+/// Hires a new storage provider and returns its id and role account.
+fn insert_storage_provider<T: Trait>() -> (StorageProviderId<T>, T::AccountId) {
+    let storage_provider_id = working_group::NextWorkerId::<T, StorageWorkingGroupInstance>::get();
+    let role_account_id: T::AccountId = account("storage_provider", 0, SEED);
+
+    working_group::WorkerById::<T, StorageWorkingGroupInstance>::insert(
+        storage_provider_id,
+        working_group::Worker::new(&Default::default(), &role_account_id, &None, &None),
+    );
+    working_group::NextWorkerId::<T, StorageWorkingGroupInstance>::mutate(|id| *id += One::one());
+
+    (storage_provider_id, role_account_id)
+}
+
+/// Service endpoints with the longest urls.
+fn max_service_endpoints(count: u32) -> Vec<ServiceEndpoint> {
+    (0..count)
+        .map(|_| ServiceEndpoint {
+            protocol: EndpointProtocol::Http,
+            url: vec![b'u'; MAX_SERVICE_URL_LENGTH],
+        })
+        .collect()
+}
+
+fn set_storage_node_record<T: Trait>(
+    storage_provider_id: StorageProviderId<T>,
+    account_id: T::AccountId,
+    endpoints_count: u32,
+) {
+    Module::<T>::set_service_record(
+        RawOrigin::Signed(account_id).into(),
+        WorkingGroup::Storage,
+        storage_provider_id,
+        ServiceKind::StorageNode,
+        max_service_endpoints(endpoints_count),
+        1,
+        vec![b'r'; MAX_SERVICE_REGION_LENGTH],
+    )
+    .expect("storage provider can set its service record");
+}
+
+benchmarks! {
+    _ { }
+
+    set_ipns_id {
+        let (storage_provider_id, account_id) = insert_storage_provider::<T>();
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, IPNS_IDENTITY.to_vec())
+    verify {
+        assert!(<AccountInfoByStorageProviderId<T>>::contains_key(storage_provider_id));
+    }
+
+    unset_ipns_id {
+        let (storage_provider_id, account_id) = insert_storage_provider::<T>();
+        Module::<T>::set_ipns_id(
+            RawOrigin::Signed(account_id.clone()).into(),
+            storage_provider_id,
+            IPNS_IDENTITY.to_vec(),
+        )
+        .expect("storage provider can set its IPNS identity");
+    }: _(RawOrigin::Signed(account_id), storage_provider_id)
+    verify {
+        assert!(!<AccountInfoByStorageProviderId<T>>::contains_key(storage_provider_id));
+    }
+
+    set_service_record {
+        let i in 1 .. MAX_SERVICE_ENDPOINTS as u32;
+        let (storage_provider_id, account_id) = insert_storage_provider::<T>();
+    }: _(
+        RawOrigin::Signed(account_id),
+        WorkingGroup::Storage,
+        storage_provider_id,
+        ServiceKind::StorageNode,
+        max_service_endpoints(i),
+        1,
+        vec![b'r'; MAX_SERVICE_REGION_LENGTH]
+    )
+    verify {
+        let key = (WorkingGroup::Storage, storage_provider_id, ServiceKind::StorageNode);
+        assert_eq!(Module::<T>::service_record(key).unwrap().endpoints.len(), i as usize);
+    }
+
+    unset_service_record {
+        let (storage_provider_id, account_id) = insert_storage_provider::<T>();
+        set_storage_node_record::<T>(
+            storage_provider_id,
+            account_id.clone(),
+            MAX_SERVICE_ENDPOINTS as u32,
+        );
+    }: _(
+        RawOrigin::Signed(account_id),
+        WorkingGroup::Storage,
+        storage_provider_id,
+        ServiceKind::StorageNode
+    )
+    verify {
+        let key = (WorkingGroup::Storage, storage_provider_id, ServiceKind::StorageNode);
+        assert!(Module::<T>::service_record(key).is_none());
+    }
+
+    set_default_lifetime {
+        let lifetime = T::BlockNumber::from(DEFAULT_LIFETIME);
+    }: _(RawOrigin::Root, lifetime)
+    verify {
+        assert_eq!(Module::<T>::default_lifetime(), lifetime);
+    }
+
+    set_bootstrap_endpoints {
+        let i in 1 .. 10_000;
+        let endpoints = vec![vec![b'u'; i as usize]];
+    }: _(RawOrigin::Root, endpoints.clone())
+    verify {
+        assert_eq!(Module::<T>::bootstrap_endpoints(), endpoints);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{initial_test_ext, Test};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn test_set_ipns_id() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_set_ipns_id::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_unset_ipns_id() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_unset_ipns_id::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_set_service_record() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_set_service_record::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_unset_service_record() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_unset_service_record::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_set_default_lifetime() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_set_default_lifetime::<Test>());
+        });
+    }
+
+    #[test]
+    fn test_set_bootstrap_endpoints() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_set_bootstrap_endpoints::<Test>());
+        });
+    }
+}
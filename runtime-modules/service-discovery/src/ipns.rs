@@ -0,0 +1,167 @@
+//! IPNS identity validation. Supports the peer ids in the legacy base58btc multihash form
+//! (e.g. 'Qm...', '12D3KooW...') and in the CIDv1 form with the 'libp2p-key' codec
+//! (base32 'b...' and base58btc 'z...' multibase encodings).
+//! https://github.com/libp2p/specs/blob/master/peer-ids/peer-ids.md
+
+use crate::IPNSIdentity;
+use sp_std::iter;
+use sp_std::vec::Vec;
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+const MULTIBASE_BASE32_PREFIX: u8 = b'b';
+const MULTIBASE_BASE58BTC_PREFIX: u8 = b'z';
+
+const CID_VERSION_1: u64 = 1;
+const LIBP2P_KEY_CODEC: u64 = 0x72;
+
+const IDENTITY_MULTIHASH_CODE: u64 = 0x00;
+const SHA2_256_MULTIHASH_CODE: u64 = 0x12;
+const SHA2_256_DIGEST_LENGTH: u64 = 32;
+// Public keys up to 42 bytes are inlined into the peer id with the identity multihash.
+const MAX_INLINED_KEY_LENGTH: u64 = 42;
+
+/// Maximum length of the encoded IPNS identity.
+pub const MAX_IPNS_IDENTITY_LENGTH: usize = 128;
+
+/// Validates the encoded IPNS identity and returns its canonical form: base58btc encoded
+/// multihash of the peer id. Returns None for the malformed identities.
+pub(crate) fn normalize_ipns_identity(id: &[u8]) -> Option<IPNSIdentity> {
+    if id.is_empty() || id.len() > MAX_IPNS_IDENTITY_LENGTH {
+        return None;
+    }
+
+    let multihash = match id[0] {
+        MULTIBASE_BASE32_PREFIX => decode_cid_v1(&decode_base32(&id[1..])?)?,
+        MULTIBASE_BASE58BTC_PREFIX => decode_cid_v1(&decode_base58(&id[1..])?)?,
+        _ => decode_base58(id)?,
+    };
+
+    if !is_valid_peer_id_multihash(&multihash) {
+        return None;
+    }
+
+    Some(encode_base58(&multihash))
+}
+
+// Returns the multihash from the CIDv1 with the 'libp2p-key' codec.
+fn decode_cid_v1(cid: &[u8]) -> Option<Vec<u8>> {
+    let (version, rest) = decode_varint(cid)?;
+    let (codec, multihash) = decode_varint(rest)?;
+
+    if version != CID_VERSION_1 || codec != LIBP2P_KEY_CODEC {
+        return None;
+    }
+
+    Some(multihash.to_vec())
+}
+
+fn is_valid_peer_id_multihash(multihash: &[u8]) -> bool {
+    let decoded = decode_varint(multihash).and_then(|(code, rest)| {
+        decode_varint(rest).map(|(length, digest)| (code, length, digest))
+    });
+
+    match decoded {
+        Some((code, length, digest)) if digest.len() as u64 == length => match code {
+            IDENTITY_MULTIHASH_CODE => length > 0 && length <= MAX_INLINED_KEY_LENGTH,
+            SHA2_256_MULTIHASH_CODE => length == SHA2_256_DIGEST_LENGTH,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+// Decodes unsigned varint, returns the value and the remaining bytes.
+fn decode_varint(input: &[u8]) -> Option<(u64, &[u8])> {
+    let mut value = 0u64;
+
+    // Multiformats restrict varints to 9 bytes.
+    for (index, byte) in input.iter().enumerate().take(9) {
+        value |= u64::from(byte & 0x7f) << (7 * index);
+
+        if byte & 0x80 == 0 {
+            return Some((value, &input[index + 1..]));
+        }
+    }
+
+    None
+}
+
+fn decode_base58(input: &[u8]) -> Option<Vec<u8>> {
+    // Little-endian bytes of the decoded number.
+    let mut bytes: Vec<u8> = Vec::new();
+
+    for symbol in input {
+        let mut carry = BASE58_ALPHABET.iter().position(|s| s == symbol)? as u32;
+
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let leading_zeros = input
+        .iter()
+        .take_while(|s| **s == BASE58_ALPHABET[0])
+        .count();
+    bytes.extend(iter::repeat(0).take(leading_zeros));
+    bytes.reverse();
+
+    Some(bytes)
+}
+
+fn encode_base58(input: &[u8]) -> Vec<u8> {
+    // Little-endian base58 digits of the encoded number.
+    let mut digits: Vec<u8> = Vec::new();
+
+    for byte in input {
+        let mut carry = u32::from(*byte);
+
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let leading_zeros = input.iter().take_while(|b| **b == 0).count();
+
+    iter::repeat(BASE58_ALPHABET[0])
+        .take(leading_zeros)
+        .chain(digits.iter().rev().map(|d| BASE58_ALPHABET[*d as usize]))
+        .collect()
+}
+
+// Decodes lowercase RFC4648 base32 without padding.
+fn decode_base32(input: &[u8]) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer = 0u32;
+    let mut bits = 0u32;
+
+    for symbol in input {
+        let value = BASE32_ALPHABET.iter().position(|s| s == symbol)? as u32;
+
+        buffer = (buffer << 5) | value;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(bytes)
+}
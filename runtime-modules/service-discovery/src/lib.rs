@@ -1,7 +1,10 @@
 //! # Service discovery module
 //! Service discovery module for the Joystream platform supports the storage providers.
 //! It registers their 'pings' in the system with the expiration time, and stores the bootstrap
-//! nodes for the Colossus.
+//! nodes for the Colossus. It also registers the typed service endpoints (storage nodes, caching
+//! gateways, query nodes) of the working group workers with the same expiration semantics.
+//! Expired 'pings' and service records are pruned at the beginning of the block (bounded number
+//! per block), and the records of the workers leaving their working group are removed immediately.
 //!
 //! ## Comments
 //!
@@ -10,15 +13,20 @@
 //!
 //! ## Supported extrinsics
 //!
-//! - [set_ipns_id](./struct.Module.html#method.set_ipns_id) - Creates the ServiceProviderRecord to save an IPNS identity for the storage provider.
+//! - [set_ipns_id](./struct.Module.html#method.set_ipns_id) - Creates the ServiceProviderRecord to save a validated IPNS identity for the storage provider.
 //! - [unset_ipns_id](./struct.Module.html#method.unset_ipns_id) - Deletes the ServiceProviderRecord with the IPNS identity for the storage provider.
 //! - [set_default_lifetime](./struct.Module.html#method.set_default_lifetime) - Sets default lifetime for storage providers accounts info.
 //! - [set_bootstrap_endpoints](./struct.Module.html#method.set_bootstrap_endpoints) - Sets bootstrap endpoints for the Colossus.
+//! - [set_service_record](./struct.Module.html#method.set_service_record) - Creates the ServiceRecord with the service endpoints of the working group worker.
+//! - [unset_service_record](./struct.Module.html#method.unset_service_record) - Deletes the ServiceRecord of the working group worker.
 //!
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod ipns;
 mod mock;
 mod tests;
 
@@ -26,7 +34,14 @@ use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
-use frame_support::{decl_event, decl_module, decl_storage, ensure};
+use common::working_group::WorkingGroup;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, ensure, IterableStorageMap,
+};
+use sp_runtime::traits::{One, Saturating};
+use sp_runtime::DispatchResult;
 use sp_std::vec::Vec;
 use system::ensure_root;
 /*
@@ -45,6 +60,8 @@ use system::ensure_root;
 /// base58 encoded IPNS identity multihash codec
 pub type IPNSIdentity = Vec<u8>;
 
+pub use ipns::MAX_IPNS_IDENTITY_LENGTH;
+
 /// HTTP Url string to a discovery service endpoint
 pub type Url = Vec<u8>;
 
@@ -57,6 +74,18 @@ pub(crate) type StorageWorkingGroup<T> = working_group::Module<T, StorageWorking
 /// Storage provider is a worker from the  working_group module.
 pub type StorageProviderId<T> = working_group::WorkerId<T>;
 
+/// Worker of any working group.
+pub type WorkerId<T> = working_group::WorkerId<T>;
+
+/// Maximum number of the endpoints in the service record.
+pub const MAX_SERVICE_ENDPOINTS: usize = 10;
+
+/// Maximum length of the service endpoint url.
+pub const MAX_SERVICE_URL_LENGTH: usize = 256;
+
+/// Maximum length of the service region tag.
+pub const MAX_SERVICE_REGION_LENGTH: usize = 32;
+
 pub(crate) const MINIMUM_LIFETIME: u32 = 600; // 1hr assuming 6s block times
 pub(crate) const DEFAULT_LIFETIME: u32 = MINIMUM_LIFETIME * 24; // 24hr
 
@@ -70,10 +99,122 @@ pub struct ServiceProviderRecord<BlockNumber> {
     pub expires_at: BlockNumber,
 }
 
+/// Kind of the service provided by the working group worker.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Copy, Debug)]
+pub enum ServiceKind {
+    /// Storage node (Colossus).
+    StorageNode,
+    /// Caching gateway (distributor) node.
+    Gateway,
+    /// Query node.
+    QueryNode,
+}
+
+impl ServiceKind {
+    /// All the service kinds.
+    pub const ALL: [ServiceKind; 3] = [
+        ServiceKind::StorageNode,
+        ServiceKind::Gateway,
+        ServiceKind::QueryNode,
+    ];
+}
+
+/// Protocol of the service endpoint.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Copy, Debug)]
+pub enum EndpointProtocol {
+    /// HTTP(S) endpoint.
+    Http,
+    /// WebSocket endpoint.
+    Ws,
+}
+
+/// Typed endpoint of the service.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct ServiceEndpoint {
+    /// Endpoint protocol.
+    pub protocol: EndpointProtocol,
+    /// Endpoint url.
+    pub url: Url,
+}
+
+/// Defines the service endpoints of the working group worker and their expiration date.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct ServiceRecord<BlockNumber> {
+    /// Service endpoints.
+    pub endpoints: Vec<ServiceEndpoint>,
+    /// Version of the protocol supported by the service.
+    pub protocol_version: u32,
+    /// Region tag of the service (e.g.: 'eu-west').
+    pub region: Vec<u8>,
+    /// Block at which information expires.
+    pub expires_at: BlockNumber,
+}
+
+/// Key of the ServiceRecord: working group of the worker, id of the worker and kind of the service.
+pub type ServiceRecordKey<T> = (WorkingGroup, WorkerId<T>, ServiceKind);
+
+/// Validates the worker origin for the working groups.
+pub trait WorkerOriginValidator<T: Trait> {
+    /// Ensures the origin is signed by the role account of the worker from the working group.
+    fn ensure_worker_origin(
+        origin: T::Origin,
+        working_group: WorkingGroup,
+        worker_id: &WorkerId<T>,
+    ) -> DispatchResult;
+}
+
 /// The _Service discovery_ main _Trait_.
 pub trait Trait: system::Trait + working_group::Trait<StorageWorkingGroupInstance> {
     /// _Service discovery_ event type.
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// Validates the worker origin for the service records.
+    type WorkerOriginValidator: WorkerOriginValidator<Self>;
+
+    /// Maximum number of the expired account info records pruned per block.
+    type MaxAccountInfosPrunedPerBlock: Get<u32>;
+
+    /// Maximum number of the expired service records pruned per block.
+    type MaxServiceRecordsPrunedPerBlock: Get<u32>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+}
+
+/// Weight functions needed for the service discovery module.
+pub trait WeightInfo {
+    fn set_ipns_id() -> Weight;
+    fn unset_ipns_id() -> Weight;
+    fn set_service_record(i: u32) -> Weight;
+    fn unset_service_record() -> Weight;
+    fn set_default_lifetime() -> Weight;
+    fn set_bootstrap_endpoints(i: u32) -> Weight;
+}
+
+/// Flat weights for the test runtimes.
+impl WeightInfo for () {
+    fn set_ipns_id() -> Weight {
+        10_000_000
+    }
+    fn unset_ipns_id() -> Weight {
+        10_000_000
+    }
+    fn set_service_record(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn unset_service_record() -> Weight {
+        10_000_000
+    }
+    fn set_default_lifetime() -> Weight {
+        10_000_000
+    }
+    fn set_bootstrap_endpoints(_i: u32) -> Weight {
+        10_000_000
+    }
 }
 
 decl_storage! {
@@ -85,6 +226,27 @@ decl_storage! {
         pub AccountInfoByStorageProviderId get(fn account_info_by_storage_provider_id):
             map hasher(blake2_128_concat) StorageProviderId<T> => ServiceProviderRecord<T::BlockNumber>;
 
+        /// Storage provider ids by the expiration block of their ServiceProviderRecord.
+        /// Can contain outdated entries for the refreshed records.
+        pub AccountInfoIdsByExpiration get(fn account_info_ids_by_expiration):
+            map hasher(twox_64_concat) T::BlockNumber => Vec<StorageProviderId<T>>;
+
+        /// Next expiration block of the ServiceProviderRecords to be pruned.
+        pub AccountInfoPruningCursor get(fn account_info_pruning_cursor): T::BlockNumber;
+
+        /// Mapping of the working group workers' services to their ServiceRecord
+        pub ServiceRecords get(fn service_record):
+            map hasher(blake2_128_concat) ServiceRecordKey<T>
+                => Option<ServiceRecord<T::BlockNumber>>;
+
+        /// ServiceRecord keys by the expiration block of the record.
+        /// Can contain outdated entries for the refreshed and removed records.
+        pub ServiceRecordKeysByExpiration get(fn service_record_keys_by_expiration):
+            map hasher(twox_64_concat) T::BlockNumber => Vec<ServiceRecordKey<T>>;
+
+        /// Next expiration block of the ServiceRecords to be pruned.
+        pub ServiceRecordPruningCursor get(fn service_record_pruning_cursor): T::BlockNumber;
+
         /// Lifetime of an ServiceProviderRecord record in AccountInfoByAccountId map
         pub DefaultLifetime get(fn default_lifetime) config():
             T::BlockNumber = T::BlockNumber::from(DEFAULT_LIFETIME);
@@ -94,7 +256,8 @@ decl_storage! {
 decl_event! {
     /// _Service discovery_ events
     pub enum Event<T> where
-        StorageProviderId = StorageProviderId<T>
+        StorageProviderId = StorageProviderId<T>,
+        WorkerId = WorkerId<T>
        {
         /// Emits on updating of the account info.
         /// Params:
@@ -106,6 +269,28 @@ decl_event! {
         /// Params:
         /// - Id of the storage provider.
         AccountInfoRemoved(StorageProviderId),
+
+        /// Emits on updating of the service record.
+        /// Params:
+        /// - Working group of the worker.
+        /// - Id of the worker.
+        /// - Kind of the service.
+        ServiceRecordUpdated(WorkingGroup, WorkerId, ServiceKind),
+
+        /// Emits on removing of the service record.
+        /// Params:
+        /// - Working group of the worker.
+        /// - Id of the worker.
+        /// - Kind of the service.
+        ServiceRecordRemoved(WorkingGroup, WorkerId, ServiceKind),
+    }
+}
+
+decl_error! {
+    /// _Service discovery_ module predefined errors
+    pub enum Error for Module<T: Trait> {
+        /// IPNS identity is not a valid base58 multihash or CIDv1 encoded peer id.
+        InvalidIpnsIdentity,
     }
 }
 
@@ -115,9 +300,19 @@ decl_module! {
         /// Default deposit_event() handler
         fn deposit_event() = default;
 
+        /// Predefined errors.
+        type Error = Error<T>;
+
+        /// Prunes the expired ServiceProviderRecords and ServiceRecords.
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::prune_expired_account_infos(now)
+                .saturating_add(Self::prune_expired_service_records(now))
+        }
+
         /// Creates the ServiceProviderRecord to save an IPNS identity for the storage provider.
-        /// Requires signed storage provider credentials.
-        #[weight = 10_000_000] // TODO: adjust weight
+        /// The identity is accepted in the base58 multihash or CIDv1 form and stored
+        /// as the base58 multihash. Requires signed storage provider credentials.
+        #[weight = T::WeightInfo::set_ipns_id()]
         pub fn set_ipns_id(
             origin,
             storage_provider_id: StorageProviderId<T>,
@@ -125,38 +320,98 @@ decl_module! {
         ) {
             <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
 
-            // TODO: ensure id is a valid base58 encoded IPNS identity
+            let id = ipns::normalize_ipns_identity(&id).ok_or(Error::<T>::InvalidIpnsIdentity)?;
 
             //
             // == MUTATION SAFE ==
             //
 
+            let expires_at = <system::Module<T>>::block_number() + Self::default_lifetime();
+
             <AccountInfoByStorageProviderId<T>>::insert(storage_provider_id, ServiceProviderRecord {
                 identity: id.clone(),
-                expires_at: <system::Module<T>>::block_number() + Self::default_lifetime(),
+                expires_at,
             });
+            <AccountInfoIdsByExpiration<T>>::mutate(expires_at, |ids| ids.push(storage_provider_id));
 
             Self::deposit_event(RawEvent::AccountInfoUpdated(storage_provider_id, id));
         }
 
         /// Deletes the ServiceProviderRecord with the IPNS identity for the storage provider.
         /// Requires signed storage provider credentials.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::unset_ipns_id()]
         pub fn unset_ipns_id(origin, storage_provider_id: StorageProviderId<T>) {
             <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
 
             // == MUTATION SAFE ==
 
-            if <AccountInfoByStorageProviderId<T>>::contains_key(storage_provider_id) {
-                <AccountInfoByStorageProviderId<T>>::remove(storage_provider_id);
-                Self::deposit_event(RawEvent::AccountInfoRemoved(storage_provider_id));
-            }
+            Self::remove_account_info(&storage_provider_id);
+        }
+
+        /// Creates the ServiceRecord with the service endpoints of the working group worker.
+        /// Requires signed worker credentials.
+        #[weight = T::WeightInfo::set_service_record(endpoints.len() as u32)]
+        pub fn set_service_record(
+            origin,
+            working_group: WorkingGroup,
+            worker_id: WorkerId<T>,
+            service_kind: ServiceKind,
+            endpoints: Vec<ServiceEndpoint>,
+            protocol_version: u32,
+            region: Vec<u8>,
+        ) {
+            T::WorkerOriginValidator::ensure_worker_origin(origin, working_group, &worker_id)?;
+
+            ensure!(!endpoints.is_empty(), "discovery: service endpoints cannot be empty");
+            ensure!(endpoints.len() <= MAX_SERVICE_ENDPOINTS,
+                "discovery: too many service endpoints");
+            ensure!(
+                endpoints.iter().all(|endpoint|
+                    !endpoint.url.is_empty() && endpoint.url.len() <= MAX_SERVICE_URL_LENGTH
+                ),
+                "discovery: invalid service endpoint url length"
+            );
+            ensure!(region.len() <= MAX_SERVICE_REGION_LENGTH,
+                "discovery: service region tag is too long");
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let key = (working_group, worker_id, service_kind);
+            let expires_at = <system::Module<T>>::block_number() + Self::default_lifetime();
+
+            <ServiceRecords<T>>::insert(key, ServiceRecord {
+                endpoints,
+                protocol_version,
+                region,
+                expires_at,
+            });
+            <ServiceRecordKeysByExpiration<T>>::mutate(expires_at, |keys| keys.push(key));
+
+            Self::deposit_event(RawEvent::ServiceRecordUpdated(working_group, worker_id, service_kind));
+        }
+
+        /// Deletes the ServiceRecord of the working group worker.
+        /// Requires signed worker credentials.
+        #[weight = T::WeightInfo::unset_service_record()]
+        pub fn unset_service_record(
+            origin,
+            working_group: WorkingGroup,
+            worker_id: WorkerId<T>,
+            service_kind: ServiceKind,
+        ) {
+            T::WorkerOriginValidator::ensure_worker_origin(origin, working_group, &worker_id)?;
+
+            // == MUTATION SAFE ==
+
+            Self::remove_service_record(working_group, worker_id, service_kind);
         }
 
         // Privileged methods
 
         /// Sets default lifetime for storage providers accounts info. Requires root privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_default_lifetime()]
         pub fn set_default_lifetime(origin, lifetime: T::BlockNumber) {
             ensure_root(origin)?;
             ensure!(lifetime >= T::BlockNumber::from(MINIMUM_LIFETIME),
@@ -168,7 +423,9 @@ decl_module! {
         }
 
         /// Sets bootstrap endpoints for the Colossus. Requires root privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_bootstrap_endpoints(
+            endpoints.iter().map(|endpoint| endpoint.len() as u32).sum()
+        )]
         pub fn set_bootstrap_endpoints(origin, endpoints: Vec<Url>) {
             ensure_root(origin)?;
 
@@ -186,4 +443,174 @@ impl<T: Trait> Module<T> {
             || <system::Module<T>>::block_number()
                 > <AccountInfoByStorageProviderId<T>>::get(storage_provider_id).expires_at
     }
+
+    /// Verifies that the service record of the working group worker is still valid.
+    pub fn is_service_record_expired(
+        working_group: WorkingGroup,
+        worker_id: &WorkerId<T>,
+        service_kind: ServiceKind,
+    ) -> bool {
+        Self::service_record((working_group, *worker_id, service_kind)).map_or(true, |record| {
+            <system::Module<T>>::block_number() > record.expires_at
+        })
+    }
+
+    /// Removes the ServiceProviderRecord and the service records of the worker.
+    pub fn remove_worker_records(working_group: WorkingGroup, worker_id: &WorkerId<T>) {
+        if working_group == WorkingGroup::Storage {
+            Self::remove_account_info(worker_id);
+        }
+
+        for service_kind in ServiceKind::ALL.iter() {
+            Self::remove_service_record(working_group, *worker_id, *service_kind);
+        }
+    }
+
+    /// Indexes the existing ServiceProviderRecords by their expiration blocks and starts the
+    /// pruning of the ServiceProviderRecords and the ServiceRecords from the current block.
+    /// Runs once: the pruning cursor is stored by every block since then.
+    /// Returns the weight of the migration.
+    pub fn migrate_account_info_expirations() -> Weight {
+        if <AccountInfoPruningCursor<T>>::exists() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let now = <system::Module<T>>::block_number();
+        let mut records_count: Weight = 0;
+
+        for (storage_provider_id, record) in <AccountInfoByStorageProviderId<T>>::iter() {
+            // Already expired records are pruned with the current block ones.
+            let expires_at = record.expires_at.max(now);
+            <AccountInfoIdsByExpiration<T>>::mutate(expires_at, |ids| {
+                ids.push(storage_provider_id)
+            });
+
+            records_count += 1;
+        }
+
+        <AccountInfoPruningCursor<T>>::put(now);
+        <ServiceRecordPruningCursor<T>>::put(now);
+
+        T::DbWeight::get().reads_writes(1 + records_count.saturating_mul(2), records_count + 2)
+    }
+
+    // Removes the expired ServiceProviderRecords with the expiration blocks before the current
+    // one, up to the MaxAccountInfosPrunedPerBlock index entries (or empty blocks) per block.
+    fn prune_expired_account_infos(now: T::BlockNumber) -> Weight {
+        let mut pruning_budget = T::MaxAccountInfosPrunedPerBlock::get() as usize;
+        let mut cursor = Self::account_info_pruning_cursor();
+        let mut reads: Weight = 1;
+        let mut writes: Weight = 0;
+
+        while pruning_budget > 0 && cursor < now {
+            let mut ids = Self::account_info_ids_by_expiration(cursor);
+            reads += 1;
+
+            let pruned_ids: Vec<StorageProviderId<T>> =
+                ids.drain(..pruning_budget.min(ids.len())).collect();
+            pruning_budget = pruning_budget.saturating_sub(pruned_ids.len().max(1));
+
+            for storage_provider_id in pruned_ids {
+                reads += 1;
+
+                // The record could be refreshed after the indexing.
+                let expired =
+                    <AccountInfoByStorageProviderId<T>>::contains_key(storage_provider_id)
+                        && Self::account_info_by_storage_provider_id(storage_provider_id)
+                            .expires_at
+                            <= cursor;
+
+                if expired {
+                    Self::remove_account_info(&storage_provider_id);
+                    writes += 1;
+                }
+            }
+
+            writes += 1;
+            if ids.is_empty() {
+                <AccountInfoIdsByExpiration<T>>::remove(cursor);
+                cursor = cursor.saturating_add(One::one());
+            } else {
+                <AccountInfoIdsByExpiration<T>>::insert(cursor, ids);
+            }
+        }
+
+        <AccountInfoPruningCursor<T>>::put(cursor);
+        writes += 1;
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    // Removes the expired ServiceRecords with the expiration blocks before the current one, up to
+    // the MaxServiceRecordsPrunedPerBlock index entries (or empty blocks) per block.
+    fn prune_expired_service_records(now: T::BlockNumber) -> Weight {
+        let mut pruning_budget = T::MaxServiceRecordsPrunedPerBlock::get() as usize;
+        let mut cursor = Self::service_record_pruning_cursor();
+        let mut reads: Weight = 1;
+        let mut writes: Weight = 0;
+
+        while pruning_budget > 0 && cursor < now {
+            let mut keys = Self::service_record_keys_by_expiration(cursor);
+            reads += 1;
+
+            let pruned_keys: Vec<ServiceRecordKey<T>> =
+                keys.drain(..pruning_budget.min(keys.len())).collect();
+            pruning_budget = pruning_budget.saturating_sub(pruned_keys.len().max(1));
+
+            for (working_group, worker_id, service_kind) in pruned_keys {
+                reads += 1;
+
+                // The record could be refreshed after the indexing.
+                let expired = Self::service_record((working_group, worker_id, service_kind))
+                    .map_or(false, |record| record.expires_at <= cursor);
+
+                if expired {
+                    Self::remove_service_record(working_group, worker_id, service_kind);
+                    writes += 1;
+                }
+            }
+
+            writes += 1;
+            if keys.is_empty() {
+                <ServiceRecordKeysByExpiration<T>>::remove(cursor);
+                cursor = cursor.saturating_add(One::one());
+            } else {
+                <ServiceRecordKeysByExpiration<T>>::insert(cursor, keys);
+            }
+        }
+
+        <ServiceRecordPruningCursor<T>>::put(cursor);
+        writes += 1;
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    fn remove_account_info(storage_provider_id: &StorageProviderId<T>) {
+        if <AccountInfoByStorageProviderId<T>>::contains_key(storage_provider_id) {
+            <AccountInfoByStorageProviderId<T>>::remove(storage_provider_id);
+            Self::deposit_event(RawEvent::AccountInfoRemoved(*storage_provider_id));
+        }
+    }
+
+    fn remove_service_record(
+        working_group: WorkingGroup,
+        worker_id: WorkerId<T>,
+        service_kind: ServiceKind,
+    ) {
+        let key = (working_group, worker_id, service_kind);
+        if <ServiceRecords<T>>::contains_key(&key) {
+            <ServiceRecords<T>>::remove(&key);
+            Self::deposit_event(RawEvent::ServiceRecordRemoved(
+                working_group,
+                worker_id,
+                service_kind,
+            ));
+        }
+    }
+}
+
+impl<T: Trait> working_group::WorkerExitHandler<T, StorageWorkingGroupInstance> for Module<T> {
+    fn worker_exited(worker_id: &WorkerId<T>, _worker: &working_group::WorkerOf<T>) {
+        Self::remove_worker_records(WorkingGroup::Storage, worker_id);
+    }
 }
@@ -81,8 +81,35 @@ impl system::Trait for Test {
     type OnKilledAccount = ();
 }
 
+parameter_types! {
+    pub const MaxAccountInfosPrunedPerBlock: u32 = 2;
+    pub const MaxServiceRecordsPrunedPerBlock: u32 = 2;
+}
+
 impl Trait for Test {
     type Event = MetaEvent;
+    type WorkerOriginValidator = StorageWorkerOnlyOriginValidator;
+    type MaxAccountInfosPrunedPerBlock = MaxAccountInfosPrunedPerBlock;
+    type MaxServiceRecordsPrunedPerBlock = MaxServiceRecordsPrunedPerBlock;
+    type WeightInfo = ();
+}
+
+// Authorizes the storage working group workers only.
+pub struct StorageWorkerOnlyOriginValidator;
+
+impl WorkerOriginValidator<Test> for StorageWorkerOnlyOriginValidator {
+    fn ensure_worker_origin(
+        origin: Origin,
+        working_group: WorkingGroup,
+        worker_id: &u64,
+    ) -> DispatchResult {
+        match working_group {
+            WorkingGroup::Storage => {
+                <StorageWorkingGroup<Test>>::ensure_worker_signed(origin, worker_id).map(|_| ())
+            }
+            _ => Err("Unsupported working group".into()),
+        }
+    }
 }
 
 impl hiring::Trait for Test {
@@ -111,6 +138,7 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 impl common::currency::GovernanceCurrency for Test {
@@ -138,6 +166,9 @@ parameter_types! {
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
+    type WorkerExitHandler = Discovery;
+    type WorkerRoleAccountUpdateHandler = ();
 }
 
 impl pallet_timestamp::Trait for Test {
@@ -159,8 +190,11 @@ pub type System = system::Module<Test>;
 pub type Discovery = Module<Test>;
 
 pub(crate) fn hire_storage_provider() -> (u64, u64) {
-    let storage_provider_id = 1;
-    let role_account_id = 1;
+    hire_storage_provider_with_id(1)
+}
+
+pub(crate) fn hire_storage_provider_with_id(storage_provider_id: u64) -> (u64, u64) {
+    let role_account_id = storage_provider_id;
 
     let storage_provider = working_group::Worker {
         member_id: 1,
@@ -2,8 +2,11 @@
 
 use super::mock::*;
 
+use frame_support::traits::OnInitialize;
 use system::{EventRecord, Phase, RawOrigin};
 
+const TEST_IPNS_IDENTITY: &[u8] = b"QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N";
+
 #[test]
 fn set_ipns_id() {
     initial_test_ext().execute_with(|| {
@@ -12,7 +15,7 @@ fn set_ipns_id() {
 
         let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
 
-        let identity = "alice".as_bytes().to_vec();
+        let identity = TEST_IPNS_IDENTITY.to_vec();
         let ttl = <Test as system::Trait>::BlockNumber::from(DEFAULT_LIFETIME);
         assert!(Discovery::set_ipns_id(
             Origin::signed(storage_provider_account_id),
@@ -60,6 +63,94 @@ fn set_ipns_id() {
     });
 }
 
+#[test]
+fn set_ipns_id_normalizes_identity() {
+    initial_test_ext().execute_with(|| {
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let identities: [(&[u8], &[u8]); 4] = [
+            // CIDv1 base32 form
+            (
+                b"bafzbeie5745rpv2m6tjyuugywy4d5ewrqgqqhfnf445he3omzpjbx5xqxe",
+                TEST_IPNS_IDENTITY,
+            ),
+            // CIDv1 base58btc form
+            (
+                b"zdvgqC3jczfCwLUoSyWT8GLc5UZ9aG4RkAg7XAfidRbX9qVj6",
+                TEST_IPNS_IDENTITY,
+            ),
+            // Inlined ed25519 public key
+            (
+                b"12D3KooWD3eckifWpRn9wQpMG9R9hX3sD158z7EqHWmweQAJU5SA",
+                b"12D3KooWD3eckifWpRn9wQpMG9R9hX3sD158z7EqHWmweQAJU5SA",
+            ),
+            (
+                b"bafzaajaiaejcal72gwuz2or47oyxxn6b3rkwdmmkrxgkjxzy3rqt5kczyn7lcm3l",
+                b"12D3KooWD3eckifWpRn9wQpMG9R9hX3sD158z7EqHWmweQAJU5SA",
+            ),
+        ];
+
+        for (identity, canonical_identity) in identities.iter() {
+            assert!(Discovery::set_ipns_id(
+                Origin::signed(storage_provider_account_id),
+                storage_provider_id,
+                identity.to_vec(),
+            )
+            .is_ok());
+
+            assert_eq!(
+                Discovery::account_info_by_storage_provider_id(&storage_provider_id).identity,
+                canonical_identity.to_vec()
+            );
+        }
+    });
+}
+
+#[test]
+fn set_ipns_id_fails_with_invalid_identity() {
+    initial_test_ext().execute_with(|| {
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let invalid_identities: [&[u8]; 5] = [
+            // Empty
+            b"",
+            // Not base58
+            b"alice",
+            // Truncated multihash
+            b"QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5",
+            // Typo in the multibase encoding
+            b"bafzbeie5745rpv2m6tjyuugywy4d5ewrqgqqhfnf445he3omzpjbx5xqx1",
+            // Unsupported CID codec (dag-pb)
+            b"bafybeie5745rpv2m6tjyuugywy4d5ewrqgqqhfnf445he3omzpjbx5xqxe",
+        ];
+
+        for identity in invalid_identities.iter() {
+            assert_eq!(
+                Discovery::set_ipns_id(
+                    Origin::signed(storage_provider_account_id),
+                    storage_provider_id,
+                    identity.to_vec(),
+                ),
+                Err(Error::<Test>::InvalidIpnsIdentity.into())
+            );
+        }
+
+        let too_long_identity = vec![b'Q'; MAX_IPNS_IDENTITY_LENGTH + 1];
+        assert_eq!(
+            Discovery::set_ipns_id(
+                Origin::signed(storage_provider_account_id),
+                storage_provider_id,
+                too_long_identity,
+            ),
+            Err(Error::<Test>::InvalidIpnsIdentity.into())
+        );
+
+        assert!(!<AccountInfoByStorageProviderId<Test>>::contains_key(
+            &storage_provider_id
+        ));
+    });
+}
+
 #[test]
 fn unset_ipns_id() {
     initial_test_ext().execute_with(|| {
@@ -176,3 +267,466 @@ fn set_bootstrap_endpoints() {
         assert_eq!(Discovery::bootstrap_endpoints(), endpoints, "");
     });
 }
+
+fn test_service_endpoints() -> Vec<ServiceEndpoint> {
+    vec![
+        ServiceEndpoint {
+            protocol: EndpointProtocol::Http,
+            url: b"https://gateway.example.com".to_vec(),
+        },
+        ServiceEndpoint {
+            protocol: EndpointProtocol::Ws,
+            url: b"wss://gateway.example.com".to_vec(),
+        },
+    ]
+}
+
+#[test]
+fn set_service_record() {
+    initial_test_ext().execute_with(|| {
+        let current_block_number = 1000;
+        System::set_block_number(current_block_number);
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let ttl = <Test as system::Trait>::BlockNumber::from(DEFAULT_LIFETIME);
+        let region = b"eu-west".to_vec();
+        assert!(Discovery::set_service_record(
+            Origin::signed(storage_provider_account_id),
+            WorkingGroup::Storage,
+            storage_provider_id,
+            ServiceKind::Gateway,
+            test_service_endpoints(),
+            1,
+            region.clone(),
+        )
+        .is_ok());
+
+        assert_eq!(
+            Discovery::service_record((
+                WorkingGroup::Storage,
+                storage_provider_id,
+                ServiceKind::Gateway
+            )),
+            Some(ServiceRecord {
+                endpoints: test_service_endpoints(),
+                protocol_version: 1,
+                region,
+                expires_at: current_block_number + ttl,
+            })
+        );
+
+        // Other services of the worker are not registered.
+        assert!(Discovery::service_record((
+            WorkingGroup::Storage,
+            storage_provider_id,
+            ServiceKind::QueryNode
+        ))
+        .is_none());
+
+        assert_eq!(
+            *System::events().last().unwrap(),
+            EventRecord {
+                phase: Phase::Initialization,
+                event: MetaEvent::discovery(RawEvent::ServiceRecordUpdated(
+                    WorkingGroup::Storage,
+                    storage_provider_id,
+                    ServiceKind::Gateway
+                )),
+                topics: vec![]
+            }
+        );
+
+        // Invalid worker data
+        let invalid_worker_id = 2;
+        let invalid_worker_account_id = 2;
+        assert!(Discovery::set_service_record(
+            Origin::signed(invalid_worker_account_id),
+            WorkingGroup::Storage,
+            invalid_worker_id,
+            ServiceKind::Gateway,
+            test_service_endpoints(),
+            1,
+            Vec::new(),
+        )
+        .is_err());
+
+        // Worker of another working group
+        assert!(Discovery::set_service_record(
+            Origin::signed(storage_provider_account_id),
+            WorkingGroup::Content,
+            storage_provider_id,
+            ServiceKind::Gateway,
+            test_service_endpoints(),
+            1,
+            Vec::new(),
+        )
+        .is_err());
+    });
+}
+
+#[test]
+fn set_service_record_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let set_record = |endpoints: Vec<ServiceEndpoint>, region: Vec<u8>| {
+            Discovery::set_service_record(
+                Origin::signed(storage_provider_account_id),
+                WorkingGroup::Storage,
+                storage_provider_id,
+                ServiceKind::QueryNode,
+                endpoints,
+                1,
+                region,
+            )
+        };
+
+        // No endpoints
+        assert!(set_record(Vec::new(), Vec::new()).is_err());
+
+        // Too many endpoints
+        let endpoint = test_service_endpoints()[0].clone();
+        assert!(set_record(vec![endpoint; MAX_SERVICE_ENDPOINTS + 1], Vec::new()).is_err());
+
+        // Empty url
+        let empty_url_endpoint = ServiceEndpoint {
+            protocol: EndpointProtocol::Http,
+            url: Vec::new(),
+        };
+        assert!(set_record(vec![empty_url_endpoint], Vec::new()).is_err());
+
+        // Too long url
+        let long_url_endpoint = ServiceEndpoint {
+            protocol: EndpointProtocol::Http,
+            url: vec![b'a'; MAX_SERVICE_URL_LENGTH + 1],
+        };
+        assert!(set_record(vec![long_url_endpoint], Vec::new()).is_err());
+
+        // Too long region tag
+        assert!(set_record(
+            test_service_endpoints(),
+            vec![b'a'; MAX_SERVICE_REGION_LENGTH + 1]
+        )
+        .is_err());
+
+        assert!(Discovery::service_record((
+            WorkingGroup::Storage,
+            storage_provider_id,
+            ServiceKind::QueryNode
+        ))
+        .is_none());
+    });
+}
+
+#[test]
+fn unset_service_record() {
+    initial_test_ext().execute_with(|| {
+        let current_block_number = 1000;
+        System::set_block_number(current_block_number);
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let key = (
+            WorkingGroup::Storage,
+            storage_provider_id,
+            ServiceKind::StorageNode,
+        );
+        <ServiceRecords<Test>>::insert(
+            &key,
+            ServiceRecord {
+                endpoints: test_service_endpoints(),
+                protocol_version: 1,
+                region: Vec::new(),
+                expires_at: 1000,
+            },
+        );
+
+        // Invalid worker data
+        let invalid_worker_account_id = 2;
+        assert!(Discovery::unset_service_record(
+            Origin::signed(invalid_worker_account_id),
+            WorkingGroup::Storage,
+            storage_provider_id,
+            ServiceKind::StorageNode,
+        )
+        .is_err());
+        assert!(<ServiceRecords<Test>>::contains_key(&key));
+
+        assert!(Discovery::unset_service_record(
+            Origin::signed(storage_provider_account_id),
+            WorkingGroup::Storage,
+            storage_provider_id,
+            ServiceKind::StorageNode,
+        )
+        .is_ok());
+        assert!(!<ServiceRecords<Test>>::contains_key(&key));
+
+        assert_eq!(
+            *System::events().last().unwrap(),
+            EventRecord {
+                phase: Phase::Initialization,
+                event: MetaEvent::discovery(RawEvent::ServiceRecordRemoved(
+                    WorkingGroup::Storage,
+                    storage_provider_id,
+                    ServiceKind::StorageNode
+                )),
+                topics: vec![]
+            }
+        );
+    });
+}
+
+#[test]
+fn is_service_record_expired() {
+    initial_test_ext().execute_with(|| {
+        let worker_id = 1;
+        let expires_at = 1000;
+
+        assert!(Discovery::is_service_record_expired(
+            WorkingGroup::Storage,
+            &worker_id,
+            ServiceKind::Gateway
+        ));
+
+        <ServiceRecords<Test>>::insert(
+            (WorkingGroup::Storage, worker_id, ServiceKind::Gateway),
+            ServiceRecord {
+                endpoints: test_service_endpoints(),
+                protocol_version: 1,
+                region: Vec::new(),
+                expires_at,
+            },
+        );
+
+        System::set_block_number(expires_at - 10);
+        assert!(!Discovery::is_service_record_expired(
+            WorkingGroup::Storage,
+            &worker_id,
+            ServiceKind::Gateway
+        ));
+
+        System::set_block_number(expires_at + 10);
+        assert!(Discovery::is_service_record_expired(
+            WorkingGroup::Storage,
+            &worker_id,
+            ServiceKind::Gateway
+        ));
+    });
+}
+
+#[test]
+fn expired_account_infos_are_pruned() {
+    initial_test_ext().execute_with(|| {
+        let set_at = 1;
+        let expires_at = set_at + <Test as system::Trait>::BlockNumber::from(DEFAULT_LIFETIME);
+        System::set_block_number(set_at);
+
+        let storage_provider_ids = vec![1, 2, 3];
+        for storage_provider_id in storage_provider_ids.iter() {
+            let (account_id, _) = hire_storage_provider_with_id(*storage_provider_id);
+
+            assert!(Discovery::set_ipns_id(
+                Origin::signed(account_id),
+                *storage_provider_id,
+                TEST_IPNS_IDENTITY.to_vec(),
+            )
+            .is_ok());
+        }
+
+        // The first record is refreshed.
+        System::set_block_number(set_at + 1);
+        assert!(Discovery::set_ipns_id(Origin::signed(1), 1, TEST_IPNS_IDENTITY.to_vec(),).is_ok());
+
+        // Pruning is up to date with the blocks.
+        <AccountInfoPruningCursor<Test>>::put(expires_at);
+
+        // Nothing is expired yet.
+        System::set_block_number(expires_at);
+        Discovery::on_initialize(expires_at);
+        for storage_provider_id in storage_provider_ids.iter() {
+            assert!(<AccountInfoByStorageProviderId<Test>>::contains_key(
+                storage_provider_id
+            ));
+        }
+
+        // Pruning is limited per block: the refreshed record entry and the second record.
+        System::set_block_number(expires_at + 1);
+        Discovery::on_initialize(expires_at + 1);
+        assert!(<AccountInfoByStorageProviderId<Test>>::contains_key(1));
+        assert!(!<AccountInfoByStorageProviderId<Test>>::contains_key(2));
+        assert!(<AccountInfoByStorageProviderId<Test>>::contains_key(3));
+        assert_eq!(
+            *System::events().last().unwrap(),
+            EventRecord {
+                phase: Phase::Initialization,
+                event: MetaEvent::discovery(RawEvent::AccountInfoRemoved(2)),
+                topics: vec![]
+            }
+        );
+
+        // The rest of the expired records and the refreshed one.
+        System::set_block_number(expires_at + 2);
+        Discovery::on_initialize(expires_at + 2);
+        for storage_provider_id in storage_provider_ids.iter() {
+            assert!(!<AccountInfoByStorageProviderId<Test>>::contains_key(
+                storage_provider_id
+            ));
+        }
+        assert!(!<AccountInfoIdsByExpiration<Test>>::contains_key(
+            expires_at
+        ));
+        assert!(!<AccountInfoIdsByExpiration<Test>>::contains_key(
+            expires_at + 1
+        ));
+        assert_eq!(Discovery::account_info_pruning_cursor(), expires_at + 2);
+    });
+}
+
+#[test]
+fn worker_records_are_removed_on_leaving_working_group() {
+    initial_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        assert!(Discovery::set_ipns_id(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            TEST_IPNS_IDENTITY.to_vec(),
+        )
+        .is_ok());
+        assert!(Discovery::set_service_record(
+            Origin::signed(storage_provider_account_id),
+            WorkingGroup::Storage,
+            storage_provider_id,
+            ServiceKind::StorageNode,
+            test_service_endpoints(),
+            1,
+            Vec::new(),
+        )
+        .is_ok());
+
+        assert!(
+            working_group::Module::<Test, StorageWorkingGroupInstance>::leave_role(
+                Origin::signed(storage_provider_account_id),
+                storage_provider_id,
+                Vec::new(),
+            )
+            .is_ok()
+        );
+
+        assert!(!<AccountInfoByStorageProviderId<Test>>::contains_key(
+            storage_provider_id
+        ));
+        assert!(Discovery::service_record((
+            WorkingGroup::Storage,
+            storage_provider_id,
+            ServiceKind::StorageNode
+        ))
+        .is_none());
+
+        let discovery_events: Vec<_> = System::events()
+            .into_iter()
+            .map(|record| record.event)
+            .filter(|event| match event {
+                MetaEvent::discovery(RawEvent::AccountInfoRemoved(_))
+                | MetaEvent::discovery(RawEvent::ServiceRecordRemoved(..)) => true,
+                _ => false,
+            })
+            .collect();
+        assert_eq!(
+            discovery_events,
+            vec![
+                MetaEvent::discovery(RawEvent::AccountInfoRemoved(storage_provider_id)),
+                MetaEvent::discovery(RawEvent::ServiceRecordRemoved(
+                    WorkingGroup::Storage,
+                    storage_provider_id,
+                    ServiceKind::StorageNode
+                )),
+            ]
+        );
+    });
+}
+
+#[test]
+fn expired_service_records_are_pruned() {
+    initial_test_ext().execute_with(|| {
+        let set_at = 1;
+        let expires_at = set_at + <Test as system::Trait>::BlockNumber::from(DEFAULT_LIFETIME);
+        System::set_block_number(set_at);
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+        let key = (
+            WorkingGroup::Storage,
+            storage_provider_id,
+            ServiceKind::StorageNode,
+        );
+
+        assert!(Discovery::set_service_record(
+            Origin::signed(storage_provider_account_id),
+            WorkingGroup::Storage,
+            storage_provider_id,
+            ServiceKind::StorageNode,
+            test_service_endpoints(),
+            1,
+            Vec::new(),
+        )
+        .is_ok());
+
+        // Pruning is up to date with the blocks.
+        <ServiceRecordPruningCursor<Test>>::put(expires_at);
+
+        // Nothing is expired yet.
+        System::set_block_number(expires_at);
+        Discovery::on_initialize(expires_at);
+        assert!(Discovery::service_record(key).is_some());
+
+        System::set_block_number(expires_at + 1);
+        Discovery::on_initialize(expires_at + 1);
+        assert!(Discovery::service_record(key).is_none());
+        assert!(!<ServiceRecordKeysByExpiration<Test>>::contains_key(
+            expires_at
+        ));
+        assert_eq!(Discovery::service_record_pruning_cursor(), expires_at + 1);
+        assert_eq!(
+            *System::events().last().unwrap(),
+            EventRecord {
+                phase: Phase::Initialization,
+                event: MetaEvent::discovery(RawEvent::ServiceRecordRemoved(
+                    WorkingGroup::Storage,
+                    storage_provider_id,
+                    ServiceKind::StorageNode
+                )),
+                topics: vec![]
+            }
+        );
+    });
+}
+
+#[test]
+fn account_info_expirations_migration_runs_once() {
+    initial_test_ext().execute_with(|| {
+        let now = 10;
+        System::set_block_number(now);
+
+        let record = ServiceProviderRecord {
+            identity: TEST_IPNS_IDENTITY.to_vec(),
+            expires_at: now + 5,
+        };
+        <AccountInfoByStorageProviderId<Test>>::insert(1, record.clone());
+
+        Discovery::migrate_account_info_expirations();
+
+        assert_eq!(Discovery::account_info_ids_by_expiration(now + 5), vec![1]);
+        assert_eq!(Discovery::account_info_pruning_cursor(), now);
+        assert_eq!(Discovery::service_record_pruning_cursor(), now);
+
+        // The next upgrades don't index the records again.
+        <AccountInfoByStorageProviderId<Test>>::insert(2, record);
+
+        Discovery::migrate_account_info_expirations();
+
+        assert_eq!(Discovery::account_info_ids_by_expiration(now + 5), vec![1]);
+    });
+}
@@ -0,0 +1,171 @@
+use frame_benchmarking::benchmarks;
+use frame_support::dispatch::UnfilteredDispatchable;
+use frame_support::traits::{Currency, Get};
+use sp_runtime::traits::{Bounded, One};
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::prelude::*;
+use system::RawOrigin;
+
+use super::*;
+use crate::data_directory::{
+    Call, ChannelIdByContentId, DataObjectsMap, Module, Quota, StorageObjectOwner, UploadFeePerByte,
+};
+use common::currency::{BalanceOf, GovernanceCurrency};
+
+// Number of storage providers storing the removed content.
+const STORAGE_RELATIONSHIPS: u32 = 10;
+
+// Adds the content ids to the known content ids index.
+fn inject_known_content_ids<T: Trait>(content_ids: Vec<T::ContentId>) {
+    let (liaison, _) = insert_storage_provider::<T>();
+    let objects: DataObjectsMap<T> = content_ids
+        .into_iter()
+        .map(|content_id| (content_id, data_object::<T>(content_id, liaison)))
+        .collect();
+
+    Call::<T>::inject_data_objects(objects)
+        .dispatch_bypass_filter(RawOrigin::Root.into())
+        .expect("root can inject data objects");
+}
+
+// Inserts an accepted data object of the member stored by several storage providers.
+fn insert_stored_content<T: Trait>(owner: MemberId<T>) -> T::ContentId {
+    let content_id = content_id::<T>(0);
+    let (liaison, _) = insert_storage_provider::<T>();
+
+    inject_known_content_ids::<T>(vec![content_id]);
+    DataObjectByContentId::<T>::insert(
+        content_id,
+        DataObjectInternal {
+            owner,
+            liaison_judgement: LiaisonJudgement::Accepted,
+            ..data_object::<T>(content_id, liaison)
+        },
+    );
+    ChannelIdByContentId::<T>::insert(content_id, T::insert_channel(owner));
+
+    for _ in 0..STORAGE_RELATIONSHIPS {
+        let (storage_provider_id, account_id) = insert_storage_provider::<T>();
+        crate::data_object_storage_registry::Module::<T>::add_relationship(
+            RawOrigin::Signed(account_id).into(),
+            storage_provider_id,
+            content_id,
+        )
+        .expect("storage provider can add relationships");
+    }
+
+    content_id
+}
+
+benchmarks! {
+    _ { }
+
+    add_content {
+        let (member_id, account_id) = insert_member::<T>();
+        let channel_id = T::insert_channel(member_id);
+        let _ = insert_storage_provider::<T>();
+        let type_id = insert_data_object_type::<T>();
+        let content_id = content_id::<T>(0);
+        let size = T::DefaultQuotaSizeLimit::get();
+
+        UploadFeePerByte::<T>::put(BalanceOf::<T>::one());
+        <T as GovernanceCurrency>::Currency::make_free_balance_be(
+            &account_id,
+            BalanceOf::<T>::max_value(),
+        );
+    }: _(RawOrigin::Signed(account_id), member_id, Some(channel_id), content_id, type_id, size, b"video/mp4".to_vec(), content_id.encode())
+    verify {
+        assert!(DataObjectByContentId::<T>::contains_key(content_id));
+        assert_eq!(Module::<T>::quota(&StorageObjectOwner::Channel(channel_id)).size_used, size);
+    }
+
+    remove_content {
+        let (member_id, account_id) = insert_member::<T>();
+        let content_id = insert_stored_content::<T>(member_id);
+    }: _(RawOrigin::Signed(account_id), member_id, content_id)
+    verify {
+        assert!(!DataObjectByContentId::<T>::contains_key(content_id));
+    }
+
+    remove_content_as_lead {
+        let lead_account_id = insert_lead::<T>();
+        let content_id = insert_stored_content::<T>(MemberId::<T>::default());
+    }: _(RawOrigin::Signed(lead_account_id), content_id)
+    verify {
+        assert!(!DataObjectByContentId::<T>::contains_key(content_id));
+    }
+
+    accept_content {
+        let (storage_provider_id, account_id) = insert_storage_provider::<T>();
+        let content_id = content_id::<T>(0);
+        insert_data_object::<T>(content_id, storage_provider_id);
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, content_id)
+    verify {
+        assert!(Module::<T>::is_known_content_id(&content_id));
+    }
+
+    reject_content {
+        let (storage_provider_id, account_id) = insert_storage_provider::<T>();
+        let content_id = content_id::<T>(0);
+        insert_data_object::<T>(content_id, storage_provider_id);
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, content_id)
+    verify {
+        let data_object = Module::<T>::data_object_by_content_id(content_id).unwrap();
+        assert_eq!(data_object.liaison_judgement, LiaisonJudgement::Rejected);
+    }
+
+    set_storage_capacity {
+        let (storage_provider_id, account_id) = insert_storage_provider::<T>();
+        let capacity = u64::max_value() / 2;
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, capacity)
+    verify {
+        assert_eq!(
+            Module::<T>::storage_capacity_by_storage_provider_id(storage_provider_id).capacity,
+            Some(capacity)
+        );
+    }
+
+    update_quota_limits {
+        let lead_account_id = insert_lead::<T>();
+        let (member_id, _) = insert_member::<T>();
+        let owner = StorageObjectOwner::Member(member_id);
+    }: _(RawOrigin::Signed(lead_account_id), owner, u64::max_value(), u64::max_value())
+    verify {
+        assert_eq!(
+            Module::<T>::quota_by_owner(&owner),
+            Some(Quota::new(u64::max_value(), u64::max_value()))
+        );
+    }
+
+    set_upload_fee_per_byte {
+        let upload_fee_per_byte = BalanceOf::<T>::one();
+    }: _(RawOrigin::Root, upload_fee_per_byte)
+    verify {
+        assert_eq!(Module::<T>::upload_fee_per_byte(), upload_fee_per_byte);
+    }
+
+    remove_known_content_id {
+        let content_ids: Vec<T::ContentId> = (0..T::MaxObjectsPerInjection::get())
+            .map(content_id::<T>)
+            .collect();
+        let removed_content_id = content_ids[0];
+        inject_known_content_ids::<T>(content_ids);
+    }: _(RawOrigin::Root, removed_content_id)
+    verify {
+        assert!(!Module::<T>::is_known_content_id(&removed_content_id));
+    }
+
+    inject_data_objects {
+        let i in 1 .. T::MaxObjectsPerInjection::get();
+        let (storage_provider_id, _) = insert_storage_provider::<T>();
+        let objects: DataObjectsMap<T> = (0..i)
+            .map(|index| {
+                let content_id = content_id::<T>(index);
+                (content_id, data_object::<T>(content_id, storage_provider_id))
+            })
+            .collect::<BTreeMap<_, _>>();
+    }: _(RawOrigin::Root, objects)
+    verify {
+        assert_eq!(Module::<T>::known_content_ids_count(), u64::from(i));
+    }
+}
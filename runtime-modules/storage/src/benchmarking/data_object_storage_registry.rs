@@ -0,0 +1,115 @@
+use frame_benchmarking::benchmarks;
+use sp_runtime::traits::Bounded;
+use sp_std::prelude::*;
+use system::RawOrigin;
+
+use super::*;
+use crate::data_object_storage_registry::{
+    Call, Module, Relationships, StorageChallenge, StorageChallengeId, StorageChallenges,
+};
+
+fn insert_relationship<T: Trait>(
+    storage_provider_id: StorageProviderId<T>,
+    account_id: T::AccountId,
+) -> T::DataObjectStorageRelationshipId {
+    let content_id = content_id::<T>(0);
+    insert_data_object::<T>(content_id, storage_provider_id);
+
+    let relationship_id = Module::<T>::next_relationship_id();
+    Module::<T>::add_relationship(
+        RawOrigin::Signed(account_id).into(),
+        storage_provider_id,
+        content_id,
+    )
+    .expect("storage provider can add relationships");
+
+    relationship_id
+}
+
+fn insert_storage_challenge<T: Trait>(
+    storage_provider_id: StorageProviderId<T>,
+    response: Option<T::Hash>,
+) -> StorageChallengeId {
+    let challenge_id = Module::<T>::next_storage_challenge_id();
+
+    StorageChallenges::<T>::insert(
+        challenge_id,
+        StorageChallenge::<T> {
+            content_id: content_id::<T>(0),
+            storage_provider_id,
+            range_start: 0,
+            range_length: 0,
+            deadline: T::BlockNumber::max_value(),
+            response,
+        },
+    );
+
+    challenge_id
+}
+
+benchmarks! {
+    _ { }
+
+    add_relationship {
+        let (storage_provider_id, account_id) = insert_storage_provider::<T>();
+        let content_id = content_id::<T>(0);
+        insert_data_object::<T>(content_id, storage_provider_id);
+        let relationship_id = Module::<T>::next_relationship_id();
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, content_id)
+    verify {
+        assert!(Relationships::<T>::contains_key(relationship_id));
+    }
+
+    set_relationship_ready {
+        let (storage_provider_id, account_id) = insert_storage_provider::<T>();
+        let relationship_id = insert_relationship::<T>(storage_provider_id, account_id.clone());
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, relationship_id)
+    verify {
+        assert!(Module::<T>::relationships(relationship_id).unwrap().ready);
+        assert!(!Module::<T>::is_under_replicated(&content_id::<T>(0)));
+    }
+
+    unset_relationship_ready {
+        let (storage_provider_id, account_id) = insert_storage_provider::<T>();
+        let relationship_id = insert_relationship::<T>(storage_provider_id, account_id.clone());
+        Module::<T>::set_relationship_ready(
+            RawOrigin::Signed(account_id.clone()).into(),
+            storage_provider_id,
+            relationship_id,
+        )
+        .expect("storage provider can set own relationships ready");
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, relationship_id)
+    verify {
+        assert!(!Module::<T>::relationships(relationship_id).unwrap().ready);
+        assert!(Module::<T>::is_under_replicated(&content_id::<T>(0)));
+    }
+
+    answer_storage_challenge {
+        let (storage_provider_id, account_id) = insert_storage_provider::<T>();
+        let challenge_id = insert_storage_challenge::<T>(storage_provider_id, None);
+        let range_hash = T::Hash::default();
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, challenge_id, range_hash)
+    verify {
+        assert_eq!(
+            Module::<T>::storage_challenges(challenge_id).unwrap().response,
+            Some(range_hash)
+        );
+    }
+
+    reject_storage_challenge_response {
+        let lead_account_id = insert_lead::<T>();
+        let (storage_provider_id, _) = insert_storage_provider::<T>();
+        let challenge_id =
+            insert_storage_challenge::<T>(storage_provider_id, Some(T::Hash::default()));
+    }: _(RawOrigin::Signed(lead_account_id), challenge_id)
+    verify {
+        assert_eq!(Module::<T>::failed_storage_challenges(storage_provider_id), 1);
+    }
+
+    set_target_replication_factor {
+        let lead_account_id = insert_lead::<T>();
+    }: _(RawOrigin::Signed(lead_account_id), u32::max_value())
+    verify {
+        assert_eq!(Module::<T>::target_replication_factor(), u32::max_value());
+    }
+}
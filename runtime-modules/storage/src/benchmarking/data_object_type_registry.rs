@@ -0,0 +1,57 @@
+use frame_benchmarking::benchmarks;
+use sp_std::prelude::*;
+use system::RawOrigin;
+
+use super::*;
+use crate::data_object_type_registry::{Call, Module};
+
+fn max_data_object_type() -> DataObjectType {
+    DataObjectType {
+        description: vec![b'd'; 1024],
+        active: true,
+        max_size: Some(u64::max_value()),
+        allowed_content_types: vec![vec![b'c'; 64]; 32],
+        min_replication_factor: Some(u32::max_value()),
+    }
+}
+
+benchmarks! {
+    _ { }
+
+    register_data_object_type {
+        let lead_account_id = insert_lead::<T>();
+        let type_id = Module::<T>::next_data_object_type_id();
+    }: _(RawOrigin::Signed(lead_account_id), max_data_object_type())
+    verify {
+        assert!(DataObjectTypes::<T>::contains_key(type_id));
+    }
+
+    update_data_object_type {
+        let lead_account_id = insert_lead::<T>();
+        let type_id = insert_data_object_type::<T>();
+    }: _(RawOrigin::Signed(lead_account_id), type_id, max_data_object_type())
+    verify {
+        assert_eq!(Module::<T>::data_object_types(type_id), Some(max_data_object_type()));
+    }
+
+    activate_data_object_type {
+        let lead_account_id = insert_lead::<T>();
+        let type_id = insert_data_object_type::<T>();
+        DataObjectTypes::<T>::mutate(type_id, |data_object_type| {
+            if let Some(data_object_type) = data_object_type {
+                data_object_type.active = false;
+            }
+        });
+    }: _(RawOrigin::Signed(lead_account_id), type_id)
+    verify {
+        assert!(Module::<T>::data_object_types(type_id).unwrap().active);
+    }
+
+    deactivate_data_object_type {
+        let lead_account_id = insert_lead::<T>();
+        let type_id = insert_data_object_type::<T>();
+    }: _(RawOrigin::Signed(lead_account_id), type_id)
+    verify {
+        assert!(!Module::<T>::data_object_types(type_id).unwrap().active);
+    }
+}
@@ -0,0 +1,120 @@
+//! Storage modules benchmarking.
+
+mod data_directory;
+mod data_object_storage_registry;
+mod data_object_type_registry;
+
+use codec::{Decode, Encode};
+use frame_benchmarking::account;
+use frame_support::{StorageMap, StorageValue};
+use sp_arithmetic::traits::One;
+use sp_runtime::traits::Hash;
+use sp_std::prelude::*;
+use system::RawOrigin;
+
+use crate::data_directory::{
+    DataObject, DataObjectByContentId, DataObjectInternal, LiaisonJudgement,
+};
+use crate::data_object_type_registry::{DataObjectType, DataObjectTypes, NextDataObjectTypeId};
+use crate::{MemberId, StorageProviderId, StorageWorkingGroupInstance};
+
+const SEED: u32 = 0;
+
+/// Liaison selection is provided by the runtime `StorageProviderHelper`, so the runtime has to
+/// tell the benchmarks how to make a storage provider eligible for it.
+pub trait Trait: crate::data_object_storage_registry::Trait {
+    /// Makes the storage provider available for the content liaison selection.
+    fn make_storage_provider_available(storage_provider_id: StorageProviderId<Self>);
+
+    /// Creates a new channel owned by the member.
+    fn insert_channel(owner: MemberId<Self>) -> Self::ChannelId;
+}
+
+fn insert_worker<T: Trait>(name: &'static str) -> (StorageProviderId<T>, T::AccountId) {
+    let worker_id = working_group::NextWorkerId::<T, StorageWorkingGroupInstance>::get();
+    let role_account_id: T::AccountId = account(name, 0, SEED);
+
+    working_group::WorkerById::<T, StorageWorkingGroupInstance>::insert(
+        worker_id,
+        working_group::Worker::new(&MemberId::<T>::default(), &role_account_id, &None, &None),
+    );
+    working_group::NextWorkerId::<T, StorageWorkingGroupInstance>::mutate(|id| *id += One::one());
+
+    (worker_id, role_account_id)
+}
+
+/// Sets a new storage working group lead and returns its role account.
+pub(crate) fn insert_lead<T: Trait>() -> T::AccountId {
+    let (lead_id, lead_account_id) = insert_worker::<T>("lead");
+    working_group::CurrentLead::<T, StorageWorkingGroupInstance>::put(lead_id);
+
+    lead_account_id
+}
+
+/// Hires a new storage provider ready to be chosen as a liaison.
+pub(crate) fn insert_storage_provider<T: Trait>() -> (StorageProviderId<T>, T::AccountId) {
+    let (storage_provider_id, role_account_id) = insert_worker::<T>("storage_provider");
+    T::make_storage_provider_available(storage_provider_id);
+
+    (storage_provider_id, role_account_id)
+}
+
+/// Registers a new member controlled by the returned account.
+pub(crate) fn insert_member<T: Trait>() -> (MemberId<T>, T::AccountId) {
+    let account_id: T::AccountId = account("member", 0, SEED);
+    let member_id = membership::Module::<T>::members_created();
+
+    membership::ScreeningAuthority::<T>::put(account_id.clone());
+    membership::Module::<T>::add_screened_member(
+        RawOrigin::Signed(account_id.clone()).into(),
+        account_id.clone(),
+        Some(b"storage_member".to_vec()),
+        None,
+        None,
+    )
+    .expect("benchmark member must be registered");
+
+    (member_id, account_id)
+}
+
+/// Registers a new active data object type.
+pub(crate) fn insert_data_object_type<T: Trait>() -> T::DataObjectTypeId {
+    let type_id = NextDataObjectTypeId::<T>::get();
+
+    DataObjectTypes::<T>::insert(type_id, DataObjectType::default());
+    NextDataObjectTypeId::<T>::mutate(|id| *id += One::one());
+
+    type_id
+}
+
+/// Deterministic content id for the given index.
+pub(crate) fn content_id<T: Trait>(index: u32) -> T::ContentId {
+    let hash = T::Hashing::hash_of(&(b"content", index));
+    let entropy: &[u8] = hash.as_ref();
+
+    T::ContentId::decode(&mut &entropy[..]).unwrap_or_default()
+}
+
+/// Creates a pending data object with the given liaison.
+pub(crate) fn data_object<T: Trait>(
+    content_id: T::ContentId,
+    liaison: StorageProviderId<T>,
+) -> DataObject<T> {
+    DataObjectInternal {
+        owner: MemberId::<T>::default(),
+        added_at: common::current_block_time::<T>(),
+        type_id: insert_data_object_type::<T>(),
+        size: u64::max_value(),
+        liaison,
+        liaison_judgement: LiaisonJudgement::Pending,
+        ipfs_content_id: content_id.encode(),
+    }
+}
+
+/// Inserts a pending data object with the given liaison.
+pub(crate) fn insert_data_object<T: Trait>(
+    content_id: T::ContentId,
+    liaison: StorageProviderId<T>,
+) {
+    DataObjectByContentId::<T>::insert(content_id, data_object::<T>(content_id, liaison));
+}
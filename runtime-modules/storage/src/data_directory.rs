@@ -10,10 +10,15 @@
 //!
 //! ### Public extrinsic
 //! - [add_content](./struct.Module.html#method.add_content) - Adds the content to the system.
+//! - [remove_content](./struct.Module.html#method.remove_content) - Removes the content owned by the member.
 //!
 //! ### Private extrinsics
 //! - accept_content - Storage provider accepts a content.
 //! - reject_content - Storage provider rejects a content.
+//! - set_storage_capacity - Storage provider declares its storage capacity.
+//! - remove_content_as_lead - Storage working group lead removes a content.
+//! - update_quota_limits - Storage working group lead updates the storage quota limits of the member or the channel.
+//! - set_upload_fee_per_byte - Sets the fee charged per byte of the uploaded content. Requires root privileges.
 //! - remove_known_content_id - Removes the content id from the list of known content ids. Requires root privileges.
 //! - set_known_content_id - Sets the content id from the list of known content ids. Requires root privileges.
 //!
@@ -23,21 +28,26 @@
 
 use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchResult;
-use frame_support::traits::Get;
+use frame_support::storage::migration;
+use frame_support::traits::{Currency, Get};
+use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
-use sp_runtime::traits::{MaybeSerialize, Member};
+use sp_runtime::traits::{MaybeSerialize, Member, Saturating};
+use sp_runtime::SaturatedConversion;
 use sp_std::collections::btree_map::BTreeMap;
+use sp_std::vec;
 use sp_std::vec::Vec;
 use system::ensure_root;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
+use common::currency::{BalanceOf, GovernanceCurrency};
 use common::origin::ActorOriginValidator;
 pub(crate) use common::BlockAndTime;
 
 use crate::data_object_type_registry;
-use crate::data_object_type_registry::IsActiveDataObjectType;
+use crate::data_object_type_registry::{DataObjectTypeProvider, IsActiveDataObjectType};
 use crate::{MemberId, StorageProviderId, StorageWorkingGroup, StorageWorkingGroupInstance};
 
 /// The _Data directory_ main _Trait_.
@@ -54,16 +64,88 @@ pub trait Trait:
     /// Content id.
     type ContentId: Parameter + Member + MaybeSerialize + Copy + Ord + Default;
 
-    /// Provides random storage provider id.
+    /// Content channel id.
+    type ChannelId: Parameter + Member + MaybeSerialize + Copy + Ord + Default;
+
+    /// Provides random storage provider id with enough free space.
     type StorageProviderHelper: StorageProviderHelper<Self>;
 
     ///Active data object type validator.
     type IsActiveDataObjectType: data_object_type_registry::IsActiveDataObjectType<Self>;
 
+    /// Provides the data object type constraints.
+    type DataObjectTypeProvider: data_object_type_registry::DataObjectTypeProvider<Self>;
+
     /// Validates member id and origin combination.
     type MemberOriginValidator: ActorOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>;
 
+    /// Validates the channel ownership of the member.
+    type ChannelOwnershipValidator: ChannelOwnershipValidator<Self>;
+
+    /// Cleans up the data related to the removed content.
+    type ContentRemovalHandler: ContentRemovalHandler<Self>;
+
+    /// Handles the content accepted by its liaison.
+    type ContentAcceptanceHandler: ContentAcceptanceHandler<Self>;
+
     type MaxObjectsPerInjection: Get<u32>;
+
+    /// Default limit of the content objects for the member or the channel.
+    type DefaultQuotaObjectsLimit: Get<u64>;
+
+    /// Default limit of the content size in bytes for the member or the channel.
+    type DefaultQuotaSizeLimit: Get<u64>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+}
+
+/// Weight functions needed for the data directory module.
+pub trait WeightInfo {
+    fn add_content() -> Weight;
+    fn remove_content() -> Weight;
+    fn remove_content_as_lead() -> Weight;
+    fn accept_content() -> Weight;
+    fn reject_content() -> Weight;
+    fn set_storage_capacity() -> Weight;
+    fn update_quota_limits() -> Weight;
+    fn set_upload_fee_per_byte() -> Weight;
+    fn remove_known_content_id() -> Weight;
+    fn inject_data_objects(i: u32) -> Weight;
+}
+
+/// Flat weights for the test runtimes.
+impl WeightInfo for () {
+    fn add_content() -> Weight {
+        10_000_000
+    }
+    fn remove_content() -> Weight {
+        10_000_000
+    }
+    fn remove_content_as_lead() -> Weight {
+        10_000_000
+    }
+    fn accept_content() -> Weight {
+        10_000_000
+    }
+    fn reject_content() -> Weight {
+        10_000_000
+    }
+    fn set_storage_capacity() -> Weight {
+        10_000_000
+    }
+    fn update_quota_limits() -> Weight {
+        10_000_000
+    }
+    fn set_upload_fee_per_byte() -> Weight {
+        10_000_000
+    }
+    fn remove_known_content_id() -> Weight {
+        10_000_000
+    }
+    fn inject_data_objects(_i: u32) -> Weight {
+        10_000_000
+    }
 }
 
 decl_error! {
@@ -85,7 +167,28 @@ decl_error! {
         RequireRootOrigin,
 
         /// DataObject Injection Failed. Too Many DataObjects.
-        DataObjectsInjectionExceededLimit
+        DataObjectsInjectionExceededLimit,
+
+        /// Only the owner of the content may remove it.
+        OwnerRequired,
+
+        /// Only the channel owner may add the content to the channel.
+        ChannelOwnerRequired,
+
+        /// Content objects limit of the storage quota exceeded.
+        QuotaObjectsLimitExceeded,
+
+        /// Content size limit of the storage quota exceeded.
+        QuotaSizeLimitExceeded,
+
+        /// Not enough balance to pay the upload fee.
+        InsufficientBalanceForUploadFee,
+
+        /// Content size exceeds the maximum size of the data object type.
+        DataObjectTypeMaxSizeExceeded,
+
+        /// Content type is not allowed by the data object type.
+        ContentTypeNotAllowed,
     }
 }
 
@@ -144,17 +247,139 @@ pub struct DataObjectInternal<MemberId, BlockNumber, Moment, DataObjectTypeId, S
     pub ipfs_content_id: Vec<u8>,
 }
 
+/// Storage capacity declared by the storage provider and the space taken by the content it is
+/// the liaison for.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, Default, PartialEq, Debug)]
+pub struct StorageCapacity {
+    /// Declared storage capacity in bytes. Not limited until the storage provider declares it.
+    pub capacity: Option<u64>,
+
+    /// Bytes taken by the content assigned to the storage provider.
+    pub used: u64,
+}
+
+impl StorageCapacity {
+    /// Storage space left for the new content in bytes, if the capacity is declared.
+    pub fn free_space(&self) -> Option<u64> {
+        self.capacity
+            .map(|capacity| capacity.saturating_sub(self.used))
+    }
+}
+
+/// Owner of the storage quota.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, Debug)]
+pub enum StorageObjectOwner<MemberId, ChannelId> {
+    /// Member uploading the content.
+    Member(MemberId),
+
+    /// Channel the content is uploaded to.
+    Channel(ChannelId),
+}
+
+/// Alias for StorageObjectOwner
+pub type StorageObjectOwnerOf<T> = StorageObjectOwner<MemberId<T>, <T as Trait>::ChannelId>;
+
+/// Storage quota limits and the content objects counted against them.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, Default, PartialEq, Debug)]
+pub struct Quota {
+    /// Maximum number of the content objects.
+    pub objects_limit: u64,
+
+    /// Maximum total size of the content objects in bytes.
+    pub size_limit: u64,
+
+    /// Number of the content objects counted against the quota.
+    pub objects_used: u64,
+
+    /// Total size of the content objects counted against the quota in bytes.
+    pub size_used: u64,
+}
+
+impl Quota {
+    /// Creates an unused quota with the given limits.
+    pub fn new(objects_limit: u64, size_limit: u64) -> Self {
+        Quota {
+            objects_limit,
+            size_limit,
+            ..Default::default()
+        }
+    }
+
+    fn ensure_can_fulfill<T: Trait>(&self, size: u64) -> DispatchResult {
+        ensure!(
+            self.objects_used < self.objects_limit,
+            Error::<T>::QuotaObjectsLimitExceeded
+        );
+
+        ensure!(
+            self.size_used.saturating_add(size) <= self.size_limit,
+            Error::<T>::QuotaSizeLimitExceeded
+        );
+
+        Ok(())
+    }
+
+    fn fulfill(&mut self, size: u64) {
+        self.objects_used = self.objects_used.saturating_add(1);
+        self.size_used = self.size_used.saturating_add(size);
+    }
+
+    fn release(&mut self, size: u64) {
+        self.objects_used = self.objects_used.saturating_sub(1);
+        self.size_used = self.size_used.saturating_sub(size);
+    }
+}
+
+/// Maximum number of the known content ids returned by a single page query.
+pub const MAX_KNOWN_CONTENT_IDS_PAGE_SIZE: u64 = 1000;
+
 /// A map collection of unique DataObjects keyed by the ContentId
 pub type DataObjectsMap<T> = BTreeMap<<T as Trait>::ContentId, DataObject<T>>;
 
 decl_storage! {
     trait Store for Module<T: Trait> as DataDirectory {
-        /// List of ids known to the system.
-        pub KnownContentIds get(fn known_content_ids) config(): Vec<T::ContentId> = Vec::new();
+        /// Number of the content ids known to the system.
+        pub KnownContentIdsCount get(fn known_content_ids_count): u64;
+
+        /// Content ids known to the system by their position in the index.
+        pub KnownContentIdByIndex get(fn known_content_id_by_index):
+            map hasher(twox_64_concat) u64 => T::ContentId;
+
+        /// Positions of the content ids known to the system in the index.
+        pub KnownContentIdIndex get(fn known_content_id_index):
+            map hasher(blake2_128_concat) T::ContentId => Option<u64>;
 
         /// Maps data objects by their content id.
         pub DataObjectByContentId get(fn data_object_by_content_id) config():
             map hasher(blake2_128_concat) T::ContentId => Option<DataObject<T>>;
+
+        /// Maps storage capacity by the storage provider id.
+        pub StorageCapacityByStorageProviderId get(fn storage_capacity_by_storage_provider_id):
+            map hasher(blake2_128_concat) StorageProviderId<T> => StorageCapacity;
+
+        /// Maps the storage quotas by their owners. Owners without the stored quota get the
+        /// default one.
+        pub QuotaByOwner get(fn quota_by_owner):
+            map hasher(blake2_128_concat) StorageObjectOwnerOf<T> => Option<Quota>;
+
+        /// Maps the channel ids by the content ids uploaded to the channels.
+        pub ChannelIdByContentId get(fn channel_id_by_content_id):
+            map hasher(blake2_128_concat) T::ContentId => Option<T::ChannelId>;
+
+        /// Fee charged per byte of the uploaded content.
+        pub UploadFeePerByte get(fn upload_fee_per_byte): BalanceOf<T>;
+    }
+    add_extra_genesis {
+        /// List of ids known to the system.
+        config(known_content_ids): Vec<T::ContentId>;
+        build(|config: &GenesisConfig<T>| {
+            for content_id in config.known_content_ids.iter() {
+                <Module<T>>::index_known_content_id(*content_id);
+            }
+        });
     }
 }
 
@@ -163,7 +388,9 @@ decl_event! {
     pub enum Event<T> where
         <T as Trait>::ContentId,
         MemberId = MemberId<T>,
-        StorageProviderId = StorageProviderId<T>
+        StorageProviderId = StorageProviderId<T>,
+        StorageObjectOwner = StorageObjectOwnerOf<T>,
+        Balance = BalanceOf<T>
     {
         /// Emits on adding of the content.
         /// Params:
@@ -182,6 +409,30 @@ decl_event! {
         /// - Id of the relationship.
         /// - Id of the storage provider.
         ContentRejected(ContentId, StorageProviderId),
+
+        /// Emits on the content removal. Storage providers should unpin the content.
+        /// Params:
+        /// - Id of the content.
+        /// - IPFS content id.
+        ContentRemoved(ContentId, Vec<u8>),
+
+        /// Emits when the storage provider declares its storage capacity.
+        /// Params:
+        /// - Id of the storage provider.
+        /// - Storage capacity in bytes.
+        StorageCapacityUpdated(StorageProviderId, u64),
+
+        /// Emits when the storage working group lead updates the storage quota limits.
+        /// Params:
+        /// - Owner of the storage quota.
+        /// - Content objects limit.
+        /// - Content size limit in bytes.
+        QuotaLimitsUpdated(StorageObjectOwner, u64, u64),
+
+        /// Emits when the upload fee changes.
+        /// Params:
+        /// - Fee charged per byte of the uploaded content.
+        UploadFeePerByteUpdated(Balance),
     }
 }
 
@@ -197,29 +448,63 @@ decl_module! {
         /// Maximum objects allowed per inject_data_objects() transaction
         const MaxObjectsPerInjection: u32 = T::MaxObjectsPerInjection::get();
 
-        /// Adds the content to the system. Member id should match its origin. The created DataObject
+        /// Default limit of the content objects for the member or the channel.
+        const DefaultQuotaObjectsLimit: u64 = T::DefaultQuotaObjectsLimit::get();
+
+        /// Default limit of the content size in bytes for the member or the channel.
+        const DefaultQuotaSizeLimit: u64 = T::DefaultQuotaSizeLimit::get();
+
+        /// Adds the content to the system. Member id should match its origin. The content is
+        /// counted against the storage quotas of the member and the optional channel owned by
+        /// the member. The upload fee is charged to the member account. The content size and its
+        /// MIME type should satisfy the data object type constraints. The created DataObject
         /// awaits liaison to accept or reject it.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::add_content()]
         pub fn add_content(
             origin,
             member_id: MemberId<T>,
+            channel_id: Option<T::ChannelId>,
             content_id: T::ContentId,
             type_id: <T as data_object_type_registry::Trait>::DataObjectTypeId,
             size: u64,
+            content_type: Vec<u8>,
             ipfs_content_id: Vec<u8>
         ) {
-            T::MemberOriginValidator::ensure_actor_origin(
+            let account_id = T::MemberOriginValidator::ensure_actor_origin(
                 origin,
                 member_id,
             )?;
 
+            if let Some(channel_id) = channel_id {
+                ensure!(T::ChannelOwnershipValidator::is_channel_owner(&member_id, &channel_id),
+                    Error::<T>::ChannelOwnerRequired);
+            }
+
             ensure!(T::IsActiveDataObjectType::is_active_data_object_type(&type_id),
                 Error::<T>::DataObjectTypeMustBeActive);
 
+            let data_object_type = T::DataObjectTypeProvider::data_object_type(&type_id)
+                .ok_or(Error::<T>::DataObjectTypeMustBeActive)?;
+
+            ensure!(data_object_type.is_size_allowed(size),
+                Error::<T>::DataObjectTypeMaxSizeExceeded);
+
+            ensure!(data_object_type.is_content_type_allowed(&content_type),
+                Error::<T>::ContentTypeNotAllowed);
+
             ensure!(!<DataObjectByContentId<T>>::contains_key(content_id),
                 Error::<T>::DataObjectAlreadyAdded);
 
-            let liaison = T::StorageProviderHelper::get_random_storage_provider()?;
+            let quota_owners = Self::quota_owners(member_id, channel_id);
+            for owner in quota_owners.iter() {
+                Self::quota(owner).ensure_can_fulfill::<T>(size)?;
+            }
+
+            let upload_fee = Self::upload_fee(size);
+            ensure!(<T as GovernanceCurrency>::Currency::can_slash(&account_id, upload_fee),
+                Error::<T>::InsufficientBalanceForUploadFee);
+
+            let liaison = T::StorageProviderHelper::get_random_storage_provider(size)?;
 
             // Let's create the entry then
             let data: DataObject<T> = DataObjectInternal {
@@ -236,13 +521,44 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            let _ = <T as GovernanceCurrency>::Currency::slash(&account_id, upload_fee);
+
+            for owner in quota_owners.iter() {
+                Self::fulfill_quota(owner, size);
+            }
+
+            if let Some(channel_id) = channel_id {
+                <ChannelIdByContentId<T>>::insert(&content_id, channel_id);
+            }
+
             <DataObjectByContentId<T>>::insert(&content_id, data);
+            Self::reserve_storage_space(&liaison, size);
             Self::deposit_event(RawEvent::ContentAdded(content_id, member_id));
         }
 
+        /// Removes the content from the system. Member id should match its origin and the content
+        /// owner. Storage relationships of the content are removed as well.
+        #[weight = <T as Trait>::WeightInfo::remove_content()]
+        pub fn remove_content(origin, member_id: MemberId<T>, content_id: T::ContentId) {
+            T::MemberOriginValidator::ensure_actor_origin(
+                origin,
+                member_id,
+            )?;
+
+            let data = Self::data_object_by_content_id(&content_id).ok_or(Error::<T>::CidNotFound)?;
+
+            ensure!(data.owner == member_id, Error::<T>::OwnerRequired);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::delete_content(content_id, data);
+        }
+
         /// Storage provider accepts a content. Requires signed storage provider account and its id.
         /// The LiaisonJudgement can be updated, but only by the liaison.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::accept_content()]
         pub(crate) fn accept_content(
             origin,
             storage_provider_id: StorageProviderId<T>,
@@ -254,14 +570,16 @@ decl_module! {
 
             Self::update_content_judgement(&storage_provider_id, content_id, LiaisonJudgement::Accepted)?;
 
-            <KnownContentIds<T>>::mutate(|ids| ids.push(content_id));
+            Self::index_known_content_id(content_id);
+
+            T::ContentAcceptanceHandler::content_accepted(&content_id);
 
             Self::deposit_event(RawEvent::ContentAccepted(content_id, storage_provider_id));
         }
 
         /// Storage provider rejects a content. Requires signed storage provider account and its id.
         /// The LiaisonJudgement can be updated, but only by the liaison.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::reject_content()]
         pub(crate) fn reject_content(
             origin,
             storage_provider_id: StorageProviderId<T>,
@@ -275,28 +593,95 @@ decl_module! {
             Self::deposit_event(RawEvent::ContentRejected(content_id, storage_provider_id));
         }
 
+        /// Storage provider declares its storage capacity in bytes. Requires signed storage
+        /// provider account and its id. Liaisons for the new content are chosen among the storage
+        /// providers with enough free space, or among the ones with undeclared capacity if there
+        /// are none.
+        #[weight = <T as Trait>::WeightInfo::set_storage_capacity()]
+        pub(crate) fn set_storage_capacity(
+            origin,
+            storage_provider_id: StorageProviderId<T>,
+            capacity: u64
+        ) {
+            <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
+
+            // == MUTATION SAFE ==
+
+            <StorageCapacityByStorageProviderId<T>>::mutate(&storage_provider_id, |storage_capacity| {
+                storage_capacity.capacity = Some(capacity);
+            });
+
+            Self::deposit_event(RawEvent::StorageCapacityUpdated(storage_provider_id, capacity));
+        }
+
+        /// Removes any content from the system. Requires the storage working group lead origin.
+        /// Storage relationships of the content are removed as well.
+        #[weight = <T as Trait>::WeightInfo::remove_content_as_lead()]
+        pub(crate) fn remove_content_as_lead(origin, content_id: T::ContentId) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            let data = Self::data_object_by_content_id(&content_id).ok_or(Error::<T>::CidNotFound)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::delete_content(content_id, data);
+        }
+
+        /// Updates the storage quota limits of the member or the channel. Requires the storage
+        /// working group lead origin. Content objects already counted against the quota are kept.
+        #[weight = <T as Trait>::WeightInfo::update_quota_limits()]
+        pub(crate) fn update_quota_limits(
+            origin,
+            owner: StorageObjectOwnerOf<T>,
+            objects_limit: u64,
+            size_limit: u64
+        ) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            // == MUTATION SAFE ==
+
+            let mut quota = Self::quota(&owner);
+            quota.objects_limit = objects_limit;
+            quota.size_limit = size_limit;
+            <QuotaByOwner<T>>::insert(&owner, quota);
+
+            Self::deposit_event(RawEvent::QuotaLimitsUpdated(owner, objects_limit, size_limit));
+        }
+
         // Sudo methods
 
+        /// Sets the fee charged per byte of the uploaded content. Zero fee disables the charge.
+        /// Requires root privileges.
+        #[weight = <T as Trait>::WeightInfo::set_upload_fee_per_byte()]
+        fn set_upload_fee_per_byte(origin, upload_fee_per_byte: BalanceOf<T>) {
+            ensure_root(origin)?;
+
+            // == MUTATION SAFE ==
+
+            <UploadFeePerByte<T>>::put(upload_fee_per_byte);
+
+            Self::deposit_event(RawEvent::UploadFeePerByteUpdated(upload_fee_per_byte));
+        }
+
         /// Removes the content id from the list of known content ids. Requires root privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::remove_known_content_id()]
         fn remove_known_content_id(origin, content_id: T::ContentId) {
             ensure_root(origin)?;
 
             // == MUTATION SAFE ==
 
-            let upd_content_ids: Vec<T::ContentId> = Self::known_content_ids()
-                .into_iter()
-                .filter(|&id| id != content_id)
-                .collect();
-            <KnownContentIds<T>>::put(upd_content_ids);
+            Self::unindex_known_content_id(content_id);
         }
 
         /// Injects a set of data objects and their corresponding content id into the directory.
         /// The operation is "silent" - no events will be emitted as objects are added.
         /// The number of objects that can be added per call is limited to prevent the dispatch
         /// from causing the block production to fail if it takes too much time to process.
-        /// Existing data objects will be overwritten.
-        #[weight = 10_000_000] // TODO: adjust weight
+        /// Existing data objects will be overwritten. Injected objects are counted against the
+        /// member quotas of their owners and the storage space of their liaisons without limits.
+        #[weight = <T as Trait>::WeightInfo::inject_data_objects(objects.len() as u32)]
         pub(crate) fn inject_data_objects(origin, objects: DataObjectsMap<T>) {
             ensure_root(origin)?;
 
@@ -304,22 +689,128 @@ decl_module! {
             ensure!(objects.len() <= T::MaxObjectsPerInjection::get() as usize, Error::<T>::DataObjectsInjectionExceededLimit);
 
             for (id, object) in objects.into_iter() {
-                // append to known content ids
-                // duplicates will be removed at the end
-                <KnownContentIds<T>>::mutate(|ids| ids.push(id));
+                // the overwritten object doesn't take the quotas and the storage space anymore
+                if let Some(data) = Self::data_object_by_content_id(&id) {
+                    Self::release_content_resources(id, &data);
+                }
+
+                Self::fulfill_quota(&StorageObjectOwner::Member(object.owner), object.size);
+                if object.liaison_judgement != LiaisonJudgement::Rejected {
+                    Self::reserve_storage_space(&object.liaison, object.size);
+                }
+
+                // already known content ids are skipped
+                Self::index_known_content_id(id);
                 <DataObjectByContentId<T>>::insert(id, object);
             }
-
-            // remove duplicate ids
-            <KnownContentIds<T>>::mutate(|ids| {
-                ids.sort();
-                ids.dedup();
-            });
         }
     }
 }
 
 impl<T: Trait> Module<T> {
+    /// Returns up to `limit` content ids known to the system starting from the `start` position
+    /// of the index. The page size is bounded by `MAX_KNOWN_CONTENT_IDS_PAGE_SIZE`.
+    pub fn known_content_ids(start: u64, limit: u64) -> Vec<T::ContentId> {
+        let end = start
+            .saturating_add(limit.min(MAX_KNOWN_CONTENT_IDS_PAGE_SIZE))
+            .min(Self::known_content_ids_count());
+
+        (start..end).map(<KnownContentIdByIndex<T>>::get).collect()
+    }
+
+    /// Returns the storage quota of the owner. Owners without the stored quota get the default one.
+    pub fn quota(owner: &StorageObjectOwnerOf<T>) -> Quota {
+        Self::quota_by_owner(owner).unwrap_or_else(|| {
+            Quota::new(
+                T::DefaultQuotaObjectsLimit::get(),
+                T::DefaultQuotaSizeLimit::get(),
+            )
+        })
+    }
+
+    /// Returns the fee charged for the upload of the content of the given size in bytes.
+    pub fn upload_fee(size: u64) -> BalanceOf<T> {
+        Self::upload_fee_per_byte().saturating_mul(size.saturated_into())
+    }
+
+    /// Verifies that the content id is known to the system.
+    pub fn is_known_content_id(content_id: &T::ContentId) -> bool {
+        <KnownContentIdIndex<T>>::contains_key(content_id)
+    }
+
+    /// Moves the content ids from the legacy `KnownContentIds` list to the index.
+    /// Returns the weight of the migration.
+    pub fn migrate_known_content_ids() -> Weight {
+        let known_content_ids: Vec<T::ContentId> =
+            migration::take_storage_value(b"DataDirectory", b"KnownContentIds", &[])
+                .unwrap_or_default();
+
+        let migrated_ids_count = known_content_ids.len() as Weight;
+
+        for content_id in known_content_ids {
+            Self::index_known_content_id(content_id);
+        }
+
+        T::DbWeight::get().reads_writes(
+            1 + migrated_ids_count.saturating_mul(2),
+            1 + migrated_ids_count.saturating_mul(3),
+        )
+    }
+
+    // Appends the content id to the index, unless it is already known.
+    fn index_known_content_id(content_id: T::ContentId) {
+        if Self::is_known_content_id(&content_id) {
+            return;
+        }
+
+        let index = Self::known_content_ids_count();
+
+        <KnownContentIdByIndex<T>>::insert(index, content_id);
+        <KnownContentIdIndex<T>>::insert(content_id, index);
+        KnownContentIdsCount::put(index + 1);
+    }
+
+    // Removes the content id from the index. The last content id of the index takes its position.
+    fn unindex_known_content_id(content_id: T::ContentId) {
+        if let Some(index) = <KnownContentIdIndex<T>>::take(content_id) {
+            let last_index = Self::known_content_ids_count().saturating_sub(1);
+            let last_content_id = <KnownContentIdByIndex<T>>::take(last_index);
+
+            if index != last_index {
+                <KnownContentIdByIndex<T>>::insert(index, last_content_id);
+                <KnownContentIdIndex<T>>::insert(last_content_id, index);
+            }
+
+            KnownContentIdsCount::put(last_index);
+        }
+    }
+
+    // Owners of the storage quotas the content is counted against.
+    fn quota_owners(
+        member_id: MemberId<T>,
+        channel_id: Option<T::ChannelId>,
+    ) -> Vec<StorageObjectOwnerOf<T>> {
+        let mut owners = vec![StorageObjectOwner::Member(member_id)];
+
+        if let Some(channel_id) = channel_id {
+            owners.push(StorageObjectOwner::Channel(channel_id));
+        }
+
+        owners
+    }
+
+    fn fulfill_quota(owner: &StorageObjectOwnerOf<T>, size: u64) {
+        let mut quota = Self::quota(owner);
+        quota.fulfill(size);
+        <QuotaByOwner<T>>::insert(owner, quota);
+    }
+
+    fn release_quota(owner: &StorageObjectOwnerOf<T>, size: u64) {
+        let mut quota = Self::quota(owner);
+        quota.release(size);
+        <QuotaByOwner<T>>::insert(owner, quota);
+    }
+
     fn update_content_judgement(
         storage_provider_id: &StorageProviderId<T>,
         content_id: T::ContentId,
@@ -334,17 +825,93 @@ impl<T: Trait> Module<T> {
             Error::<T>::LiaisonRequired
         );
 
+        // Rejected content doesn't take the storage space of its liaison.
+        let was_rejected = data.liaison_judgement == LiaisonJudgement::Rejected;
+        let is_rejected = judgement == LiaisonJudgement::Rejected;
+
+        if !was_rejected && is_rejected {
+            Self::release_storage_space(storage_provider_id, data.size);
+        } else if was_rejected && !is_rejected {
+            Self::reserve_storage_space(storage_provider_id, data.size);
+        }
+
         data.liaison_judgement = judgement;
         <DataObjectByContentId<T>>::insert(content_id, data);
 
         Ok(())
     }
+
+    // Removes the data object with its known content id, frees the storage space of its liaison
+    // and the storage quotas of its owners and cleans up the related data.
+    fn delete_content(content_id: T::ContentId, data: DataObject<T>) {
+        <DataObjectByContentId<T>>::remove(&content_id);
+        Self::unindex_known_content_id(content_id);
+        Self::release_content_resources(content_id, &data);
+
+        T::ContentRemovalHandler::content_removed(&content_id);
+
+        Self::deposit_event(RawEvent::ContentRemoved(content_id, data.ipfs_content_id));
+    }
+
+    // Frees the storage quotas of the content owners and the storage space of its liaison.
+    fn release_content_resources(content_id: T::ContentId, data: &DataObject<T>) {
+        let channel_id = <ChannelIdByContentId<T>>::take(&content_id);
+        for owner in Self::quota_owners(data.owner, channel_id).iter() {
+            Self::release_quota(owner, data.size);
+        }
+
+        if data.liaison_judgement != LiaisonJudgement::Rejected {
+            Self::release_storage_space(&data.liaison, data.size);
+        }
+    }
+
+    // Takes the storage space of the content from the storage provider.
+    fn reserve_storage_space(storage_provider_id: &StorageProviderId<T>, size: u64) {
+        <StorageCapacityByStorageProviderId<T>>::mutate(storage_provider_id, |storage_capacity| {
+            storage_capacity.used = storage_capacity.used.saturating_add(size);
+        });
+    }
+
+    // Returns the storage space of the content to the storage provider.
+    fn release_storage_space(storage_provider_id: &StorageProviderId<T>, size: u64) {
+        <StorageCapacityByStorageProviderId<T>>::mutate(storage_provider_id, |storage_capacity| {
+            storage_capacity.used = storage_capacity.used.saturating_sub(size);
+        });
+    }
 }
 
 /// Provides random storage provider id. We use it when assign the content to the storage provider.
 pub trait StorageProviderHelper<T: Trait> {
-    /// Provides random storage provider id.
-    fn get_random_storage_provider() -> Result<StorageProviderId<T>, &'static str>;
+    /// Provides random storage provider id with enough free space for the content of the given
+    /// size in bytes.
+    fn get_random_storage_provider(content_size: u64)
+        -> Result<StorageProviderId<T>, &'static str>;
+}
+
+/// Validates the channel ownership of the member.
+pub trait ChannelOwnershipValidator<T: Trait> {
+    /// Verifies that the member owns the channel.
+    fn is_channel_owner(member_id: &MemberId<T>, channel_id: &T::ChannelId) -> bool;
+}
+
+/// Cleans up the data related to the removed content.
+pub trait ContentRemovalHandler<T: Trait> {
+    /// Handles the removal of the content with the provided id.
+    fn content_removed(id: &T::ContentId);
+}
+
+impl<T: Trait> ContentRemovalHandler<T> for () {
+    fn content_removed(_id: &T::ContentId) {}
+}
+
+/// Handles the content accepted by its liaison.
+pub trait ContentAcceptanceHandler<T: Trait> {
+    /// Handles the acceptance of the content with the provided id.
+    fn content_accepted(id: &T::ContentId);
+}
+
+impl<T: Trait> ContentAcceptanceHandler<T> for () {
+    fn content_accepted(_id: &T::ContentId) {}
 }
 
 /// Content access helper.
@@ -12,6 +12,29 @@
 //! - [add_relationship](./struct.Module.html#method.add_relationship) - Add storage provider-to-content relationship.
 //! - [set_relationship_ready](./struct.Module.html#method.set_relationship_ready)- Activates storage provider-to-content relationship.
 //! - [unset_relationship_ready](./struct.Module.html#method.unset_relationship_ready) - Deactivates storage provider-to-content relationship.
+//! - [set_target_replication_factor](./struct.Module.html#method.set_target_replication_factor) - Sets the number of the ready replicas expected for the content. Requires leader.
+//! - [answer_storage_challenge](./struct.Module.html#method.answer_storage_challenge) - Answers the storage challenge with the hash of the challenged byte range.
+//! - [reject_storage_challenge_response](./struct.Module.html#method.reject_storage_challenge_response) - Rejects the wrong storage challenge response. Requires leader.
+//!
+//! Relationships of the content are removed together with the content in the data directory module.
+//! Ready relationships of the storage provider leaving the storage working group are deactivated.
+//!
+//! ## Replication
+//!
+//! The storage provider can have a single relationship per content, so the module counts ready
+//! relationships of the content as its replicas. The accepted content with less ready replicas than
+//! the target replication factor (or the minimal replication factor of its data object type) is
+//! listed as under-replicated, so the storage providers can pick it up.
+//!
+//! ## Storage challenges
+//!
+//! Every storage challenge period the module picks random ready relationships and challenges their
+//! storage providers to answer with the hash of a random byte range of the content before the
+//! deadline. The leader can reject the wrong response before the deadline. Unanswered and rejected
+//! challenges are recorded as failed against the storage provider.
+//!
+//! The runtime doesn't have the content, so the responses are not verified on-chain: the leader
+//! is expected to verify them off-chain against the stored content.
 //!
 
 // Clippy linter requirement.
@@ -24,15 +47,28 @@
 
 use codec::{Codec, Decode, Encode};
 use frame_support::dispatch::DispatchResult;
+use frame_support::traits::{Get, Randomness};
+use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
-use sp_arithmetic::traits::BaseArithmetic;
+use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
 use sp_runtime::traits::{MaybeSerialize, Member};
+use sp_runtime::SaturatedConversion;
 use sp_std::vec::Vec;
 
-use crate::data_directory::{self, ContentIdExists};
+use crate::data_directory::{
+    self, ContentAcceptanceHandler, ContentIdExists, ContentRemovalHandler,
+};
+use crate::data_object_type_registry::DataObjectTypeProvider;
 use crate::{StorageProviderId, StorageWorkingGroup, StorageWorkingGroupInstance};
 
 const DEFAULT_FIRST_RELATIONSHIP_ID: u8 = 1;
+const DEFAULT_TARGET_REPLICATION_FACTOR: u32 = 1;
+
+// Version of the storage layout. Increased with every migration added to the `migrate()`.
+const STORAGE_VERSION: u32 = 1;
+
+/// Maximum number of the challenged bytes of the content.
+pub const STORAGE_CHALLENGE_RANGE_LENGTH: u64 = 256 * 1024;
 
 /// The _Data object storage registry_ main _Trait_.
 pub trait Trait:
@@ -56,6 +92,57 @@ pub trait Trait:
 
     /// Ensures that a content exists
     type ContentIdExists: data_directory::ContentIdExists<Self>;
+
+    /// Source of the randomness for the storage challenges.
+    type Randomness: Randomness<Self::Hash>;
+
+    /// Number of blocks between the storage challenge rounds. Zero disables the challenges.
+    type StorageChallengePeriod: Get<Self::BlockNumber>;
+
+    /// Number of blocks the storage provider has to answer the storage challenge. Zero is treated
+    /// as a single block.
+    type StorageChallengeResponsePeriod: Get<Self::BlockNumber>;
+
+    /// Maximum number of the storage challenges issued per round.
+    type MaxStorageChallengesPerRound: Get<u32>;
+
+    /// Handles the failed storage challenges.
+    type StorageChallengeFailureHandler: StorageChallengeFailureHandler<Self>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+}
+
+/// Weight functions needed for the data object storage registry module.
+pub trait WeightInfo {
+    fn add_relationship() -> Weight;
+    fn set_relationship_ready() -> Weight;
+    fn unset_relationship_ready() -> Weight;
+    fn set_target_replication_factor() -> Weight;
+    fn answer_storage_challenge() -> Weight;
+    fn reject_storage_challenge_response() -> Weight;
+}
+
+/// Flat weights for the test runtimes.
+impl WeightInfo for () {
+    fn add_relationship() -> Weight {
+        10_000_000
+    }
+    fn set_relationship_ready() -> Weight {
+        10_000_000
+    }
+    fn unset_relationship_ready() -> Weight {
+        10_000_000
+    }
+    fn set_target_replication_factor() -> Weight {
+        10_000_000
+    }
+    fn answer_storage_challenge() -> Weight {
+        10_000_000
+    }
+    fn reject_storage_challenge_response() -> Weight {
+        10_000_000
+    }
 }
 
 decl_error! {
@@ -72,6 +159,21 @@ decl_error! {
 
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// No storage challenge found for this ID.
+        StorageChallengeNotFound,
+
+        /// Only the challenged storage provider may answer the storage challenge.
+        OnlyChallengedStorageProviderMayAnswer,
+
+        /// Storage challenge is already answered.
+        StorageChallengeAlreadyAnswered,
+
+        /// Storage challenge is not answered yet.
+        StorageChallengeNotAnswered,
+
+        /// Storage provider already has a relationship with this content.
+        DataObjectStorageRelationshipAlreadyExists,
     }
 }
 
@@ -88,6 +190,31 @@ pub struct DataObjectStorageRelationship<T: Trait> {
     pub ready: bool,
 }
 
+/// Storage challenge id.
+pub type StorageChallengeId = u64;
+
+/// Challenge of the storage provider to prove it still stores the content.
+#[derive(Clone, Encode, Decode, PartialEq, Debug)]
+pub struct StorageChallenge<T: Trait> {
+    /// Content id.
+    pub content_id: <T as data_directory::Trait>::ContentId,
+
+    /// Challenged storage provider id.
+    pub storage_provider_id: StorageProviderId<T>,
+
+    /// Offset of the first challenged byte of the content.
+    pub range_start: u64,
+
+    /// Number of the challenged bytes.
+    pub range_length: u64,
+
+    /// Block the challenge should be answered before.
+    pub deadline: T::BlockNumber,
+
+    /// Hash of the challenged byte range provided by the storage provider.
+    pub response: Option<T::Hash>,
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as DataObjectStorageRegistry {
 
@@ -105,6 +232,45 @@ decl_storage! {
         /// Keeps a list of storage relationships per content id.
         pub RelationshipsByContentId get(fn relationships_by_content_id): map hasher(blake2_128_concat)
             T::ContentId => Vec<T::DataObjectStorageRelationshipId>;
+
+        /// Keeps a list of storage relationships per storage provider id.
+        pub RelationshipsByStorageProvider get(fn relationships_by_storage_provider):
+            map hasher(blake2_128_concat) StorageProviderId<T> => Vec<T::DataObjectStorageRelationshipId>;
+
+        /// Relationship of the storage provider with the content.
+        pub RelationshipIdByStorageProviderAndContentId
+            get(fn relationship_id_by_storage_provider_and_content_id): double_map
+            hasher(blake2_128_concat) StorageProviderId<T>, hasher(blake2_128_concat) T::ContentId
+            => Option<T::DataObjectStorageRelationshipId>;
+
+        /// Number of the ready storage relationships per content id.
+        pub ReadyReplicasByContentId get(fn ready_replicas_by_content_id): map hasher(blake2_128_concat)
+            T::ContentId => u32;
+
+        /// Number of the ready replicas expected for the content.
+        pub TargetReplicationFactor get(fn target_replication_factor): u32 = DEFAULT_TARGET_REPLICATION_FACTOR;
+
+        /// Under-replicated content ids with the block they became under-replicated at.
+        pub UnderReplicatedContent get(fn under_replicated_content): map hasher(blake2_128_concat)
+            T::ContentId => Option<T::BlockNumber>;
+
+        /// Defines next storage challenge id.
+        pub NextStorageChallengeId get(fn next_storage_challenge_id): StorageChallengeId;
+
+        /// Pending storage challenges.
+        pub StorageChallenges get(fn storage_challenges): map hasher(twox_64_concat)
+            StorageChallengeId => Option<StorageChallenge<T>>;
+
+        /// Storage challenge ids by their deadlines.
+        pub StorageChallengeIdsByDeadline get(fn storage_challenge_ids_by_deadline):
+            map hasher(twox_64_concat) T::BlockNumber => Vec<StorageChallengeId>;
+
+        /// Number of the failed storage challenges per storage provider.
+        pub FailedStorageChallenges get(fn failed_storage_challenges): map hasher(blake2_128_concat)
+            StorageProviderId<T> => u32;
+
+        /// Version of the storage layout. New chains start with the current version.
+        pub StorageVersion get(fn storage_version) build(|_: &GenesisConfig<T>| STORAGE_VERSION): u32;
     }
 }
 
@@ -113,6 +279,7 @@ decl_event! {
     pub enum Event<T> where
         <T as data_directory::Trait>::ContentId,
         <T as Trait>::DataObjectStorageRelationshipId,
+        <T as system::Trait>::Hash,
         StorageProviderId = StorageProviderId<T>
     {
         /// Emits on adding of the data object storage relationship.
@@ -127,6 +294,50 @@ decl_event! {
         /// - Id of the relationship.
         /// - Current state of the relationship (True=Active).
         DataObjectStorageRelationshipReadyUpdated(DataObjectStorageRelationshipId, bool),
+
+        /// Emits on removing of the data object storage relationship with its content.
+        /// Params:
+        /// - Id of the relationship.
+        /// - Id of the content.
+        /// - Id of the storage provider.
+        DataObjectStorageRelationshipRemoved(DataObjectStorageRelationshipId, ContentId, StorageProviderId),
+
+        /// Emits when the content gets less ready replicas than expected.
+        /// Params:
+        /// - Id of the content.
+        /// - Number of the ready replicas.
+        /// - Number of the expected replicas.
+        ContentUnderReplicated(ContentId, u32, u32),
+
+        /// Emits when the under-replicated content gets the expected number of ready replicas.
+        /// Params:
+        /// - Id of the content.
+        /// - Number of the ready replicas.
+        ContentReplicated(ContentId, u32),
+
+        /// Emits on the target replication factor update.
+        /// Params:
+        /// - Number of the ready replicas expected for the content.
+        TargetReplicationFactorUpdated(u32),
+
+        /// Emits when the storage provider gets challenged to prove it stores the content.
+        /// Params:
+        /// - Id of the storage challenge.
+        /// - Id of the content.
+        /// - Id of the storage provider.
+        StorageChallengeIssued(StorageChallengeId, ContentId, StorageProviderId),
+
+        /// Emits when the storage provider answers the storage challenge.
+        /// Params:
+        /// - Id of the storage challenge.
+        /// - Hash of the challenged byte range.
+        StorageChallengeAnswered(StorageChallengeId, Hash),
+
+        /// Emits when the storage provider fails the storage challenge.
+        /// Params:
+        /// - Id of the storage challenge.
+        /// - Id of the storage provider.
+        StorageChallengeFailed(StorageChallengeId, StorageProviderId),
     }
 }
 
@@ -139,9 +350,30 @@ decl_module! {
         /// Predefined errors.
         type Error = Error<T>;
 
+        /// Number of blocks between the storage challenge rounds.
+        const StorageChallengePeriod: T::BlockNumber = T::StorageChallengePeriod::get();
+
+        /// Number of blocks the storage provider has to answer the storage challenge.
+        const StorageChallengeResponsePeriod: T::BlockNumber =
+            T::StorageChallengeResponsePeriod::get();
+
+        /// Maximum number of the storage challenges issued per round.
+        const MaxStorageChallengesPerRound: u32 = T::MaxStorageChallengesPerRound::get();
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let mut weight = Self::expire_storage_challenges(now);
+
+            let challenge_period = T::StorageChallengePeriod::get();
+            if !challenge_period.is_zero() && (now % challenge_period).is_zero() {
+                weight += Self::issue_storage_challenges(now);
+            }
+
+            weight
+        }
+
         /// Add storage provider-to-content relationship. The storage provider should be registered
-        /// in the storage working group.
-        #[weight = 10_000_000] // TODO: adjust weight
+        /// in the storage working group and can have a single relationship per content.
+        #[weight = <T as Trait>::WeightInfo::add_relationship()]
         pub fn add_relationship(origin, storage_provider_id: StorageProviderId<T>, cid: T::ContentId) {
             // Origin should match storage provider.
             <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
@@ -149,6 +381,11 @@ decl_module! {
             // Content ID must exist
             ensure!(T::ContentIdExists::has_content(&cid), Error::<T>::CidNotFound);
 
+            ensure!(
+                !<RelationshipIdByStorageProviderAndContentId<T>>::contains_key(storage_provider_id, cid),
+                Error::<T>::DataObjectStorageRelationshipAlreadyExists
+            );
+
             // Create new ID, data.
             let new_id = Self::next_relationship_id();
             let dosr: DataObjectStorageRelationship<T> = DataObjectStorageRelationship {
@@ -171,6 +408,10 @@ decl_module! {
             let mut dosr_list = Self::relationships_by_content_id(cid);
             dosr_list.push(new_id);
             <RelationshipsByContentId<T>>::insert(cid, dosr_list);
+            <RelationshipsByStorageProvider<T>>::mutate(storage_provider_id, |ids| ids.push(new_id));
+            <RelationshipIdByStorageProviderAndContentId<T>>::insert(storage_provider_id, cid, new_id);
+
+            Self::update_replication_status(cid);
 
             // Emit event
             Self::deposit_event(
@@ -180,7 +421,7 @@ decl_module! {
 
         /// Activates storage provider-to-content relationship. The storage provider should be registered
         /// in the storage working group. A storage provider may flip their own ready state, but nobody else.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_relationship_ready()]
         pub fn set_relationship_ready(
             origin,
             storage_provider_id: StorageProviderId<T>,
@@ -191,7 +432,7 @@ decl_module! {
 
         /// Deactivates storage provider-to-content relationship. The storage provider should be registered
         /// in the storage working group. A storage provider may flip their own ready state, but nobody else.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::unset_relationship_ready()]
         pub fn unset_relationship_ready(
             origin,
             storage_provider_id: StorageProviderId<T>,
@@ -199,10 +440,213 @@ decl_module! {
         ) {
             Self::toggle_dosr_ready(origin, storage_provider_id, id, false)?;
         }
+
+        /// Sets the number of the ready replicas expected for the content. Requires leader
+        /// privileges. Replication status of the content is updated on its next relationship change.
+        #[weight = <T as Trait>::WeightInfo::set_target_replication_factor()]
+        pub fn set_target_replication_factor(origin, target_replication_factor: u32) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            TargetReplicationFactor::put(target_replication_factor);
+
+            Self::deposit_event(RawEvent::TargetReplicationFactorUpdated(target_replication_factor));
+        }
+
+        /// Answers the storage challenge with the hash of the challenged byte range of the content.
+        /// The storage provider should be registered in the storage working group and challenged.
+        #[weight = <T as Trait>::WeightInfo::answer_storage_challenge()]
+        pub fn answer_storage_challenge(
+            origin,
+            storage_provider_id: StorageProviderId<T>,
+            challenge_id: StorageChallengeId,
+            range_hash: T::Hash
+        ) {
+            <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
+
+            let mut challenge = Self::ensure_storage_challenge_exists(challenge_id)?;
+
+            ensure!(
+                challenge.storage_provider_id == storage_provider_id,
+                Error::<T>::OnlyChallengedStorageProviderMayAnswer
+            );
+
+            ensure!(challenge.response.is_none(), Error::<T>::StorageChallengeAlreadyAnswered);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            challenge.response = Some(range_hash);
+            <StorageChallenges<T>>::insert(challenge_id, challenge);
+
+            Self::deposit_event(RawEvent::StorageChallengeAnswered(challenge_id, range_hash));
+        }
+
+        /// Rejects the wrong storage challenge response. Requires leader privileges. The storage
+        /// challenge is recorded as failed.
+        #[weight = <T as Trait>::WeightInfo::reject_storage_challenge_response()]
+        pub fn reject_storage_challenge_response(origin, challenge_id: StorageChallengeId) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            let challenge = Self::ensure_storage_challenge_exists(challenge_id)?;
+
+            ensure!(challenge.response.is_some(), Error::<T>::StorageChallengeNotAnswered);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <StorageChallenges<T>>::remove(challenge_id);
+
+            Self::fail_storage_challenge(challenge_id, challenge.storage_provider_id);
+        }
     }
 }
 
 impl<T: Trait> Module<T> {
+    /// Migrates the storage to the current layout version. Runs only the migrations
+    /// the storage has not passed yet. Returns the weight of the migration.
+    pub fn migrate() -> Weight {
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+        if Self::storage_version() < 1 {
+            weight = weight.saturating_add(Self::migrate_replicas());
+        }
+
+        <StorageVersion>::put(STORAGE_VERSION);
+
+        weight
+    }
+
+    // Indexes the relationships by their storage providers, removes the duplicate relationships
+    // of the same storage provider and content, counts the ready relationships of the content and
+    // lists the under-replicated content. The ready state of a removed duplicate is kept by the
+    // first relationship. The registry holds a relationship per stored replica, thousands at most,
+    // so all of them are migrated at once.
+    fn migrate_replicas() -> Weight {
+        let mut content_count: Weight = 0;
+        let mut relationships_count: Weight = 0;
+
+        let relationships_by_content_id: Vec<_> = <RelationshipsByContentId<T>>::iter().collect();
+
+        for (content_id, ids) in relationships_by_content_id {
+            let mut kept_ids = Vec::new();
+
+            for id in ids {
+                relationships_count += 1;
+
+                let dosr = match Self::relationships(id) {
+                    Some(dosr) => dosr,
+                    None => continue,
+                };
+
+                let storage_provider_id = dosr.storage_provider_id;
+
+                match Self::relationship_id_by_storage_provider_and_content_id(
+                    storage_provider_id,
+                    content_id,
+                ) {
+                    Some(kept_id) => {
+                        <Relationships<T>>::remove(id);
+
+                        if dosr.ready {
+                            <Relationships<T>>::mutate(kept_id, |kept_dosr| {
+                                if let Some(kept_dosr) = kept_dosr {
+                                    kept_dosr.ready = true;
+                                }
+                            });
+                        }
+
+                        Self::deposit_event(RawEvent::DataObjectStorageRelationshipRemoved(
+                            id,
+                            content_id,
+                            storage_provider_id,
+                        ));
+                    }
+                    None => {
+                        <RelationshipsByStorageProvider<T>>::mutate(storage_provider_id, |ids| {
+                            ids.push(id)
+                        });
+                        <RelationshipIdByStorageProviderAndContentId<T>>::insert(
+                            storage_provider_id,
+                            content_id,
+                            id,
+                        );
+
+                        kept_ids.push(id);
+                    }
+                }
+            }
+
+            let ready_replicas = kept_ids
+                .iter()
+                .filter_map(|id| Self::relationships(id))
+                .filter(|dosr| dosr.ready)
+                .count() as u32;
+
+            <RelationshipsByContentId<T>>::insert(content_id, kept_ids);
+            <ReadyReplicasByContentId<T>>::insert(content_id, ready_replicas);
+            Self::update_replication_status(content_id);
+
+            content_count += 1;
+        }
+
+        T::DbWeight::get().reads_writes(
+            content_count
+                .saturating_mul(5)
+                .saturating_add(relationships_count.saturating_mul(4)),
+            content_count
+                .saturating_mul(3)
+                .saturating_add(relationships_count.saturating_mul(3)),
+        )
+    }
+
+    /// Deactivates the ready relationships of the storage provider and updates the replication
+    /// status of their content. Invoked when the storage provider leaves the storage working group.
+    pub fn deactivate_storage_provider_relationships(storage_provider_id: &StorageProviderId<T>) {
+        for id in Self::relationships_by_storage_provider(storage_provider_id) {
+            let mut dosr = match Self::relationships(id) {
+                Some(dosr) if dosr.ready => dosr,
+                _ => continue,
+            };
+
+            let content_id = dosr.content_id;
+            dosr.ready = false;
+            <Relationships<T>>::insert(id, dosr);
+
+            <ReadyReplicasByContentId<T>>::mutate(content_id, |ready_replicas| {
+                *ready_replicas = ready_replicas.saturating_sub(1);
+            });
+
+            Self::update_replication_status(content_id);
+
+            Self::deposit_event(RawEvent::DataObjectStorageRelationshipReadyUpdated(
+                id, false,
+            ));
+        }
+    }
+
+    /// Returns the number of the ready replicas expected for the content: the target replication
+    /// factor or the minimal replication factor of its data object type, whichever is greater.
+    pub fn content_replication_target(content_id: &T::ContentId) -> u32 {
+        let type_replication_factor = T::ContentIdExists::get_data_object(content_id)
+            .ok()
+            .and_then(|data| T::DataObjectTypeProvider::data_object_type(&data.type_id))
+            .and_then(|data_object_type| data_object_type.min_replication_factor)
+            .unwrap_or(0);
+
+        Self::target_replication_factor().max(type_replication_factor)
+    }
+
+    /// Verifies that the content has less ready replicas than expected.
+    pub fn is_under_replicated(content_id: &T::ContentId) -> bool {
+        <UnderReplicatedContent<T>>::contains_key(content_id)
+    }
+
     fn toggle_dosr_ready(
         origin: T::Origin,
         storage_provider_id: StorageProviderId<T>,
@@ -220,15 +664,224 @@ impl<T: Trait> Module<T> {
             Error::<T>::OnlyStorageProviderMayClaimReady
         );
 
+        let content_id = dosr.content_id;
+        let ready_changed = dosr.ready != ready;
+
         // Flip to ready
         dosr.ready = ready;
 
-        // Update DOSR and fire event.
+        // Update DOSR and the replication status of the content, then fire event.
         <Relationships<T>>::insert(id, dosr);
+
+        if ready_changed {
+            <ReadyReplicasByContentId<T>>::mutate(content_id, |ready_replicas| {
+                *ready_replicas = if ready {
+                    ready_replicas.saturating_add(1)
+                } else {
+                    ready_replicas.saturating_sub(1)
+                };
+            });
+
+            Self::update_replication_status(content_id);
+        }
+
         Self::deposit_event(RawEvent::DataObjectStorageRelationshipReadyUpdated(
             id, ready,
         ));
 
         Ok(())
     }
+
+    // Number of blocks the storage provider has to answer the storage challenge, at least one.
+    fn storage_challenge_response_period() -> T::BlockNumber {
+        T::StorageChallengeResponsePeriod::get().max(One::one())
+    }
+
+    fn ensure_storage_challenge_exists(
+        challenge_id: StorageChallengeId,
+    ) -> Result<StorageChallenge<T>, Error<T>> {
+        Self::storage_challenges(challenge_id).ok_or(Error::<T>::StorageChallengeNotFound)
+    }
+
+    // Challenges the storage providers of the random ready relationships. Returns the weight of
+    // the round.
+    fn issue_storage_challenges(now: T::BlockNumber) -> Weight {
+        let first_id: u64 = Self::first_relationship_id().saturated_into();
+        let next_id: u64 = Self::next_relationship_id().saturated_into();
+        let relationships_count = next_id.saturating_sub(first_id);
+        let max_challenges = T::MaxStorageChallengesPerRound::get();
+
+        if relationships_count == 0 {
+            return T::DbWeight::get().reads(2);
+        }
+
+        // Challenges of the current block are already expired, so the deadline is in the future.
+        let deadline = now + Self::storage_challenge_response_period();
+
+        for index in 0..max_challenges {
+            let (relationship_seed, range_seed) = Self::random_seeds(index);
+            let relationship_id: T::DataObjectStorageRelationshipId =
+                (first_id + relationship_seed % relationships_count).saturated_into();
+
+            let dosr = match Self::relationships(relationship_id) {
+                Some(dosr) if dosr.ready => dosr,
+                _ => continue,
+            };
+
+            let size = match T::ContentIdExists::get_data_object(&dosr.content_id) {
+                Ok(data) => data.size,
+                Err(_) => continue,
+            };
+
+            let range_start = if size == 0 { 0 } else { range_seed % size };
+            let challenge_id = Self::next_storage_challenge_id();
+            let challenge = StorageChallenge::<T> {
+                content_id: dosr.content_id,
+                storage_provider_id: dosr.storage_provider_id,
+                range_start,
+                range_length: STORAGE_CHALLENGE_RANGE_LENGTH.min(size - range_start),
+                deadline,
+                response: None,
+            };
+
+            <StorageChallenges<T>>::insert(challenge_id, challenge);
+            <StorageChallengeIdsByDeadline<T>>::mutate(deadline, |ids| ids.push(challenge_id));
+            NextStorageChallengeId::put(challenge_id + 1);
+
+            Self::deposit_event(RawEvent::StorageChallengeIssued(
+                challenge_id,
+                dosr.content_id,
+                dosr.storage_provider_id,
+            ));
+        }
+
+        T::DbWeight::get().reads_writes(
+            2 + u64::from(max_challenges).saturating_mul(4),
+            u64::from(max_challenges).saturating_mul(3),
+        )
+    }
+
+    // Records the unanswered storage challenges with the deadline as failed. Challenges of the
+    // removed content are dropped. Returns the weight of the expiration.
+    fn expire_storage_challenges(now: T::BlockNumber) -> Weight {
+        let challenge_ids = <StorageChallengeIdsByDeadline<T>>::take(now);
+        let challenges_count = challenge_ids.len() as u64;
+
+        for challenge_id in challenge_ids {
+            if let Some(challenge) = <StorageChallenges<T>>::take(challenge_id) {
+                if challenge.response.is_none()
+                    && T::ContentIdExists::has_content(&challenge.content_id)
+                {
+                    Self::fail_storage_challenge(challenge_id, challenge.storage_provider_id);
+                }
+            }
+        }
+
+        T::DbWeight::get().reads_writes(
+            1 + challenges_count.saturating_mul(3),
+            1 + challenges_count.saturating_mul(2),
+        )
+    }
+
+    fn fail_storage_challenge(
+        challenge_id: StorageChallengeId,
+        storage_provider_id: StorageProviderId<T>,
+    ) {
+        <FailedStorageChallenges<T>>::mutate(storage_provider_id, |failed_challenges| {
+            *failed_challenges = failed_challenges.saturating_add(1);
+        });
+
+        T::StorageChallengeFailureHandler::storage_challenge_failed(&storage_provider_id);
+
+        Self::deposit_event(RawEvent::StorageChallengeFailed(
+            challenge_id,
+            storage_provider_id,
+        ));
+    }
+
+    // Provides two random numbers for the storage challenge with the given index in the round.
+    fn random_seeds(index: u32) -> (u64, u64) {
+        let random_hash = T::Randomness::random(&(b"storage_challenge", index).encode());
+        let mut random_bytes = random_hash.as_ref();
+
+        (
+            u64::decode(&mut random_bytes).unwrap_or_default(),
+            u64::decode(&mut random_bytes).unwrap_or_default(),
+        )
+    }
+
+    // Lists the content as under-replicated or removes it from the list depending on the number of
+    // its ready replicas. Emits the event on the status change.
+    fn update_replication_status(content_id: T::ContentId) {
+        let ready_replicas = Self::ready_replicas_by_content_id(content_id);
+        let target_replicas = Self::content_replication_target(&content_id);
+        let was_under_replicated = Self::is_under_replicated(&content_id);
+
+        if ready_replicas < target_replicas {
+            if !was_under_replicated {
+                <UnderReplicatedContent<T>>::insert(
+                    content_id,
+                    <system::Module<T>>::block_number(),
+                );
+                Self::deposit_event(RawEvent::ContentUnderReplicated(
+                    content_id,
+                    ready_replicas,
+                    target_replicas,
+                ));
+            }
+        } else if was_under_replicated {
+            <UnderReplicatedContent<T>>::remove(content_id);
+            Self::deposit_event(RawEvent::ContentReplicated(content_id, ready_replicas));
+        }
+    }
+}
+
+/// Handles the failed storage challenges of the storage providers.
+pub trait StorageChallengeFailureHandler<T: Trait> {
+    /// Handles the storage challenge failed by the storage provider.
+    fn storage_challenge_failed(storage_provider_id: &StorageProviderId<T>);
+}
+
+impl<T: Trait> StorageChallengeFailureHandler<T> for () {
+    fn storage_challenge_failed(_storage_provider_id: &StorageProviderId<T>) {}
+}
+
+impl<T: Trait> ContentRemovalHandler<T> for Module<T> {
+    fn content_removed(content_id: &T::ContentId) {
+        <ReadyReplicasByContentId<T>>::remove(content_id);
+        <UnderReplicatedContent<T>>::remove(content_id);
+
+        for id in <RelationshipsByContentId<T>>::take(content_id) {
+            if let Some(dosr) = <Relationships<T>>::take(id) {
+                <RelationshipsByStorageProvider<T>>::mutate(dosr.storage_provider_id, |ids| {
+                    ids.retain(|relationship_id| *relationship_id != id)
+                });
+                <RelationshipIdByStorageProviderAndContentId<T>>::remove(
+                    dosr.storage_provider_id,
+                    content_id,
+                );
+
+                Self::deposit_event(RawEvent::DataObjectStorageRelationshipRemoved(
+                    id,
+                    dosr.content_id,
+                    dosr.storage_provider_id,
+                ));
+            }
+        }
+    }
+}
+
+impl<T: Trait> ContentAcceptanceHandler<T> for Module<T> {
+    fn content_accepted(content_id: &T::ContentId) {
+        Self::update_replication_status(*content_id);
+    }
+}
+
+impl<T: Trait> working_group::WorkerExitHandler<T, StorageWorkingGroupInstance> for Module<T> {
+    fn worker_exited(
+        storage_provider_id: &StorageProviderId<T>,
+        _worker: &working_group::WorkerOf<T>,
+    ) {
+        Self::deactivate_storage_provider_relationships(storage_provider_id);
+    }
 }
@@ -24,6 +24,8 @@
 
 use codec::{Codec, Decode, Encode};
 use frame_support::dispatch::DispatchError;
+use frame_support::storage::migration;
+use frame_support::traits::Get;
 use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, Parameter};
 use sp_arithmetic::traits::BaseArithmetic;
@@ -35,6 +37,9 @@ use crate::{StorageWorkingGroup, StorageWorkingGroupInstance};
 const DEFAULT_TYPE_DESCRIPTION: &str = "Default data object type for audio and video content.";
 const DEFAULT_FIRST_DATA_OBJECT_TYPE_ID: u8 = 1;
 
+// Version of the storage layout. Increased with every migration added to the `migrate()`.
+const STORAGE_VERSION: u32 = 1;
+
 /// The _Data object type registry_ main _Trait_.
 pub trait Trait: system::Trait + working_group::Trait<StorageWorkingGroupInstance> {
     /// _Data object type registry_ event type.
@@ -49,6 +54,33 @@ pub trait Trait: system::Trait + working_group::Trait<StorageWorkingGroupInstanc
         + Copy
         + MaybeSerialize
         + PartialEq;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+}
+
+/// Weight functions needed for the data object type registry module.
+pub trait WeightInfo {
+    fn register_data_object_type() -> Weight;
+    fn update_data_object_type() -> Weight;
+    fn activate_data_object_type() -> Weight;
+    fn deactivate_data_object_type() -> Weight;
+}
+
+/// Flat weights for the test runtimes.
+impl WeightInfo for () {
+    fn register_data_object_type() -> Weight {
+        10_000_000
+    }
+    fn update_data_object_type() -> Weight {
+        10_000_000
+    }
+    fn activate_data_object_type() -> Weight {
+        10_000_000
+    }
+    fn deactivate_data_object_type() -> Weight {
+        10_000_000
+    }
 }
 
 decl_error! {
@@ -70,6 +102,15 @@ pub struct DataObjectType {
 
     /// Active/Disabled flag.
     pub active: bool,
+
+    /// Maximum size of the data object in bytes. No limit if not set.
+    pub max_size: Option<u64>,
+
+    /// Allowed MIME types of the data object content. Empty list allows any content type.
+    pub allowed_content_types: Vec<Vec<u8>>,
+
+    /// Minimum number of the storage providers expected to store the data object.
+    pub min_replication_factor: Option<u32>,
 }
 
 impl Default for DataObjectType {
@@ -77,10 +118,48 @@ impl Default for DataObjectType {
         DataObjectType {
             description: DEFAULT_TYPE_DESCRIPTION.as_bytes().to_vec(),
             active: true,
+            max_size: None,
+            allowed_content_types: Vec::new(),
+            min_replication_factor: None,
         }
     }
 }
 
+/// Data object type as stored before the data object constraints were introduced.
+#[derive(Decode)]
+struct LegacyDataObjectType {
+    description: Vec<u8>,
+    active: bool,
+}
+
+impl From<LegacyDataObjectType> for DataObjectType {
+    fn from(do_type: LegacyDataObjectType) -> Self {
+        DataObjectType {
+            description: do_type.description,
+            active: do_type.active,
+            max_size: None,
+            allowed_content_types: Vec::new(),
+            min_replication_factor: None,
+        }
+    }
+}
+
+impl DataObjectType {
+    /// Verifies that the data object of the given size in bytes fits the type.
+    pub fn is_size_allowed(&self, size: u64) -> bool {
+        self.max_size.map_or(true, |max_size| size <= max_size)
+    }
+
+    /// Verifies that the MIME type of the data object content is allowed by the type.
+    pub fn is_content_type_allowed(&self, content_type: &[u8]) -> bool {
+        self.allowed_content_types.is_empty()
+            || self
+                .allowed_content_types
+                .iter()
+                .any(|allowed_content_type| allowed_content_type.as_slice() == content_type)
+    }
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as DataObjectTypeRegistry {
         /// Data object type ids should start at this value.
@@ -94,6 +173,9 @@ decl_storage! {
         /// Mapping of Data object types.
         pub DataObjectTypes get(fn data_object_types): map hasher(blake2_128_concat)
             T::DataObjectTypeId => Option<DataObjectType>;
+
+        /// Version of the storage layout. New chains start with the current version.
+        pub StorageVersion get(fn storage_version) build(|_: &GenesisConfig<T>| STORAGE_VERSION): u32;
     }
 }
 
@@ -130,13 +212,15 @@ decl_module! {
 
                 <DataObjectTypes<T>>::insert(new_type_id, do_type);
                 <NextDataObjectTypeId<T>>::mutate(|n| { *n += T::DataObjectTypeId::from(1); });
+
+                return T::DbWeight::get().reads_writes(3, 2);
             }
 
-            10_000_000 //TODO: adjust weight
+            T::DbWeight::get().reads(2)
         }
 
         /// Registers the new data object type. Requires leader privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::register_data_object_type()]
         pub fn register_data_object_type(origin, data_object_type: DataObjectType) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
@@ -144,6 +228,9 @@ decl_module! {
             let do_type: DataObjectType = DataObjectType {
                 description: data_object_type.description.clone(),
                 active: data_object_type.active,
+                max_size: data_object_type.max_size,
+                allowed_content_types: data_object_type.allowed_content_types.clone(),
+                min_replication_factor: data_object_type.min_replication_factor,
             };
 
             //
@@ -157,7 +244,7 @@ decl_module! {
         }
 
         /// Updates existing data object type. Requires leader privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::update_data_object_type()]
         pub fn update_data_object_type(origin, id: T::DataObjectTypeId, data_object_type: DataObjectType) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
@@ -165,6 +252,9 @@ decl_module! {
 
             do_type.description = data_object_type.description.clone();
             do_type.active = data_object_type.active;
+            do_type.max_size = data_object_type.max_size;
+            do_type.allowed_content_types = data_object_type.allowed_content_types.clone();
+            do_type.min_replication_factor = data_object_type.min_replication_factor;
 
             //
             // == MUTATION SAFE ==
@@ -176,7 +266,7 @@ decl_module! {
         }
 
         /// Activates existing data object type. Requires leader privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::activate_data_object_type()]
         pub fn activate_data_object_type(origin, id: T::DataObjectTypeId) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
@@ -194,7 +284,7 @@ decl_module! {
         }
 
         /// Deactivates existing data object type. Requires leader privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::deactivate_data_object_type()]
         pub fn deactivate_data_object_type(origin, id: T::DataObjectTypeId) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
@@ -214,11 +304,63 @@ decl_module! {
 }
 
 impl<T: Trait> Module<T> {
+    /// Migrates the storage to the current layout version. Runs only the migrations
+    /// the storage has not passed yet. Returns the weight of the migration.
+    pub fn migrate() -> Weight {
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+        if Self::storage_version() < 1 {
+            weight = weight.saturating_add(Self::migrate_data_object_types());
+        }
+
+        <StorageVersion>::put(STORAGE_VERSION);
+
+        weight
+    }
+
+    // Re-encodes the data object types stored before the data object constraints were introduced.
+    // The registry holds a few types set by the leader, so all of them are migrated at once.
+    fn migrate_data_object_types() -> Weight {
+        let legacy_do_types = migration::StorageIterator::<LegacyDataObjectType>::new(
+            b"DataObjectTypeRegistry",
+            b"DataObjectTypes",
+        );
+
+        let mut migrated_do_types_count: Weight = 0;
+
+        for (hashed_key, legacy_do_type) in legacy_do_types {
+            let do_type: DataObjectType = legacy_do_type.into();
+
+            migration::put_storage_value(
+                b"DataObjectTypeRegistry",
+                b"DataObjectTypes",
+                &hashed_key,
+                do_type,
+            );
+
+            migrated_do_types_count += 1;
+        }
+
+        T::DbWeight::get().reads_writes(migrated_do_types_count, migrated_do_types_count)
+    }
+
     fn ensure_data_object_type(id: T::DataObjectTypeId) -> Result<DataObjectType, DispatchError> {
         Self::data_object_types(&id).ok_or_else(|| Error::<T>::DataObjectTypeNotFound.into())
     }
 }
 
+/// Data object type provider trait.
+pub trait DataObjectTypeProvider<T: Trait> {
+    /// Returns the data object type with given id.
+    fn data_object_type(id: &T::DataObjectTypeId) -> Option<DataObjectType>;
+}
+
+impl<T: Trait> DataObjectTypeProvider<T> for Module<T> {
+    fn data_object_type(id: &T::DataObjectTypeId) -> Option<DataObjectType> {
+        Self::data_object_types(id)
+    }
+}
+
 /// Active data object type validator trait.
 pub trait IsActiveDataObjectType<T: Trait> {
     /// Ensures that data object type with given id is active.
@@ -1,6 +1,9 @@
 #![cfg(test)]
 
-use frame_support::dispatch::DispatchError;
+use frame_support::dispatch::{DispatchError, UnfilteredDispatchable};
+use frame_support::storage::migration;
+use frame_support::traits::{Currency, Get};
+use frame_support::{StorageMap, StorageValue};
 use sp_std::collections::btree_map::BTreeMap;
 use system::RawOrigin;
 
@@ -15,9 +18,11 @@ fn succeed_adding_content() {
         let res = TestDataDirectory::add_content(
             Origin::signed(sender),
             member_id,
+            None,
             1,
             1234,
             0,
+            TEST_CONTENT_TYPE.to_vec(),
             vec![1, 3, 3, 7],
         );
         assert!(res.is_ok());
@@ -32,9 +37,11 @@ fn add_content_fails_with_invalid_origin() {
         let res = TestDataDirectory::add_content(
             RawOrigin::Root.into(),
             member_id,
+            None,
             1,
             1234,
             0,
+            TEST_CONTENT_TYPE.to_vec(),
             vec![1, 3, 3, 7],
         );
         assert_eq!(res, Err(DispatchError::Other("Bad origin")));
@@ -57,9 +64,11 @@ fn accept_and_reject_content_fail_with_invalid_storage_provider() {
         let res = TestDataDirectory::add_content(
             Origin::signed(sender),
             member_id,
+            None,
             1,
             1234,
             0,
+            TEST_CONTENT_TYPE.to_vec(),
             vec![1, 2, 3, 4],
         );
         assert!(res.is_ok());
@@ -107,9 +116,11 @@ fn accept_content_as_liaison() {
         let res = TestDataDirectory::add_content(
             Origin::signed(sender),
             member_id,
+            None,
             1,
             1234,
             0,
+            TEST_CONTENT_TYPE.to_vec(),
             vec![1, 2, 3, 4],
         );
         assert!(res.is_ok());
@@ -139,6 +150,11 @@ fn accept_content_as_liaison() {
             content_id,
         );
         assert_eq!(res, Ok(()));
+
+        // The accepted content has no ready replicas yet.
+        assert!(TestDataObjectStorageRegistry::is_under_replicated(
+            &content_id
+        ));
     });
 }
 
@@ -158,9 +174,11 @@ fn reject_content_as_liaison() {
         let res = TestDataDirectory::add_content(
             Origin::signed(sender),
             member_id,
+            None,
             1,
             1234,
             0,
+            TEST_CONTENT_TYPE.to_vec(),
             vec![1, 2, 3, 4],
         );
         assert!(res.is_ok());
@@ -197,7 +215,7 @@ fn reject_content_as_liaison() {
 fn data_object_injection_works() {
     with_default_mock_builder(|| {
         // No objects in directory before injection
-        assert_eq!(TestDataDirectory::known_content_ids(), vec![]);
+        assert_eq!(TestDataDirectory::known_content_ids(0, 10), vec![]);
 
         // new objects to inject into the directory
         let mut objects = BTreeMap::new();
@@ -225,7 +243,7 @@ fn data_object_injection_works() {
         assert!(res.is_ok());
 
         assert_eq!(
-            TestDataDirectory::known_content_ids(),
+            TestDataDirectory::known_content_ids(0, 10),
             vec![content_id_1, content_id_2]
         );
 
@@ -254,18 +272,22 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
         let res = TestDataDirectory::add_content(
             Origin::signed(sender),
             member_id,
+            None,
             content_id_1,
             1,
             10,
+            TEST_CONTENT_TYPE.to_vec(),
             vec![8, 8, 8, 8],
         );
         assert!(res.is_ok());
         let res = TestDataDirectory::add_content(
             Origin::signed(sender),
             member_id,
+            None,
             content_id_2,
             2,
             20,
+            TEST_CONTENT_TYPE.to_vec(),
             vec![9, 9, 9, 9],
         );
         assert!(res.is_ok());
@@ -305,7 +327,7 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
         assert!(res.is_ok());
 
         assert_eq!(
-            TestDataDirectory::known_content_ids(),
+            TestDataDirectory::known_content_ids(0, 10),
             vec![content_id_1, content_id_2]
         );
 
@@ -320,3 +342,657 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
         );
     });
 }
+
+#[test]
+fn set_storage_capacity_succeeds() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+        let capacity = 100_000;
+
+        let res = TestDataDirectory::set_storage_capacity(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            capacity,
+        );
+        assert_eq!(res, Ok(()));
+
+        assert_eq!(
+            TestDataDirectory::storage_capacity_by_storage_provider_id(storage_provider_id),
+            data_directory::StorageCapacity {
+                capacity: Some(capacity),
+                used: 0
+            }
+        );
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_directory(data_directory::RawEvent::StorageCapacityUpdated(
+                storage_provider_id,
+                capacity
+            ))
+        );
+    });
+}
+
+#[test]
+fn set_storage_capacity_fails_with_invalid_storage_provider() {
+    with_default_mock_builder(|| {
+        let (storage_provider_account_id, storage_provider_id) = (1, 5);
+
+        let res = TestDataDirectory::set_storage_capacity(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            100_000,
+        );
+        assert_eq!(res, Err(working_group::Error::<Test, crate::StorageWorkingGroupInstance>::WorkerDoesNotExist.into()));
+    });
+}
+
+#[test]
+fn liaison_storage_space_is_taken_by_not_rejected_content() {
+    with_default_mock_builder(|| {
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+        assert_eq!(storage_provider_id, TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID);
+
+        let res = TestDataDirectory::set_storage_capacity(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            5000,
+        );
+        assert_eq!(res, Ok(()));
+
+        let sender = 1u64;
+        let member_id = 1u64;
+        let content_id = 1;
+        let size = 1234;
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            None,
+            content_id,
+            1,
+            size,
+            TEST_CONTENT_TYPE.to_vec(),
+            vec![1, 2, 3, 4],
+        );
+        assert!(res.is_ok());
+
+        let free_space = || {
+            TestDataDirectory::storage_capacity_by_storage_provider_id(storage_provider_id)
+                .free_space()
+        };
+        assert_eq!(free_space(), Some(5000 - size));
+
+        let res = TestDataDirectory::reject_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+        );
+        assert_eq!(res, Ok(()));
+        assert_eq!(free_space(), Some(5000));
+
+        let res = TestDataDirectory::accept_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+        );
+        assert_eq!(res, Ok(()));
+        assert_eq!(free_space(), Some(5000 - size));
+    });
+}
+
+#[test]
+fn remove_content_succeeds() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let sender = 1u64;
+        let member_id = 1u64;
+        let content_id = TEST_MOCK_EXISTING_CID;
+        let ipfs_content_id = vec![1, 2, 3, 4];
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(sender),
+            member_id,
+            None,
+            content_id,
+            1,
+            1234,
+            TEST_CONTENT_TYPE.to_vec(),
+            ipfs_content_id.clone(),
+        );
+        assert!(res.is_ok());
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let res = TestDataDirectory::accept_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+        );
+        assert_eq!(res, Ok(()));
+
+        let relationship_id = TestDataObjectStorageRegistry::next_relationship_id();
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+        );
+        assert!(res.is_ok());
+
+        let res = TestDataDirectory::remove_content(Origin::signed(sender), member_id, content_id);
+        assert_eq!(res, Ok(()));
+
+        assert_eq!(
+            TestDataDirectory::data_object_by_content_id(content_id),
+            None
+        );
+        assert!(!TestDataDirectory::is_known_content_id(&content_id));
+        assert_eq!(
+            TestDataObjectStorageRegistry::relationships(relationship_id),
+            None
+        );
+        assert!(TestDataObjectStorageRegistry::relationships_by_content_id(content_id).is_empty());
+
+        let events: Vec<MetaEvent> = System::events()
+            .into_iter()
+            .map(|record| record.event)
+            .collect();
+        assert!(events.contains(&MetaEvent::data_object_storage_registry(
+            data_object_storage_registry::RawEvent::DataObjectStorageRelationshipRemoved(
+                relationship_id,
+                content_id,
+                storage_provider_id
+            )
+        )));
+        assert_eq!(
+            events.last(),
+            Some(&MetaEvent::data_directory(
+                data_directory::RawEvent::ContentRemoved(content_id, ipfs_content_id)
+            ))
+        );
+    });
+}
+
+#[test]
+fn remove_content_fails_with_invalid_owner() {
+    with_default_mock_builder(|| {
+        let content_id = 1;
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            None,
+            content_id,
+            1,
+            1234,
+            TEST_CONTENT_TYPE.to_vec(),
+            vec![1, 2, 3, 4],
+        );
+        assert!(res.is_ok());
+
+        let res = TestDataDirectory::remove_content(Origin::signed(2), 2, content_id);
+        assert_eq!(
+            res,
+            Err(data_directory::Error::<Test>::OwnerRequired.into())
+        );
+    });
+}
+
+#[test]
+fn remove_content_fails_with_absent_content() {
+    with_default_mock_builder(|| {
+        let res = TestDataDirectory::remove_content(Origin::signed(1), 1, 1);
+        assert_eq!(res, Err(data_directory::Error::<Test>::CidNotFound.into()));
+    });
+}
+
+#[test]
+fn remove_content_as_lead_succeeds() {
+    with_default_mock_builder(|| {
+        let content_id = 1;
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            None,
+            content_id,
+            1,
+            1234,
+            TEST_CONTENT_TYPE.to_vec(),
+            vec![1, 2, 3, 4],
+        );
+        assert!(res.is_ok());
+
+        let (lead_account_id, lead_id) = hire_storage_provider();
+
+        // Not a lead yet.
+        let res =
+            TestDataDirectory::remove_content_as_lead(Origin::signed(lead_account_id), content_id);
+        assert_eq!(
+            res,
+            Err(
+                working_group::Error::<Test, crate::StorageWorkingGroupInstance>::CurrentLeadNotSet
+                    .into()
+            )
+        );
+
+        <working_group::CurrentLead<Test, crate::StorageWorkingGroupInstance>>::put(lead_id);
+
+        let res =
+            TestDataDirectory::remove_content_as_lead(Origin::signed(lead_account_id), content_id);
+        assert_eq!(res, Ok(()));
+        assert_eq!(
+            TestDataDirectory::data_object_by_content_id(content_id),
+            None
+        );
+    });
+}
+
+fn inject_content_ids(content_ids: Vec<u64>) {
+    let objects = content_ids
+        .into_iter()
+        .map(|content_id| {
+            let object = data_directory::DataObjectInternal {
+                type_id: 1,
+                size: 1234,
+                added_at: data_directory::BlockAndTime { block: 1, time: 1 },
+                owner: 1,
+                liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
+                liaison_judgement: data_directory::LiaisonJudgement::Accepted,
+                ipfs_content_id: vec![],
+            };
+
+            (content_id, object)
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let res = TestDataDirectory::inject_data_objects(RawOrigin::Root.into(), objects);
+    assert!(res.is_ok());
+}
+
+#[test]
+fn known_content_ids_are_paged() {
+    with_default_mock_builder(|| {
+        inject_content_ids(vec![1, 2, 3, 4, 5]);
+
+        assert_eq!(TestDataDirectory::known_content_ids_count(), 5);
+        assert_eq!(TestDataDirectory::known_content_ids(0, 2), vec![1, 2]);
+        assert_eq!(TestDataDirectory::known_content_ids(2, 2), vec![3, 4]);
+        assert_eq!(TestDataDirectory::known_content_ids(4, 2), vec![5]);
+        assert_eq!(TestDataDirectory::known_content_ids(6, 2), vec![]);
+    });
+}
+
+#[test]
+fn removed_known_content_id_is_replaced_by_the_last_one() {
+    with_default_mock_builder(|| {
+        inject_content_ids(vec![1, 2, 3]);
+
+        let res = data_directory::Call::<Test>::remove_known_content_id(1)
+            .dispatch_bypass_filter(RawOrigin::Root.into());
+        assert!(res.is_ok());
+
+        assert_eq!(TestDataDirectory::known_content_ids_count(), 2);
+        assert_eq!(TestDataDirectory::known_content_ids(0, 10), vec![3, 2]);
+        assert!(!TestDataDirectory::is_known_content_id(&1));
+        assert_eq!(TestDataDirectory::known_content_id_index(3), Some(0));
+
+        // Removing an unknown content id changes nothing.
+        let res = data_directory::Call::<Test>::remove_known_content_id(1)
+            .dispatch_bypass_filter(RawOrigin::Root.into());
+        assert!(res.is_ok());
+        assert_eq!(TestDataDirectory::known_content_ids(0, 10), vec![3, 2]);
+    });
+}
+
+#[test]
+fn known_content_ids_migration_succeeds() {
+    with_default_mock_builder(|| {
+        let legacy_known_content_ids: Vec<u64> = vec![7, 3, 7, 5];
+        migration::put_storage_value(
+            b"DataDirectory",
+            b"KnownContentIds",
+            &[],
+            legacy_known_content_ids,
+        );
+
+        TestDataDirectory::migrate_known_content_ids();
+
+        assert_eq!(TestDataDirectory::known_content_ids(0, 10), vec![7, 3, 5]);
+        assert_eq!(
+            migration::get_storage_value::<Vec<u64>>(b"DataDirectory", b"KnownContentIds", &[]),
+            None
+        );
+    });
+}
+
+#[test]
+fn add_content_counts_against_member_and_channel_quotas() {
+    with_default_mock_builder(|| {
+        let member_id = 1u64;
+        let channel_id = 1u64;
+        let content_id = 1;
+        let size = 1234;
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            member_id,
+            Some(channel_id),
+            content_id,
+            1,
+            size,
+            TEST_CONTENT_TYPE.to_vec(),
+            vec![1, 2, 3, 4],
+        );
+        assert_eq!(res, Ok(()));
+
+        let member_quota =
+            TestDataDirectory::quota(&data_directory::StorageObjectOwner::Member(member_id));
+        let channel_quota =
+            TestDataDirectory::quota(&data_directory::StorageObjectOwner::Channel(channel_id));
+        let expected_quota = data_directory::Quota {
+            objects_used: 1,
+            size_used: size,
+            ..data_directory::Quota::new(
+                DefaultQuotaObjectsLimit::get(),
+                DefaultQuotaSizeLimit::get(),
+            )
+        };
+        assert_eq!(member_quota, expected_quota);
+        assert_eq!(channel_quota, expected_quota);
+
+        let res = TestDataDirectory::remove_content(Origin::signed(1), member_id, content_id);
+        assert_eq!(res, Ok(()));
+
+        assert_eq!(
+            TestDataDirectory::quota(&data_directory::StorageObjectOwner::Member(member_id))
+                .objects_used,
+            0
+        );
+        assert_eq!(
+            TestDataDirectory::quota(&data_directory::StorageObjectOwner::Channel(channel_id))
+                .size_used,
+            0
+        );
+        assert_eq!(
+            TestDataDirectory::channel_id_by_content_id(content_id),
+            None
+        );
+    });
+}
+
+#[test]
+fn data_object_injection_releases_overwritten_object_resources() {
+    with_default_mock_builder(|| {
+        let member_id = 1u64;
+        let channel_id = 1u64;
+        let content_id = 1;
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            member_id,
+            Some(channel_id),
+            content_id,
+            1,
+            1234,
+            TEST_CONTENT_TYPE.to_vec(),
+            vec![1, 2, 3, 4],
+        );
+        assert_eq!(res, Ok(()));
+
+        let new_owner = 2u64;
+        let new_size = 100;
+
+        let mut objects = BTreeMap::new();
+        objects.insert(
+            content_id,
+            data_directory::DataObjectInternal {
+                type_id: 1,
+                size: new_size,
+                added_at: data_directory::BlockAndTime { block: 1, time: 1 },
+                owner: new_owner,
+                liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
+                liaison_judgement: data_directory::LiaisonJudgement::Accepted,
+                ipfs_content_id: vec![],
+            },
+        );
+
+        let res = TestDataDirectory::inject_data_objects(RawOrigin::Root.into(), objects);
+        assert!(res.is_ok());
+
+        let member_quota =
+            TestDataDirectory::quota(&data_directory::StorageObjectOwner::Member(member_id));
+        let channel_quota =
+            TestDataDirectory::quota(&data_directory::StorageObjectOwner::Channel(channel_id));
+        let new_owner_quota =
+            TestDataDirectory::quota(&data_directory::StorageObjectOwner::Member(new_owner));
+
+        assert_eq!((member_quota.objects_used, member_quota.size_used), (0, 0));
+        assert_eq!(
+            (channel_quota.objects_used, channel_quota.size_used),
+            (0, 0)
+        );
+        assert_eq!(
+            (new_owner_quota.objects_used, new_owner_quota.size_used),
+            (1, new_size)
+        );
+        assert_eq!(
+            TestDataDirectory::channel_id_by_content_id(content_id),
+            None
+        );
+        assert_eq!(
+            TestDataDirectory::storage_capacity_by_storage_provider_id(
+                TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID
+            )
+            .used,
+            new_size
+        );
+    });
+}
+
+#[test]
+fn add_content_fails_with_foreign_channel() {
+    with_default_mock_builder(|| {
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            Some(2),
+            1,
+            1,
+            1234,
+            TEST_CONTENT_TYPE.to_vec(),
+            vec![1, 2, 3, 4],
+        );
+        assert_eq!(
+            res,
+            Err(data_directory::Error::<Test>::ChannelOwnerRequired.into())
+        );
+    });
+}
+
+#[test]
+fn add_content_fails_with_exceeded_quota() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (lead_account_id, lead_id) = hire_storage_provider();
+        <working_group::CurrentLead<Test, crate::StorageWorkingGroupInstance>>::put(lead_id);
+
+        let owner = data_directory::StorageObjectOwner::Member(1);
+        let res =
+            TestDataDirectory::update_quota_limits(Origin::signed(lead_account_id), owner, 1, 2000);
+        assert_eq!(res, Ok(()));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_directory(data_directory::RawEvent::QuotaLimitsUpdated(owner, 1, 2000))
+        );
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            None,
+            1,
+            1,
+            2001,
+            TEST_CONTENT_TYPE.to_vec(),
+            vec![1, 2, 3, 4],
+        );
+        assert_eq!(
+            res,
+            Err(data_directory::Error::<Test>::QuotaSizeLimitExceeded.into())
+        );
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            None,
+            1,
+            1,
+            2000,
+            TEST_CONTENT_TYPE.to_vec(),
+            vec![1, 2, 3, 4],
+        );
+        assert_eq!(res, Ok(()));
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            None,
+            2,
+            1,
+            0,
+            TEST_CONTENT_TYPE.to_vec(),
+            vec![1, 2, 3, 5],
+        );
+        assert_eq!(
+            res,
+            Err(data_directory::Error::<Test>::QuotaObjectsLimitExceeded.into())
+        );
+    });
+}
+
+#[test]
+fn update_quota_limits_fails_with_invalid_origin() {
+    with_default_mock_builder(|| {
+        let res = TestDataDirectory::update_quota_limits(
+            Origin::signed(1),
+            data_directory::StorageObjectOwner::Channel(1),
+            1,
+            1,
+        );
+        assert_eq!(
+            res,
+            Err(
+                working_group::Error::<Test, crate::StorageWorkingGroupInstance>::CurrentLeadNotSet
+                    .into()
+            )
+        );
+    });
+}
+
+#[test]
+fn add_content_charges_upload_fee() {
+    with_default_mock_builder(|| {
+        let upload_fee_per_byte = 2;
+        let size = 1000;
+        let initial_balance = 3000;
+
+        let res = data_directory::Call::<Test>::set_upload_fee_per_byte(upload_fee_per_byte)
+            .dispatch_bypass_filter(RawOrigin::Root.into());
+        assert!(res.is_ok());
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            None,
+            1,
+            1,
+            size,
+            TEST_CONTENT_TYPE.to_vec(),
+            vec![1, 2, 3, 4],
+        );
+        assert_eq!(
+            res,
+            Err(data_directory::Error::<Test>::InsufficientBalanceForUploadFee.into())
+        );
+
+        let _ = Balances::deposit_creating(&1, initial_balance);
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            None,
+            1,
+            1,
+            size,
+            TEST_CONTENT_TYPE.to_vec(),
+            vec![1, 2, 3, 4],
+        );
+        assert_eq!(res, Ok(()));
+        assert_eq!(
+            Balances::total_balance(&1),
+            initial_balance - size * upload_fee_per_byte
+        );
+    });
+}
+
+#[test]
+fn add_content_fails_with_violated_data_object_type_constraints() {
+    with_default_mock_builder(|| {
+        let type_id = 1;
+        <data_object_type_registry::DataObjectTypes<Test>>::insert(
+            type_id,
+            TestDataObjectType {
+                max_size: Some(2 * 1024 * 1024),
+                allowed_content_types: vec![b"image/png".to_vec(), b"image/jpeg".to_vec()],
+                ..Default::default()
+            },
+        );
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            None,
+            1,
+            type_id,
+            2 * 1024 * 1024 + 1,
+            b"image/png".to_vec(),
+            vec![1, 2, 3, 4],
+        );
+        assert_eq!(
+            res,
+            Err(data_directory::Error::<Test>::DataObjectTypeMaxSizeExceeded.into())
+        );
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            None,
+            1,
+            type_id,
+            1024,
+            TEST_CONTENT_TYPE.to_vec(),
+            vec![1, 2, 3, 4],
+        );
+        assert_eq!(
+            res,
+            Err(data_directory::Error::<Test>::ContentTypeNotAllowed.into())
+        );
+
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            1,
+            None,
+            1,
+            type_id,
+            1024,
+            b"image/jpeg".to_vec(),
+            vec![1, 2, 3, 4],
+        );
+        assert_eq!(res, Ok(()));
+    });
+}
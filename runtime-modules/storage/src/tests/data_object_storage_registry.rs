@@ -1,5 +1,9 @@
 #![cfg(test)]
 
+use frame_support::traits::Get;
+use frame_support::{StorageMap, StorageValue};
+use sp_core::H256;
+
 use super::mock::*;
 
 #[test]
@@ -84,6 +88,29 @@ fn test_add_relationship() {
     });
 }
 
+#[test]
+fn add_relationship_fails_with_existing_relationship() {
+    with_default_mock_builder(|| {
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            TEST_MOCK_EXISTING_CID,
+        );
+        assert_eq!(res, Ok(()));
+
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            TEST_MOCK_EXISTING_CID,
+        );
+        assert_eq!(
+            res,
+            Err(data_object_storage_registry::Error::<Test>::DataObjectStorageRelationshipAlreadyExists.into())
+        );
+    });
+}
+
 #[test]
 fn test_fail_adding_relationship_with_bad_content() {
     with_default_mock_builder(|| {
@@ -162,3 +189,397 @@ fn test_toggle_ready() {
         );
     });
 }
+
+#[test]
+fn ready_replicas_are_counted_and_under_replication_is_tracked() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let content_id = TEST_MOCK_EXISTING_CID;
+        let (account_id, storage_provider_id) = hire_storage_provider();
+        let dosr_id = TestDataObjectStorageRegistry::next_relationship_id();
+
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(account_id),
+            storage_provider_id,
+            content_id,
+        );
+        assert_eq!(res, Ok(()));
+        assert!(TestDataObjectStorageRegistry::is_under_replicated(
+            &content_id
+        ));
+        assert_eq!(
+            TestDataObjectStorageRegistry::under_replicated_content(content_id),
+            Some(1)
+        );
+        assert!(System::events().iter().any(|record| record.event
+            == MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::ContentUnderReplicated(content_id, 0, 1)
+            )));
+
+        for _ in 0..2 {
+            let res = TestDataObjectStorageRegistry::set_relationship_ready(
+                Origin::signed(account_id),
+                storage_provider_id,
+                dosr_id,
+            );
+            assert_eq!(res, Ok(()));
+        }
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_replicas_by_content_id(content_id),
+            1
+        );
+        assert!(!TestDataObjectStorageRegistry::is_under_replicated(
+            &content_id
+        ));
+        assert!(System::events().iter().any(|record| record.event
+            == MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::ContentReplicated(content_id, 1)
+            )));
+
+        let res = TestDataObjectStorageRegistry::unset_relationship_ready(
+            Origin::signed(account_id),
+            storage_provider_id,
+            dosr_id,
+        );
+        assert_eq!(res, Ok(()));
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_replicas_by_content_id(content_id),
+            0
+        );
+        assert!(TestDataObjectStorageRegistry::is_under_replicated(
+            &content_id
+        ));
+    });
+}
+
+#[test]
+fn content_replication_target_respects_data_object_type() {
+    with_default_mock_builder(|| {
+        assert_eq!(
+            TestDataObjectStorageRegistry::content_replication_target(&TEST_MOCK_EXISTING_CID),
+            1
+        );
+
+        // Data object type of the mock content.
+        <data_object_type_registry::DataObjectTypes<Test>>::insert(
+            1,
+            TestDataObjectType {
+                min_replication_factor: Some(3),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::content_replication_target(&TEST_MOCK_EXISTING_CID),
+            3
+        );
+    });
+}
+
+#[test]
+fn set_target_replication_factor_succeeds() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (account_id, lead_id) = hire_storage_provider();
+
+        let res = TestDataObjectStorageRegistry::set_target_replication_factor(
+            Origin::signed(account_id),
+            5,
+        );
+        assert_eq!(
+            res,
+            Err(
+                working_group::Error::<Test, crate::StorageWorkingGroupInstance>::CurrentLeadNotSet
+                    .into()
+            )
+        );
+
+        <working_group::CurrentLead<Test, crate::StorageWorkingGroupInstance>>::put(lead_id);
+
+        let res = TestDataObjectStorageRegistry::set_target_replication_factor(
+            Origin::signed(account_id),
+            5,
+        );
+        assert_eq!(res, Ok(()));
+        assert_eq!(
+            TestDataObjectStorageRegistry::target_replication_factor(),
+            5
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::TargetReplicationFactorUpdated(5)
+            )
+        );
+    });
+}
+
+#[test]
+fn storage_provider_exit_deactivates_its_relationships() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let content_id = TEST_MOCK_EXISTING_CID;
+        let dosr_id = TestDataObjectStorageRegistry::next_relationship_id();
+        let (_, storage_provider_id) = add_ready_relationship();
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_replicas_by_content_id(content_id),
+            1
+        );
+
+        <TestDataObjectStorageRegistry as working_group::WorkerExitHandler<
+            Test,
+            StorageWorkingGroupInstance,
+        >>::worker_exited(
+            &storage_provider_id,
+            &working_group::Worker::new(&Default::default(), &Default::default(), &None, &None),
+        );
+
+        assert!(
+            !TestDataObjectStorageRegistry::relationships(dosr_id)
+                .unwrap()
+                .ready
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_replicas_by_content_id(content_id),
+            0
+        );
+        assert!(TestDataObjectStorageRegistry::is_under_replicated(
+            &content_id
+        ));
+    });
+}
+
+#[test]
+fn replicas_migration_succeeds() {
+    with_default_mock_builder(|| {
+        let content_id = TEST_MOCK_EXISTING_CID;
+        // The third relationship duplicates the first one.
+        for (dosr_id, storage_provider_id, ready) in
+            vec![(1u64, 1u32, false), (2, 2, false), (3, 1, true)]
+        {
+            <data_object_storage_registry::Relationships<Test>>::insert(
+                dosr_id,
+                data_object_storage_registry::DataObjectStorageRelationship::<Test> {
+                    content_id,
+                    storage_provider_id,
+                    ready,
+                },
+            );
+        }
+        <data_object_storage_registry::RelationshipsByContentId<Test>>::insert(
+            content_id,
+            vec![1, 2, 3],
+        );
+        <data_object_storage_registry::TargetReplicationFactor>::put(2);
+        <data_object_storage_registry::StorageVersion>::put(0);
+
+        TestDataObjectStorageRegistry::migrate();
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::ready_replicas_by_content_id(content_id),
+            1
+        );
+        assert!(TestDataObjectStorageRegistry::is_under_replicated(
+            &content_id
+        ));
+        assert_eq!(
+            TestDataObjectStorageRegistry::relationships_by_content_id(content_id),
+            vec![1, 2]
+        );
+        assert!(
+            TestDataObjectStorageRegistry::relationships(1)
+                .unwrap()
+                .ready
+        );
+        assert_eq!(TestDataObjectStorageRegistry::relationships(3), None);
+        assert_eq!(
+            TestDataObjectStorageRegistry::relationship_id_by_storage_provider_and_content_id(
+                1, content_id
+            ),
+            Some(1)
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::relationships_by_storage_provider(1),
+            vec![1]
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::relationships_by_storage_provider(2),
+            vec![2]
+        );
+        assert_eq!(TestDataObjectStorageRegistry::storage_version(), 1);
+    });
+}
+
+// Adds the ready relationship of the mock content and returns the storage provider ids.
+fn add_ready_relationship() -> (u64, u32) {
+    let (account_id, storage_provider_id) = hire_storage_provider();
+    let dosr_id = TestDataObjectStorageRegistry::next_relationship_id();
+
+    let res = TestDataObjectStorageRegistry::add_relationship(
+        Origin::signed(account_id),
+        storage_provider_id,
+        TEST_MOCK_EXISTING_CID,
+    );
+    assert_eq!(res, Ok(()));
+
+    let res = TestDataObjectStorageRegistry::set_relationship_ready(
+        Origin::signed(account_id),
+        storage_provider_id,
+        dosr_id,
+    );
+    assert_eq!(res, Ok(()));
+
+    (account_id, storage_provider_id)
+}
+
+#[test]
+fn unanswered_storage_challenge_fails() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (_, storage_provider_id) = add_ready_relationship();
+        let challenge_id = TestDataObjectStorageRegistry::next_storage_challenge_id();
+
+        run_to_block(StorageChallengePeriod::get());
+
+        let challenge = TestDataObjectStorageRegistry::storage_challenges(challenge_id).unwrap();
+        assert_eq!(challenge.content_id, TEST_MOCK_EXISTING_CID);
+        assert_eq!(challenge.storage_provider_id, storage_provider_id);
+        assert!(challenge.range_start + challenge.range_length <= 1234);
+        assert_eq!(
+            challenge.deadline,
+            StorageChallengePeriod::get() + StorageChallengeResponsePeriod::get()
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::StorageChallengeIssued(
+                    challenge_id,
+                    TEST_MOCK_EXISTING_CID,
+                    storage_provider_id
+                )
+            )
+        );
+
+        run_to_block(challenge.deadline);
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::storage_challenges(challenge_id),
+            None
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::failed_storage_challenges(storage_provider_id),
+            1
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::StorageChallengeFailed(
+                    challenge_id,
+                    storage_provider_id
+                )
+            )
+        );
+    });
+}
+
+#[test]
+fn answered_storage_challenge_succeeds() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (account_id, storage_provider_id) = add_ready_relationship();
+        let challenge_id = TestDataObjectStorageRegistry::next_storage_challenge_id();
+
+        run_to_block(StorageChallengePeriod::get());
+
+        let range_hash = H256::repeat_byte(1);
+        let res = TestDataObjectStorageRegistry::answer_storage_challenge(
+            Origin::signed(account_id),
+            storage_provider_id,
+            challenge_id,
+            range_hash,
+        );
+        assert_eq!(res, Ok(()));
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::StorageChallengeAnswered(
+                    challenge_id,
+                    range_hash
+                )
+            )
+        );
+
+        let res = TestDataObjectStorageRegistry::answer_storage_challenge(
+            Origin::signed(account_id),
+            storage_provider_id,
+            challenge_id,
+            range_hash,
+        );
+        assert_eq!(
+            res,
+            Err(
+                data_object_storage_registry::Error::<Test>::StorageChallengeAlreadyAnswered.into()
+            )
+        );
+
+        run_to_block(StorageChallengePeriod::get() + StorageChallengeResponsePeriod::get());
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::failed_storage_challenges(storage_provider_id),
+            0
+        );
+    });
+}
+
+#[test]
+fn rejected_storage_challenge_response_fails_challenge() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let (account_id, storage_provider_id) = add_ready_relationship();
+        let challenge_id = TestDataObjectStorageRegistry::next_storage_challenge_id();
+
+        run_to_block(StorageChallengePeriod::get());
+
+        let lead_account_id = account_id;
+        <working_group::CurrentLead<Test, crate::StorageWorkingGroupInstance>>::put(
+            storage_provider_id,
+        );
+
+        let res = TestDataObjectStorageRegistry::reject_storage_challenge_response(
+            Origin::signed(lead_account_id),
+            challenge_id,
+        );
+        assert_eq!(
+            res,
+            Err(data_object_storage_registry::Error::<Test>::StorageChallengeNotAnswered.into())
+        );
+
+        let res = TestDataObjectStorageRegistry::answer_storage_challenge(
+            Origin::signed(account_id),
+            storage_provider_id,
+            challenge_id,
+            H256::zero(),
+        );
+        assert_eq!(res, Ok(()));
+
+        let res = TestDataObjectStorageRegistry::reject_storage_challenge_response(
+            Origin::signed(lead_account_id),
+            challenge_id,
+        );
+        assert_eq!(res, Ok(()));
+        assert_eq!(
+            TestDataObjectStorageRegistry::failed_storage_challenges(storage_provider_id),
+            1
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::storage_challenges(challenge_id),
+            None
+        );
+    });
+}
@@ -1,6 +1,8 @@
 #![cfg(test)]
 
-use frame_support::{StorageMap, StorageValue};
+use codec::Encode;
+use frame_support::storage::migration;
+use frame_support::{Blake2_128Concat, StorageHasher, StorageMap, StorageValue};
 use system::{EventRecord, Phase, RawOrigin};
 
 use super::mock::*;
@@ -62,6 +64,7 @@ fn succeed_register() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -71,6 +74,35 @@ fn succeed_register() {
     });
 }
 
+#[test]
+fn register_saves_constraints() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        SetLeadFixture::set_default_lead();
+
+        let data: TestDataObjectType = TestDataObjectType {
+            description: "video".as_bytes().to_vec(),
+            active: true,
+            max_size: Some(10 * 1024 * 1024 * 1024),
+            allowed_content_types: vec![b"video/mp4".to_vec(), b"video/webm".to_vec()],
+            min_replication_factor: Some(3),
+        };
+        let res = TestDataObjectTypeRegistry::register_data_object_type(
+            RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
+            data.clone(),
+        );
+        assert!(res.is_ok());
+
+        let dot_id = get_last_data_object_type_id();
+        let data_object_type = TestDataObjectTypeRegistry::data_object_types(dot_id).unwrap();
+        assert_eq!(data_object_type, data);
+        assert!(data_object_type.is_content_type_allowed(b"video/webm"));
+        assert!(!data_object_type.is_content_type_allowed(b"image/png"));
+        assert!(!data_object_type.is_size_allowed(10 * 1024 * 1024 * 1024 + 1));
+    });
+}
+
 #[test]
 fn activate_data_object_type_fails_with_invalid_lead() {
     with_default_mock_builder(|| {
@@ -87,6 +119,7 @@ fn activate_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -127,6 +160,7 @@ fn deactivate_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: true,
+            ..Default::default()
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -167,6 +201,7 @@ fn update_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -178,6 +213,7 @@ fn update_data_object_type_fails_with_invalid_lead() {
         let updated1: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
 
         let invalid_leader_account_id = 2;
@@ -212,6 +248,7 @@ fn update_existing() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -226,6 +263,7 @@ fn update_existing() {
         let updated1: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let res = TestDataObjectTypeRegistry::update_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -238,6 +276,7 @@ fn update_existing() {
         let updated3: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let res = TestDataObjectTypeRegistry::update_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -265,6 +304,7 @@ fn register_data_object_type_failed_with_no_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -292,6 +332,7 @@ fn activate_existing() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            ..Default::default()
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -351,3 +392,41 @@ fn activate_existing() {
         assert!(!data.unwrap().active);
     });
 }
+
+#[test]
+fn data_object_types_migration_succeeds() {
+    with_default_mock_builder(|| {
+        let legacy_do_type_id = 5u64;
+        let legacy_do_type = (b"legacy".to_vec(), true);
+        migration::put_storage_value(
+            b"DataObjectTypeRegistry",
+            b"DataObjectTypes",
+            &Blake2_128Concat::hash(&legacy_do_type_id.encode()),
+            legacy_do_type,
+        );
+        <data_object_type_registry::StorageVersion>::put(0);
+
+        TestDataObjectTypeRegistry::migrate();
+
+        let expected_do_type = data_object_type_registry::DataObjectType {
+            description: b"legacy".to_vec(),
+            active: true,
+            max_size: None,
+            allowed_content_types: Vec::new(),
+            min_replication_factor: None,
+        };
+        assert_eq!(
+            TestDataObjectTypeRegistry::data_object_types(legacy_do_type_id),
+            Some(expected_do_type.clone())
+        );
+        assert_eq!(TestDataObjectTypeRegistry::storage_version(), 1);
+
+        // The migrated data object types are not re-encoded by the next upgrades.
+        TestDataObjectTypeRegistry::migrate();
+
+        assert_eq!(
+            TestDataObjectTypeRegistry::data_object_types(legacy_do_type_id),
+            Some(expected_do_type)
+        );
+    });
+}
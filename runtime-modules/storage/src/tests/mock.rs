@@ -1,17 +1,17 @@
 #![cfg(test)]
 
 use frame_support::storage::StorageMap;
-use frame_support::traits::{OnFinalize, OnInitialize};
+use frame_support::traits::{OnFinalize, OnInitialize, Randomness};
 use frame_support::{impl_outer_event, impl_outer_origin, parameter_types};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
-    traits::{BlakeTwo256, IdentityLookup},
+    traits::{BlakeTwo256, Hash, IdentityLookup},
     Perbill,
 };
 
 use crate::data_directory::ContentIdExists;
-use crate::data_object_type_registry::IsActiveDataObjectType;
+use crate::data_object_type_registry::{DataObjectTypeProvider, IsActiveDataObjectType};
 pub use crate::StorageWorkingGroupInstance;
 pub use crate::{data_directory, data_object_storage_registry, data_object_type_registry};
 use common::currency::GovernanceCurrency;
@@ -49,6 +49,7 @@ pub const TEST_FIRST_METADATA_ID: u64 = 4000;
 
 pub const TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID: u32 = 1;
 pub const TEST_MOCK_EXISTING_CID: u64 = 42;
+pub const TEST_CONTENT_TYPE: &[u8] = b"video/mp4";
 
 pub struct AnyDataObjectTypeIsActive {}
 impl<T: data_object_type_registry::Trait> IsActiveDataObjectType<T> for AnyDataObjectTypeIsActive {
@@ -57,6 +58,27 @@ impl<T: data_object_type_registry::Trait> IsActiveDataObjectType<T> for AnyDataO
     }
 }
 
+// Unregistered data object types get the default constraints.
+impl DataObjectTypeProvider<Test> for AnyDataObjectTypeIsActive {
+    fn data_object_type(id: &u64) -> Option<data_object_type_registry::DataObjectType> {
+        Some(TestDataObjectTypeRegistry::data_object_types(id).unwrap_or_default())
+    }
+}
+
+pub struct AnyMemberOwnsChannelWithItsId {}
+impl data_directory::ChannelOwnershipValidator<Test> for AnyMemberOwnsChannelWithItsId {
+    fn is_channel_owner(member_id: &u64, channel_id: &u64) -> bool {
+        member_id == channel_id
+    }
+}
+
+pub struct TestRandomness {}
+impl Randomness<H256> for TestRandomness {
+    fn random(subject: &[u8]) -> H256 {
+        BlakeTwo256::hash(subject)
+    }
+}
+
 pub struct MockContent {}
 impl ContentIdExists<Test> for MockContent {
     fn has_content(which: &<Test as data_directory::Trait>::ContentId) -> bool {
@@ -94,6 +116,11 @@ parameter_types! {
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const MinimumPeriod: u64 = 5;
     pub const MaxObjectsPerInjection: u32 = 5;
+    pub const DefaultQuotaObjectsLimit: u64 = 100;
+    pub const DefaultQuotaSizeLimit: u64 = 100_000;
+    pub const StorageChallengePeriod: u64 = 10;
+    pub const StorageChallengeResponsePeriod: u64 = 5;
+    pub const MaxStorageChallengesPerRound: u32 = 1;
 }
 
 impl system::Trait for Test {
@@ -153,24 +180,36 @@ parameter_types! {
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
+    type WorkerExitHandler = ();
+    type WorkerRoleAccountUpdateHandler = ();
 }
 
 impl data_object_type_registry::Trait for Test {
     type Event = MetaEvent;
     type DataObjectTypeId = u64;
+    type WeightInfo = ();
 }
 
 impl data_directory::Trait for Test {
     type Event = MetaEvent;
     type ContentId = u64;
+    type ChannelId = u64;
     type StorageProviderHelper = ();
     type IsActiveDataObjectType = AnyDataObjectTypeIsActive;
+    type DataObjectTypeProvider = AnyDataObjectTypeIsActive;
     type MemberOriginValidator = ();
+    type ChannelOwnershipValidator = AnyMemberOwnsChannelWithItsId;
+    type ContentRemovalHandler = TestDataObjectStorageRegistry;
+    type ContentAcceptanceHandler = TestDataObjectStorageRegistry;
     type MaxObjectsPerInjection = MaxObjectsPerInjection;
+    type DefaultQuotaObjectsLimit = DefaultQuotaObjectsLimit;
+    type DefaultQuotaSizeLimit = DefaultQuotaSizeLimit;
+    type WeightInfo = ();
 }
 
 impl crate::data_directory::StorageProviderHelper<Test> for () {
-    fn get_random_storage_provider() -> Result<u32, &'static str> {
+    fn get_random_storage_provider(_content_size: u64) -> Result<u32, &'static str> {
         Ok(1)
     }
 }
@@ -187,6 +226,12 @@ impl data_object_storage_registry::Trait for Test {
     type Event = MetaEvent;
     type DataObjectStorageRelationshipId = u64;
     type ContentIdExists = MockContent;
+    type Randomness = TestRandomness;
+    type StorageChallengePeriod = StorageChallengePeriod;
+    type StorageChallengeResponsePeriod = StorageChallengeResponsePeriod;
+    type MaxStorageChallengesPerRound = MaxStorageChallengesPerRound;
+    type StorageChallengeFailureHandler = ();
+    type WeightInfo = ();
 }
 
 impl membership::Trait for Test {
@@ -195,6 +240,7 @@ impl membership::Trait for Test {
     type SubscriptionId = u32;
     type PaidTermId = u32;
     type ActorId = u32;
+    type WeightInfo = ();
 }
 
 impl stake::Trait for Test {
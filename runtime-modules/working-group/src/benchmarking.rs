@@ -0,0 +1,310 @@
+//! Working group module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks_instance};
+use frame_support::StorageMap;
+use sp_std::prelude::*;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+const STAKE: u32 = 10_000;
+const FREE_BALANCE: u32 = 1_000_000;
+
+// Builds a text of the maximal length allowed by the constraint.
+fn max_text(constraint: InputValidationLengthConstraint) -> Vec<u8> {
+    vec![b'x'; constraint.max() as usize]
+}
+
+fn staking_policy<T: Trait<I>, I: Instance>() -> hiring::StakingPolicy<BalanceOf<T>, T::BlockNumber>
+{
+    hiring::StakingPolicy {
+        amount: STAKE.into(),
+        amount_mode: hiring::StakingAmountLimitMode::AtLeast,
+        crowded_out_unstaking_period_length: None,
+        review_period_expired_unstaking_period_length: None,
+    }
+}
+
+// Requires both the application and the role stakes.
+fn policy_commitment<T: Trait<I>, I: Instance>(
+) -> OpeningPolicyCommitment<T::BlockNumber, BalanceOf<T>> {
+    OpeningPolicyCommitment {
+        application_staking_policy: Some(staking_policy::<T, I>()),
+        role_staking_policy: Some(staking_policy::<T, I>()),
+        ..OpeningPolicyCommitment::default()
+    }
+}
+
+fn reward_policy<T: Trait<I>, I: Instance>() -> RewardPolicy<minting::BalanceOf<T>, T::BlockNumber>
+{
+    RewardPolicy {
+        amount_per_payout: 1u32.into(),
+        next_payment_at_block: <system::Module<T>>::block_number() + One::one(),
+        payout_interval: None,
+    }
+}
+
+/// Registers a funded member controlled by the returned account.
+fn create_member<T: Trait<I>, I: Instance>(id: u32) -> (T::MemberId, T::AccountId) {
+    let account_id: T::AccountId = account("member", id, SEED);
+    let member_id = membership::Module::<T>::members_created();
+
+    let mut handle = b"worker".to_vec();
+    handle.extend_from_slice(&id.to_be_bytes());
+
+    membership::ScreeningAuthority::<T>::put(account_id.clone());
+    membership::Module::<T>::add_screened_member(
+        RawOrigin::Signed(account_id.clone()).into(),
+        account_id.clone(),
+        Some(handle),
+        None,
+        None,
+    )
+    .expect("benchmark member must be registered");
+
+    CurrencyOf::<T>::make_free_balance_be(&account_id, FREE_BALANCE.into());
+
+    (member_id, account_id)
+}
+
+// Creates the mint funding the worker rewards.
+fn create_mint<T: Trait<I>, I: Instance>() {
+    let mint_id = minting::Module::<T>::add_mint(FREE_BALANCE.into(), None)
+        .expect("benchmark mint must be created");
+
+    Mint::<T, I>::put(mint_id);
+}
+
+fn add_opening<T: Trait<I>, I: Instance>(
+    origin: RawOrigin<T::AccountId>,
+    opening_type: OpeningType,
+) -> OpeningId<T> {
+    let opening_id = NextOpeningId::<T, I>::get();
+
+    Module::<T, I>::add_opening(
+        origin.into(),
+        hiring::ActivateOpeningAt::CurrentBlock,
+        policy_commitment::<T, I>(),
+        max_text(Module::<T, I>::opening_human_readable_text()),
+        opening_type,
+    )
+    .expect("opening must be added");
+
+    opening_id
+}
+
+fn apply_on_opening<T: Trait<I>, I: Instance>(
+    opening_id: OpeningId<T>,
+    member_id: T::MemberId,
+    account_id: &T::AccountId,
+) -> ApplicationId<T> {
+    let application_id = NextApplicationId::<T, I>::get();
+
+    Module::<T, I>::apply_on_opening(
+        RawOrigin::Signed(account_id.clone()).into(),
+        member_id,
+        opening_id,
+        account_id.clone(),
+        Some(STAKE.into()),
+        Some(STAKE.into()),
+        max_text(Module::<T, I>::application_human_readable_text()),
+    )
+    .expect("member must be able to apply");
+
+    application_id
+}
+
+// Runs the whole hiring flow for the new member and returns the new worker id and account.
+fn hire<T: Trait<I>, I: Instance>(
+    origin: RawOrigin<T::AccountId>,
+    opening_type: OpeningType,
+    id: u32,
+) -> (WorkerId<T>, T::AccountId) {
+    let (member_id, account_id) = create_member::<T, I>(id);
+    let opening_id = add_opening::<T, I>(origin.clone(), opening_type);
+    let application_id = apply_on_opening::<T, I>(opening_id, member_id, &account_id);
+
+    Module::<T, I>::begin_applicant_review(origin.clone().into(), opening_id)
+        .expect("review must begin");
+
+    let worker_id = NextWorkerId::<T, I>::get();
+    let mut application_ids = BTreeSet::new();
+    application_ids.insert(application_id);
+
+    Module::<T, I>::fill_opening(
+        origin.into(),
+        opening_id,
+        application_ids,
+        Some(reward_policy::<T, I>()),
+    )
+    .expect("opening must be filled");
+
+    (worker_id, account_id)
+}
+
+fn hire_lead<T: Trait<I>, I: Instance>() -> T::AccountId {
+    create_mint::<T, I>();
+
+    let (_, lead_account_id) = hire::<T, I>(RawOrigin::Root, OpeningType::Leader, 0);
+
+    lead_account_id
+}
+
+fn hire_worker<T: Trait<I>, I: Instance>(
+    lead_account_id: &T::AccountId,
+) -> (WorkerId<T>, T::AccountId) {
+    hire::<T, I>(
+        RawOrigin::Signed(lead_account_id.clone()),
+        OpeningType::Worker,
+        1,
+    )
+}
+
+benchmarks_instance! {
+    _ { }
+
+    update_role_account {
+        let lead = hire_lead::<T, I>();
+        let (worker_id, worker_account_id) = hire_worker::<T, I>(&lead);
+        let new_role_account_id: T::AccountId = account("role", 0, SEED);
+    }: _(RawOrigin::Signed(worker_account_id), worker_id, new_role_account_id.clone())
+    verify {
+        assert_eq!(Module::<T, I>::worker_by_id(worker_id).role_account_id, new_role_account_id);
+    }
+
+    update_reward_account {
+        let lead = hire_lead::<T, I>();
+        let (worker_id, worker_account_id) = hire_worker::<T, I>(&lead);
+        let new_reward_account_id: T::AccountId = account("reward", 0, SEED);
+    }: _(RawOrigin::Signed(worker_account_id), worker_id, new_reward_account_id)
+
+    update_reward_amount {
+        let lead = hire_lead::<T, I>();
+        let (worker_id, _) = hire_worker::<T, I>(&lead);
+    }: _(RawOrigin::Signed(lead), worker_id, 2u32.into())
+
+    leave_role {
+        let lead = hire_lead::<T, I>();
+        let (worker_id, worker_account_id) = hire_worker::<T, I>(&lead);
+        let rationale = max_text(Module::<T, I>::worker_exit_rationale_text());
+    }: _(RawOrigin::Signed(worker_account_id), worker_id, rationale)
+    verify {
+        assert!(!<WorkerById<T, I>>::contains_key(worker_id));
+    }
+
+    terminate_role {
+        let lead = hire_lead::<T, I>();
+        let (worker_id, _) = hire_worker::<T, I>(&lead);
+        let rationale = max_text(Module::<T, I>::worker_exit_rationale_text());
+    }: _(RawOrigin::Signed(lead), worker_id, rationale, true)
+    verify {
+        assert!(!<WorkerById<T, I>>::contains_key(worker_id));
+    }
+
+    add_opening {
+        let lead = hire_lead::<T, I>();
+        let opening_id = NextOpeningId::<T, I>::get();
+    }: _(
+        RawOrigin::Signed(lead),
+        hiring::ActivateOpeningAt::CurrentBlock,
+        policy_commitment::<T, I>(),
+        max_text(Module::<T, I>::opening_human_readable_text()),
+        OpeningType::Worker
+    )
+    verify {
+        assert!(<OpeningById<T, I>>::contains_key(opening_id));
+    }
+
+    accept_applications {
+        let lead = hire_lead::<T, I>();
+        let opening_id = NextOpeningId::<T, I>::get();
+        let activate_at = <system::Module<T>>::block_number() + One::one();
+        Module::<T, I>::add_opening(
+            RawOrigin::Signed(lead.clone()).into(),
+            hiring::ActivateOpeningAt::ExactBlock(activate_at),
+            policy_commitment::<T, I>(),
+            max_text(Module::<T, I>::opening_human_readable_text()),
+            OpeningType::Worker,
+        )?;
+    }: _(RawOrigin::Signed(lead), opening_id)
+
+    apply_on_opening {
+        let lead = hire_lead::<T, I>();
+        let opening_id = add_opening::<T, I>(RawOrigin::Signed(lead), OpeningType::Worker);
+        let (member_id, account_id) = create_member::<T, I>(1);
+        let application_id = NextApplicationId::<T, I>::get();
+    }: _(
+        RawOrigin::Signed(account_id.clone()),
+        member_id,
+        opening_id,
+        account_id,
+        Some(STAKE.into()),
+        Some(STAKE.into()),
+        max_text(Module::<T, I>::application_human_readable_text())
+    )
+    verify {
+        assert!(<ApplicationById<T, I>>::contains_key(application_id));
+    }
+
+    withdraw_application {
+        let lead = hire_lead::<T, I>();
+        let opening_id = add_opening::<T, I>(RawOrigin::Signed(lead), OpeningType::Worker);
+        let (member_id, account_id) = create_member::<T, I>(1);
+        let application_id = apply_on_opening::<T, I>(opening_id, member_id, &account_id);
+    }: _(RawOrigin::Signed(account_id), application_id)
+
+    terminate_application {
+        let lead = hire_lead::<T, I>();
+        let opening_id = add_opening::<T, I>(RawOrigin::Signed(lead.clone()), OpeningType::Worker);
+        let (member_id, account_id) = create_member::<T, I>(1);
+        let application_id = apply_on_opening::<T, I>(opening_id, member_id, &account_id);
+    }: _(RawOrigin::Signed(lead), application_id)
+
+    begin_applicant_review {
+        let lead = hire_lead::<T, I>();
+        let opening_id = add_opening::<T, I>(RawOrigin::Signed(lead.clone()), OpeningType::Worker);
+        let (member_id, account_id) = create_member::<T, I>(1);
+        apply_on_opening::<T, I>(opening_id, member_id, &account_id);
+    }: _(RawOrigin::Signed(lead), opening_id)
+
+    fill_opening {
+        let i in 1 .. T::MaxWorkerNumberLimit::get() - 1;
+
+        let lead = hire_lead::<T, I>();
+        let opening_id = add_opening::<T, I>(RawOrigin::Signed(lead.clone()), OpeningType::Worker);
+        let application_ids = (1..=i)
+            .map(|id| {
+                let (member_id, account_id) = create_member::<T, I>(id);
+                apply_on_opening::<T, I>(opening_id, member_id, &account_id)
+            })
+            .collect::<BTreeSet<_>>();
+        Module::<T, I>::begin_applicant_review(RawOrigin::Signed(lead.clone()).into(), opening_id)?;
+        let active_worker_count = Module::<T, I>::active_worker_count();
+    }: _(RawOrigin::Signed(lead), opening_id, application_ids, Some(reward_policy::<T, I>()))
+    verify {
+        assert_eq!(Module::<T, I>::active_worker_count(), active_worker_count + i);
+    }
+
+    slash_stake {
+        let lead = hire_lead::<T, I>();
+        let (worker_id, _) = hire_worker::<T, I>(&lead);
+    }: _(RawOrigin::Signed(lead), worker_id, STAKE.into())
+
+    decrease_stake {
+        let lead = hire_lead::<T, I>();
+        let (worker_id, _) = hire_worker::<T, I>(&lead);
+    }: _(RawOrigin::Signed(lead), worker_id, (STAKE / 2).into())
+
+    increase_stake {
+        let lead = hire_lead::<T, I>();
+        let (worker_id, worker_account_id) = hire_worker::<T, I>(&lead);
+    }: _(RawOrigin::Signed(worker_account_id), worker_id, STAKE.into())
+
+    set_mint_capacity {
+        create_mint::<T, I>();
+        let capacity: minting::BalanceOf<T> = (FREE_BALANCE * 2).into();
+    }: _(RawOrigin::Root, capacity)
+    verify {
+        assert_eq!(<minting::Module<T>>::mints(Module::<T, I>::mint()).capacity(), capacity);
+    }
+}
@@ -46,6 +46,8 @@
 // Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
 //#![warn(missing_docs)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 #[cfg(test)]
 mod tests;
 mod types;
@@ -55,6 +57,7 @@ mod errors;
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::storage::IterableStorageMap;
 use frame_support::traits::{Currency, ExistenceRequirement, Get, Imbalance, WithdrawReasons};
+use frame_support::weights::Weight;
 use frame_support::{decl_event, decl_module, decl_storage, ensure, print, StorageValue};
 use sp_arithmetic::traits::{Bounded, One, Zero};
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
@@ -127,7 +130,7 @@ type RewardSettings<T> = (
 );
 
 // Type simplification
-type WorkerOf<T> = Worker<
+pub type WorkerOf<T> = Worker<
     <T as system::Trait>::AccountId,
     <T as recurringrewards::Trait>::RewardRelationshipId,
     <T as stake::Trait>::StakeId,
@@ -161,6 +164,107 @@ pub trait Trait<I: Instance>:
 
     /// Defines max workers number in the working group.
     type MaxWorkerNumberLimit: Get<u32>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+
+    /// Handles the worker leaving or being terminated.
+    type WorkerExitHandler: WorkerExitHandler<Self, I>;
+
+    /// Handles the worker role account update.
+    type WorkerRoleAccountUpdateHandler: WorkerRoleAccountUpdateHandler<Self, I>;
+}
+
+/// Handles the worker exit from the working group (leaving or termination).
+pub trait WorkerExitHandler<T: Trait<I>, I: Instance> {
+    /// Invoked after the worker was removed from the working group. Receives the removed worker.
+    fn worker_exited(worker_id: &WorkerId<T>, worker: &WorkerOf<T>);
+}
+
+impl<T: Trait<I>, I: Instance> WorkerExitHandler<T, I> for () {
+    fn worker_exited(_worker_id: &WorkerId<T>, _worker: &WorkerOf<T>) {}
+}
+
+/// Handles the update of the worker role account.
+pub trait WorkerRoleAccountUpdateHandler<T: Trait<I>, I: Instance> {
+    /// Invoked after the worker role account was replaced. Receives the previous role account.
+    fn role_account_updated(worker_id: &WorkerId<T>, old_role_account_id: &T::AccountId);
+}
+
+impl<T: Trait<I>, I: Instance> WorkerRoleAccountUpdateHandler<T, I> for () {
+    fn role_account_updated(_worker_id: &WorkerId<T>, _old_role_account_id: &T::AccountId) {}
+}
+
+/// Weight functions needed for the working group module.
+pub trait WeightInfo {
+    fn update_role_account() -> Weight;
+    fn update_reward_account() -> Weight;
+    fn update_reward_amount() -> Weight;
+    fn leave_role() -> Weight;
+    fn terminate_role() -> Weight;
+    fn add_opening() -> Weight;
+    fn accept_applications() -> Weight;
+    fn apply_on_opening() -> Weight;
+    fn withdraw_application() -> Weight;
+    fn terminate_application() -> Weight;
+    fn begin_applicant_review() -> Weight;
+    fn fill_opening(i: u32) -> Weight;
+    fn slash_stake() -> Weight;
+    fn decrease_stake() -> Weight;
+    fn increase_stake() -> Weight;
+    fn set_mint_capacity() -> Weight;
+}
+
+/// Flat weights for the test runtimes.
+impl WeightInfo for () {
+    fn update_role_account() -> Weight {
+        10_000_000
+    }
+    fn update_reward_account() -> Weight {
+        10_000_000
+    }
+    fn update_reward_amount() -> Weight {
+        10_000_000
+    }
+    fn leave_role() -> Weight {
+        10_000_000
+    }
+    fn terminate_role() -> Weight {
+        10_000_000
+    }
+    fn add_opening() -> Weight {
+        10_000_000
+    }
+    fn accept_applications() -> Weight {
+        10_000_000
+    }
+    fn apply_on_opening() -> Weight {
+        10_000_000
+    }
+    fn withdraw_application() -> Weight {
+        10_000_000
+    }
+    fn terminate_application() -> Weight {
+        10_000_000
+    }
+    fn begin_applicant_review() -> Weight {
+        10_000_000
+    }
+    fn fill_opening(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn slash_stake() -> Weight {
+        10_000_000
+    }
+    fn decrease_stake() -> Weight {
+        10_000_000
+    }
+    fn increase_stake() -> Weight {
+        10_000_000
+    }
+    fn set_mint_capacity() -> Weight {
+        10_000_000
+    }
 }
 
 decl_event!(
@@ -357,7 +461,7 @@ decl_module! {
         // ****************** Roles lifecycle **********************
 
         /// Update the associated role account of the active worker/lead.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::update_role_account()]
         pub fn update_role_account(
             origin,
             worker_id: WorkerId<T>,
@@ -380,12 +484,17 @@ decl_module! {
                 worker.role_account_id = new_role_account_id.clone()
             });
 
+            T::WorkerRoleAccountUpdateHandler::role_account_updated(
+                &worker_id,
+                &worker.role_account_id,
+            );
+
             // Trigger event
             Self::deposit_event(RawEvent::WorkerRoleAccountUpdated(worker_id, new_role_account_id));
         }
 
         /// Update the reward account associated with a set reward relationship for the active worker.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::update_reward_account()]
         pub fn update_reward_account(
             origin,
             worker_id: WorkerId<T>,
@@ -417,7 +526,7 @@ decl_module! {
 
         /// Update the reward amount associated with a set reward relationship for the active worker.
         /// Require signed leader origin or the root (to update leader reward amount).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::update_reward_amount()]
         pub fn update_reward_amount(
             origin,
             worker_id: WorkerId<T>,
@@ -451,7 +560,7 @@ decl_module! {
         }
 
         /// Leave the role by the active worker.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::leave_role()]
         pub fn leave_role(
             origin,
             worker_id: WorkerId<T>,
@@ -474,7 +583,7 @@ decl_module! {
 
         /// Terminate the active worker by the lead.
         /// Require signed leader origin or the root (to terminate the leader role).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::terminate_role()]
         pub fn terminate_role(
             origin,
             worker_id: WorkerId<T>,
@@ -512,7 +621,7 @@ decl_module! {
 
         /// Add an opening for a worker role.
         /// Require signed leader origin or the root (to add opening for the leader position).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::add_opening()]
         pub fn add_opening(
             origin,
             activate_at: hiring::ActivateOpeningAt<T::BlockNumber>,
@@ -568,7 +677,7 @@ decl_module! {
 
         /// Begin accepting worker applications to an opening that is active.
         /// Require signed leader origin or the root (to accept applications for the leader position).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::accept_applications()]
         pub fn accept_applications(origin, opening_id: OpeningId<T>)  {
             // Ensure opening exists in this working group
             // NB: Even though call to hiring module will have implicit check for
@@ -595,7 +704,7 @@ decl_module! {
         }
 
         /// Apply on a worker opening.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::apply_on_opening()]
         pub fn apply_on_opening(
             origin,
             member_id: T::MemberId,
@@ -690,7 +799,7 @@ decl_module! {
         }
 
         /// Withdraw the worker application. Can be done by the worker itself only.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::withdraw_application()]
         pub fn withdraw_application(
             origin,
             application_id: ApplicationId<T>
@@ -726,7 +835,7 @@ decl_module! {
         }
 
         /// Terminate the worker application. Can be done by the lead only.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::terminate_application()]
         pub fn terminate_application(
             origin,
             application_id: ApplicationId<T>
@@ -758,7 +867,7 @@ decl_module! {
 
         /// Begin reviewing, and therefore not accepting new applications.
         /// Require signed leader origin or the root (to begin review applications for the leader position).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::begin_applicant_review()]
         pub fn begin_applicant_review(origin, opening_id: OpeningId<T>) {
             // Ensure opening exists
             // NB: Even though call to hiring modul will have implicit check for
@@ -784,7 +893,7 @@ decl_module! {
 
         /// Fill opening for worker/lead.
         /// Require signed leader origin or the root (to fill opening for the leader position).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::fill_opening(successful_application_ids.len() as u32)]
         pub fn fill_opening(
             origin,
             opening_id: OpeningId<T>,
@@ -888,38 +997,18 @@ decl_module! {
         /// Slashes the worker stake, demands a leader origin. No limits, no actions on zero stake.
         /// If slashing balance greater than the existing stake - stake is slashed to zero.
         /// Require signed leader origin or the root (to slash the leader stake).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::slash_stake()]
         pub fn slash_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
             // Ensure lead is set or it is the council terminating the leader.
             Self::ensure_origin_for_leader(origin, worker_id)?;
 
-            // Ensuring worker actually exists.
-            let worker = Self::ensure_worker_exists(&worker_id)?;
-
-            ensure!(balance != <BalanceOf<T>>::zero(), Error::<T, I>::StakeBalanceCannotBeZero);
-
-            let stake_profile = worker.role_stake_profile.ok_or(Error::<T, I>::NoWorkerStakeProfile)?;
-
-            //
-            // == MUTATION SAFE ==
-            //
-
-            // This external module call both checks and mutates the state.
-            ensure_on_wrapped_error!(
-                <stake::Module<T>>::slash_immediate(
-                    &stake_profile.stake_id,
-                    balance,
-                    false
-                )
-            )?;
-
-            Self::deposit_event(RawEvent::StakeSlashed(worker_id));
+            Self::slash_worker_stake(&worker_id, balance)?;
         }
 
         /// Decreases the worker/lead stake and returns the remainder to the worker role_account_id.
         /// Can be decreased to zero, no actions on zero stake.
         /// Require signed leader origin or the root (to decrease the leader stake).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::decrease_stake()]
         pub fn decrease_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
             // Ensure lead is set or it is the council terminating the leader.
             Self::ensure_origin_for_leader(origin, worker_id)?;
@@ -948,7 +1037,7 @@ decl_module! {
 
         /// Increases the worker/lead stake, demands a worker origin. Transfers tokens from the worker
         /// role_account_id to the stake. No limits on the stake.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::increase_stake()]
         pub fn increase_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
             // Checks worker origin, worker existence
             let worker = Self::ensure_worker_signed(origin, &worker_id)?;
@@ -974,7 +1063,7 @@ decl_module! {
         }
 
         /// Sets the capacity to enable working group budget. Requires root origin.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::set_mint_capacity()]
         pub fn set_mint_capacity(
             origin,
             new_capacity: minting::BalanceOf<T>
@@ -1376,6 +1465,41 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         <NegativeImbalance<T>>::zero()
     }
 
+    /// Slashes the worker stake without the origin checks. Used by the `slash_stake` extrinsic and
+    /// by the runtime to punish the workers for the failures detected on-chain.
+    /// If slashing balance greater than the existing stake - stake is slashed to zero.
+    pub fn slash_worker_stake(
+        worker_id: &WorkerId<T>,
+        balance: BalanceOf<T>,
+    ) -> Result<(), Error<T, I>> {
+        // Ensuring worker actually exists.
+        let worker = Self::ensure_worker_exists(worker_id)?;
+
+        ensure!(
+            balance != <BalanceOf<T>>::zero(),
+            Error::<T, I>::StakeBalanceCannotBeZero
+        );
+
+        let stake_profile = worker
+            .role_stake_profile
+            .ok_or(Error::<T, I>::NoWorkerStakeProfile)?;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        // This external module call both checks and mutates the state.
+        ensure_on_wrapped_error!(<stake::Module<T>>::slash_immediate(
+            &stake_profile.stake_id,
+            balance,
+            false
+        ))?;
+
+        Self::deposit_event(RawEvent::StakeSlashed(*worker_id));
+
+        Ok(())
+    }
+
     /// Returns all existing worker id list excluding the current leader worker id.
     pub fn get_regular_worker_ids() -> Vec<WorkerId<T>> {
         let lead_worker_id = Self::current_lead();
@@ -1465,6 +1589,8 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         WorkerById::<T, I>::remove(worker_id);
         Self::decrease_active_worker_counter();
 
+        T::WorkerExitHandler::worker_exited(worker_id, worker);
+
         // Trigger the event
         let event = match exit_initiation_origin {
             ExitInitiationOrigin::Lead => {
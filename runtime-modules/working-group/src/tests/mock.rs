@@ -102,6 +102,7 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 impl common::currency::GovernanceCurrency for Test {
@@ -138,6 +139,9 @@ parameter_types! {
 impl Trait<TestWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
+    type WorkerExitHandler = ();
+    type WorkerRoleAccountUpdateHandler = ();
 }
 
 pub type Membership = membership::Module<Test>;
@@ -42,3 +42,25 @@ impl content_directory::ActorAuthenticator for Runtime {
         .is_ok()
     }
 }
+
+#[cfg(feature = "runtime-benchmarks")]
+impl content_directory::benchmarking::Trait for Runtime {
+    fn create_lead() -> AccountId {
+        use frame_support::{StorageMap, StorageValue};
+
+        let lead_account_id: AccountId = frame_benchmarking::account("lead", 0, 0);
+        let lead_id =
+            working_group::NextWorkerId::<Runtime, ContentDirectoryWorkingGroupInstance>::get();
+
+        working_group::WorkerById::<Runtime, ContentDirectoryWorkingGroupInstance>::insert(
+            lead_id,
+            working_group::Worker::new(&MemberId::default(), &lead_account_id, &None, &None),
+        );
+        working_group::NextWorkerId::<Runtime, ContentDirectoryWorkingGroupInstance>::put(
+            lead_id + 1,
+        );
+        working_group::CurrentLead::<Runtime, ContentDirectoryWorkingGroupInstance>::put(lead_id);
+
+        lead_account_id
+    }
+}
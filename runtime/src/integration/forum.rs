@@ -9,12 +9,12 @@
  * run convention should be.
  */
 
-use crate::{AccountId, Runtime};
+use crate::{AccountId, ForumWorkingGroup, MemberId, Runtime};
 
 /// Shim registry which will proxy ForumUserRegistry behaviour to the members module
 pub struct ShimMembershipRegistry {}
 
-impl forum::ForumUserRegistry<AccountId> for ShimMembershipRegistry {
+impl forum::ForumUserRegistry<AccountId, MemberId> for ShimMembershipRegistry {
     fn get_forum_user(id: &AccountId) -> Option<forum::ForumUser<AccountId>> {
         if membership::Module::<Runtime>::is_member_account(id) {
             // For now we don't retrieve the members profile since it is not used for anything,
@@ -28,4 +28,52 @@ impl forum::ForumUserRegistry<AccountId> for ShimMembershipRegistry {
             None
         }
     }
+
+    fn is_forum_user_account(member_id: &MemberId, account_id: &AccountId) -> bool {
+        membership::Module::<Runtime>::ensure_membership(*member_id).map_or(false, |membership| {
+            membership.root_account == *account_id || membership.controller_account == *account_id
+        })
+    }
+}
+
+/// Forum working group lead has the forum sudo authority, its workers moderate the whole forum.
+pub struct ForumWorkingGroupRolesValidator;
+
+impl forum::ForumRolesValidator<AccountId> for ForumWorkingGroupRolesValidator {
+    fn is_forum_sudo(account_id: &AccountId) -> bool {
+        ForumWorkingGroup::current_lead()
+            .and_then(|lead_id| ForumWorkingGroup::ensure_worker_exists(&lead_id).ok())
+            .map_or(false, |lead| lead.role_account_id == *account_id)
+    }
+
+    fn is_forum_moderator(account_id: &AccountId) -> bool {
+        ForumWorkingGroup::get_regular_worker_ids()
+            .iter()
+            .filter_map(|worker_id| ForumWorkingGroup::ensure_worker_exists(worker_id).ok())
+            .any(|worker| worker.role_account_id == *account_id)
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl forum::benchmarking::Trait for Runtime {
+    fn create_forum_user(account_id: &AccountId) -> MemberId {
+        use codec::Encode;
+        use frame_support::StorageValue;
+
+        let member_id = membership::Module::<Runtime>::members_created();
+
+        // The screening authority is the cheapest way to register a member with a unique handle.
+        membership::ScreeningAuthority::<Runtime>::put(account_id.clone());
+
+        membership::Module::<Runtime>::add_screened_member(
+            system::RawOrigin::Signed(account_id.clone()).into(),
+            account_id.clone(),
+            Some(account_id.encode()),
+            None,
+            None,
+        )
+        .expect("benchmark forum user must be registered");
+
+        member_id
+    }
 }
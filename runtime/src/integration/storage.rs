@@ -1,36 +1,153 @@
-use frame_support::traits::Randomness;
+use frame_support::traits::{Get, Randomness};
+use frame_support::StorageMap;
 use sp_std::vec::Vec;
 
-use crate::{ActorId, Runtime};
+use crate::{ActorId, ContentDirectory, DataDirectory, EntityId, MemberId, Runtime};
 
 /// Provides random storage provider id. We use it when assign the content to the storage provider.
 pub struct StorageProviderHelper;
 
 impl storage::data_directory::StorageProviderHelper<Runtime> for StorageProviderHelper {
-    fn get_random_storage_provider() -> Result<ActorId, &'static str> {
+    fn get_random_storage_provider(content_size: u64) -> Result<ActorId, &'static str> {
         let ids = crate::StorageWorkingGroup::get_all_worker_ids();
 
-        let live_ids: Vec<ActorId> = ids
+        // Live storage providers with their free storage space, if declared.
+        let live_storage_providers: Vec<(ActorId, Option<u64>)> = ids
             .into_iter()
             .filter(|id| !<service_discovery::Module<Runtime>>::is_account_info_expired(id))
+            .map(|id| {
+                let free_space =
+                    DataDirectory::storage_capacity_by_storage_provider_id(id).free_space();
+                (id, free_space)
+            })
             .collect();
 
-        if live_ids.is_empty() {
-            Err("No valid storage provider found.")
-        } else {
-            let index = Self::random_index(live_ids.len());
-            Ok(live_ids[index])
+        // Storage providers able to store the content with their declared free storage space.
+        let candidates: Vec<(ActorId, u64)> = live_storage_providers
+            .iter()
+            .filter_map(|(id, free_space)| free_space.map(|free_space| (*id, free_space)))
+            .filter(|(_, free_space)| *free_space > 0 && *free_space >= content_size)
+            .collect();
+
+        // The chance to be chosen is proportional to the free storage space.
+        let total_free_space: u128 = candidates
+            .iter()
+            .map(|(_, free_space)| u128::from(*free_space))
+            .sum();
+
+        if total_free_space == 0 {
+            // Storage providers which have not declared their capacity yet are not limited.
+            let undeclared_capacity_ids: Vec<ActorId> = live_storage_providers
+                .into_iter()
+                .filter(|(_, free_space)| free_space.is_none())
+                .map(|(id, _)| id)
+                .collect();
+
+            if undeclared_capacity_ids.is_empty() {
+                return Err("No valid storage provider found.");
+            }
+
+            let index = Self::random_number(undeclared_capacity_ids.len() as u128) as usize;
+
+            return Ok(undeclared_capacity_ids[index]);
         }
+
+        let mut point = Self::random_number(total_free_space);
+
+        candidates
+            .into_iter()
+            .find(|(_, free_space)| {
+                let free_space = u128::from(*free_space);
+                if point < free_space {
+                    true
+                } else {
+                    point -= free_space;
+                    false
+                }
+            })
+            .map(|(id, _)| id)
+            .ok_or("No valid storage provider found.")
     }
 }
 
 impl StorageProviderHelper {
-    fn random_index(upper_bound: usize) -> usize {
+    fn random_number(upper_bound: u128) -> u128 {
         let seed = crate::RandomnessCollectiveFlip::random_seed();
-        let mut rand: u64 = 0;
-        for offset in 0..8 {
-            rand += (seed.as_ref()[offset] as u64) << offset;
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&seed.as_ref()[..16]);
+
+        u128::from_le_bytes(bytes) % upper_bound
+    }
+}
+
+/// Content directory entities of the Channel class controlled by the member serve as its channels.
+pub struct ChannelOwnershipValidator;
+
+impl storage::data_directory::ChannelOwnershipValidator<Runtime> for ChannelOwnershipValidator {
+    fn is_channel_owner(member_id: &MemberId, channel_id: &EntityId) -> bool {
+        if !<content_directory::EntityById<Runtime>>::contains_key(channel_id) {
+            return false;
         }
-        (rand as usize) % upper_bound
+
+        let channel = ContentDirectory::entity_by_id(channel_id);
+
+        channel.get_class_id() == crate::ChannelClassId::get()
+            && *channel.get_permissions_ref().get_controller()
+                == content_directory::EntityController::Member(*member_id)
+    }
+}
+
+/// Slashes the role stake of the storage provider that failed the storage challenge.
+pub struct StorageChallengeFailureHandler;
+
+impl storage::data_object_storage_registry::StorageChallengeFailureHandler<Runtime>
+    for StorageChallengeFailureHandler
+{
+    fn storage_challenge_failed(storage_provider_id: &ActorId) {
+        let slash_amount = crate::StorageChallengeFailureSlash::get();
+
+        // Stakes smaller than the slash amount are slashed completely. Zero slash amount and
+        // the storage providers without stake are skipped.
+        let _ = crate::StorageWorkingGroup::slash_worker_stake(storage_provider_id, slash_amount);
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl storage::benchmarking::Trait for Runtime {
+    fn make_storage_provider_available(storage_provider_id: ActorId) {
+        // The liaison is chosen among the storage providers with a non-expired account info and
+        // enough free storage space.
+        <service_discovery::AccountInfoByStorageProviderId<Runtime>>::insert(
+            storage_provider_id,
+            service_discovery::ServiceProviderRecord {
+                identity: Vec::new(),
+                expires_at: crate::BlockNumber::max_value(),
+            },
+        );
+        <storage::data_directory::StorageCapacityByStorageProviderId<Runtime>>::insert(
+            storage_provider_id,
+            storage::data_directory::StorageCapacity {
+                capacity: Some(u64::max_value()),
+                used: 0,
+            },
+        );
+    }
+
+    fn insert_channel(owner: MemberId) -> EntityId {
+        use frame_support::StorageValue;
+
+        let channel_id = ContentDirectory::next_entity_id();
+        <content_directory::EntityById<Runtime>>::insert(
+            channel_id,
+            content_directory::Entity::new(
+                content_directory::EntityController::Member(owner),
+                crate::ChannelClassId::get(),
+                Default::default(),
+                Default::default(),
+            ),
+        );
+        content_directory::NextEntityId::<Runtime>::put(channel_id + 1);
+
+        channel_id
     }
 }
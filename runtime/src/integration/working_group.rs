@@ -1,8 +1,18 @@
+use common::working_group::WorkingGroup;
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
 use frame_support::StorageMap;
+use sp_runtime::DispatchResult;
 use sp_std::marker::PhantomData;
 
-use crate::{ContentDirectoryWorkingGroupInstance, StorageWorkingGroupInstance};
+use crate::weights::working_group::WeightInfo as WorkingGroupWeightInfo;
+use crate::{
+    AccountId, ActorId, ContentDirectoryWorkingGroup, ContentDirectoryWorkingGroupInstance,
+    DataObjectStorageRegistry, Discovery, Forum, ForumWorkingGroup, ForumWorkingGroupInstance,
+    Origin, Runtime, StorageWorkingGroup, StorageWorkingGroupInstance,
+};
 use stake::{BalanceOf, NegativeImbalance};
+use working_group::WeightInfo;
 
 pub struct ContentDirectoryWGStakingEventsHandler<T> {
     pub marker: PhantomData<T>,
@@ -91,3 +101,181 @@ impl<T: stake::Trait + working_group::Trait<StorageWorkingGroupInstance>>
         remaining_imbalance
     }
 }
+
+pub struct ForumWgStakingEventsHandler<T> {
+    pub marker: PhantomData<T>,
+}
+
+impl<T: stake::Trait + working_group::Trait<ForumWorkingGroupInstance>>
+    stake::StakingEventsHandler<T> for ForumWgStakingEventsHandler<T>
+{
+    /// Unstake remaining sum back to the source_account_id
+    fn unstaked(
+        stake_id: &<T as stake::Trait>::StakeId,
+        _unstaked_amount: BalanceOf<T>,
+        remaining_imbalance: NegativeImbalance<T>,
+    ) -> NegativeImbalance<T> {
+        // Stake not related to a staked role managed by the hiring module.
+        if !hiring::ApplicationIdByStakingId::<T>::contains_key(*stake_id) {
+            return remaining_imbalance;
+        }
+
+        let hiring_application_id = hiring::ApplicationIdByStakingId::<T>::get(*stake_id);
+
+        if working_group::MemberIdByHiringApplicationId::<T, ForumWorkingGroupInstance>::contains_key(
+            hiring_application_id,
+        ) {
+            return <working_group::Module<T, ForumWorkingGroupInstance>>::refund_working_group_stake(
+				*stake_id,
+				remaining_imbalance,
+			);
+        }
+
+        remaining_imbalance
+    }
+
+    /// Empty handler for the slashing.
+    fn slashed(
+        _: &<T as stake::Trait>::StakeId,
+        _: Option<<T as stake::Trait>::SlashId>,
+        _: BalanceOf<T>,
+        _: BalanceOf<T>,
+        remaining_imbalance: NegativeImbalance<T>,
+    ) -> NegativeImbalance<T> {
+        remaining_imbalance
+    }
+}
+
+/// Authorizes the workers of the working groups by their role accounts.
+pub struct WorkerOriginValidator;
+
+impl service_discovery::WorkerOriginValidator<Runtime> for WorkerOriginValidator {
+    fn ensure_worker_origin(
+        origin: Origin,
+        working_group: WorkingGroup,
+        worker_id: &ActorId,
+    ) -> DispatchResult {
+        match working_group {
+            WorkingGroup::Storage => {
+                StorageWorkingGroup::ensure_worker_signed(origin, worker_id).map(|_| ())
+            }
+            WorkingGroup::Content => {
+                ContentDirectoryWorkingGroup::ensure_worker_signed(origin, worker_id).map(|_| ())
+            }
+            WorkingGroup::Forum => {
+                ForumWorkingGroup::ensure_worker_signed(origin, worker_id).map(|_| ())
+            }
+        }
+    }
+}
+
+/// Removes the service discovery records of the storage providers on their exit and deactivates
+/// their ready storage relationships.
+pub struct StorageWorkerExitHandler;
+
+impl working_group::WorkerExitHandler<Runtime, StorageWorkingGroupInstance>
+    for StorageWorkerExitHandler
+{
+    fn worker_exited(worker_id: &ActorId, _worker: &working_group::WorkerOf<Runtime>) {
+        Discovery::remove_worker_records(WorkingGroup::Storage, worker_id);
+
+        DataObjectStorageRegistry::deactivate_storage_provider_relationships(worker_id);
+    }
+}
+
+/// Removes the service discovery records of the content directory workers on their exit.
+pub struct ContentDirectoryWorkerExitHandler;
+
+impl working_group::WorkerExitHandler<Runtime, ContentDirectoryWorkingGroupInstance>
+    for ContentDirectoryWorkerExitHandler
+{
+    fn worker_exited(worker_id: &ActorId, _worker: &working_group::WorkerOf<Runtime>) {
+        Discovery::remove_worker_records(WorkingGroup::Content, worker_id);
+    }
+}
+
+/// Revokes the category moderation rights of the forum workers on their exit.
+pub struct ForumWorkerExitHandler;
+
+impl working_group::WorkerExitHandler<Runtime, ForumWorkingGroupInstance>
+    for ForumWorkerExitHandler
+{
+    fn worker_exited(_worker_id: &ActorId, worker: &working_group::WorkerOf<Runtime>) {
+        Forum::remove_moderator_from_all_categories(&worker.role_account_id);
+    }
+}
+
+/// Revokes the category moderation rights of the replaced forum worker role accounts.
+pub struct ForumWorkerRoleAccountUpdateHandler;
+
+impl working_group::WorkerRoleAccountUpdateHandler<Runtime, ForumWorkingGroupInstance>
+    for ForumWorkerRoleAccountUpdateHandler
+{
+    fn role_account_updated(_worker_id: &ActorId, old_role_account_id: &AccountId) {
+        Forum::remove_moderator_from_all_categories(old_role_account_id);
+    }
+}
+
+/// Forum working group weights. Updating the role account and the worker exit also revoke the
+/// category moderation rights of the role account.
+pub struct ForumWorkingGroupWeightInfo;
+
+impl ForumWorkingGroupWeightInfo {
+    fn remove_moderator_from_all_categories() -> Weight {
+        let max_moderated_categories = Weight::from(crate::MaxModeratedCategories::get());
+
+        DbWeight::get().reads_writes(1, max_moderated_categories.saturating_add(1))
+            + max_moderated_categories.saturating_mul(5_000_000)
+    }
+}
+
+impl working_group::WeightInfo for ForumWorkingGroupWeightInfo {
+    fn update_role_account() -> Weight {
+        WorkingGroupWeightInfo::update_role_account() + Self::remove_moderator_from_all_categories()
+    }
+    fn update_reward_account() -> Weight {
+        WorkingGroupWeightInfo::update_reward_account()
+    }
+    fn update_reward_amount() -> Weight {
+        WorkingGroupWeightInfo::update_reward_amount()
+    }
+    fn leave_role() -> Weight {
+        WorkingGroupWeightInfo::leave_role() + Self::remove_moderator_from_all_categories()
+    }
+    fn terminate_role() -> Weight {
+        WorkingGroupWeightInfo::terminate_role() + Self::remove_moderator_from_all_categories()
+    }
+    fn add_opening() -> Weight {
+        WorkingGroupWeightInfo::add_opening()
+    }
+    fn accept_applications() -> Weight {
+        WorkingGroupWeightInfo::accept_applications()
+    }
+    fn apply_on_opening() -> Weight {
+        WorkingGroupWeightInfo::apply_on_opening()
+    }
+    fn withdraw_application() -> Weight {
+        WorkingGroupWeightInfo::withdraw_application()
+    }
+    fn terminate_application() -> Weight {
+        WorkingGroupWeightInfo::terminate_application()
+    }
+    fn begin_applicant_review() -> Weight {
+        WorkingGroupWeightInfo::begin_applicant_review()
+    }
+    fn fill_opening(i: u32) -> Weight {
+        WorkingGroupWeightInfo::fill_opening(i)
+    }
+    fn slash_stake() -> Weight {
+        WorkingGroupWeightInfo::slash_stake()
+    }
+    fn decrease_stake() -> Weight {
+        WorkingGroupWeightInfo::decrease_stake()
+    }
+    fn increase_stake() -> Weight {
+        WorkingGroupWeightInfo::increase_stake()
+    }
+    fn set_mint_capacity() -> Weight {
+        WorkingGroupWeightInfo::set_mint_capacity()
+    }
+}
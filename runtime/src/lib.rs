@@ -19,6 +19,7 @@ pub mod primitives;
 mod runtime_api;
 #[cfg(test)]
 mod tests; // Runtime integration tests
+mod weights;
 
 use frame_support::traits::KeyOwnerProofSystem;
 use frame_support::weights::{
@@ -75,10 +76,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("joystream-node"),
     impl_name: create_runtime_str!("joystream-node"),
     authoring_version: 7,
-    spec_version: 9,
+    spec_version: 10,
     impl_version: 0,
     apis: crate::runtime_api::EXPORTED_RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
 };
 
 /// The version information used to identify this runtime when compiled natively.
@@ -425,6 +426,7 @@ impl content_directory::Trait for Runtime {
     type TextMaxLengthConstraint = TextMaxLengthConstraint;
     type HashedTextMaxLengthConstraint = HashedTextMaxLengthConstraint;
     type IndividualEntitiesCreationLimit = IndividualEntitiesCreationLimit;
+    type WeightInfo = weights::content_directory::WeightInfo;
 }
 
 impl hiring::Trait for Runtime {
@@ -456,7 +458,10 @@ impl stake::Trait for Runtime {
         crate::integration::proposals::StakingEventsHandler<Self>,
         (
             crate::integration::working_group::ContentDirectoryWGStakingEventsHandler<Self>,
-            crate::integration::working_group::StorageWgStakingEventsHandler<Self>,
+            (
+                crate::integration::working_group::StorageWgStakingEventsHandler<Self>,
+                crate::integration::working_group::ForumWgStakingEventsHandler<Self>,
+            ),
         ),
     );
     type StakeId = u64;
@@ -474,39 +479,83 @@ impl common::currency::GovernanceCurrency for Runtime {
 impl governance::election::Trait for Runtime {
     type Event = Event;
     type CouncilElected = (Council, integration::proposals::CouncilElectedHandler);
+    type WeightInfo = weights::election::WeightInfo;
 }
 
 impl governance::council::Trait for Runtime {
     type Event = Event;
     type CouncilTermEnded = (CouncilElection,);
+    type WeightInfo = weights::council::WeightInfo;
+}
+
+parameter_types! {
+    pub const MemoDepositPerByte: Balance = 1;
+    pub const MessageDeposit: Balance = 100;
+    pub const MaxMessageLength: u32 = 4096;
+    pub const MaxInboxMessages: u32 = 100;
+    pub const MaxInboxSize: u32 = 64 * 1024;
+    pub const MessageLifetime: BlockNumber = 30 * DAYS;
 }
 
 impl memo::Trait for Runtime {
     type Event = Event;
+    type MemoDepositPerByte = MemoDepositPerByte;
+    type MessageDeposit = MessageDeposit;
+    type MaxMessageLength = MaxMessageLength;
+    type MaxInboxMessages = MaxInboxMessages;
+    type MaxInboxSize = MaxInboxSize;
+    type MessageLifetime = MessageLifetime;
+    type WeightInfo = weights::memo::WeightInfo;
 }
 
 parameter_types! {
     pub const MaxObjectsPerInjection: u32 = 100;
+    pub const DefaultQuotaObjectsLimit: u64 = 1_000;
+    pub const DefaultQuotaSizeLimit: u64 = 10 * 1024 * 1024 * 1024; // 10 GB
+    // The content directory schemas create the Channel class first.
+    pub const ChannelClassId: u64 = 1;
 }
 
 impl storage::data_object_type_registry::Trait for Runtime {
     type Event = Event;
     type DataObjectTypeId = u64;
+    type WeightInfo = weights::data_object_type_registry::WeightInfo;
 }
 
 impl storage::data_directory::Trait for Runtime {
     type Event = Event;
     type ContentId = ContentId;
+    type ChannelId = EntityId;
     type StorageProviderHelper = integration::storage::StorageProviderHelper;
     type IsActiveDataObjectType = DataObjectTypeRegistry;
+    type DataObjectTypeProvider = DataObjectTypeRegistry;
     type MemberOriginValidator = MembershipOriginValidator<Self>;
+    type ChannelOwnershipValidator = integration::storage::ChannelOwnershipValidator;
+    type ContentRemovalHandler = DataObjectStorageRegistry;
+    type ContentAcceptanceHandler = DataObjectStorageRegistry;
     type MaxObjectsPerInjection = MaxObjectsPerInjection;
+    type DefaultQuotaObjectsLimit = DefaultQuotaObjectsLimit;
+    type DefaultQuotaSizeLimit = DefaultQuotaSizeLimit;
+    type WeightInfo = weights::data_directory::WeightInfo;
+}
+
+parameter_types! {
+    pub const StorageChallengePeriod: BlockNumber = HOURS;
+    pub const StorageChallengeResponsePeriod: BlockNumber = 10 * MINUTES;
+    pub const MaxStorageChallengesPerRound: u32 = 10;
+    pub const StorageChallengeFailureSlash: Balance = 0;
 }
 
 impl storage::data_object_storage_registry::Trait for Runtime {
     type Event = Event;
     type DataObjectStorageRelationshipId = u64;
     type ContentIdExists = DataDirectory;
+    type Randomness = RandomnessCollectiveFlip;
+    type StorageChallengePeriod = StorageChallengePeriod;
+    type StorageChallengeResponsePeriod = StorageChallengeResponsePeriod;
+    type MaxStorageChallengesPerRound = MaxStorageChallengesPerRound;
+    type StorageChallengeFailureHandler = integration::storage::StorageChallengeFailureHandler;
+    type WeightInfo = weights::data_object_storage_registry::WeightInfo;
 }
 
 impl membership::Trait for Runtime {
@@ -515,15 +564,31 @@ impl membership::Trait for Runtime {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = ActorId;
+    type WeightInfo = weights::membership::WeightInfo;
+}
+
+parameter_types! {
+    pub const MaxPollAlternatives: u32 = 10;
+    pub const MaxStickyThreads: u32 = 5;
+    pub const MaxModeratedCategories: u32 = 20;
 }
 
 impl forum::Trait for Runtime {
     type Event = Event;
     type MembershipRegistry = integration::forum::ShimMembershipRegistry;
+    type ForumUserId = MemberId;
     type ThreadId = ThreadId;
     type PostId = PostId;
+    type MaxPollAlternatives = MaxPollAlternatives;
+    type MaxStickyThreads = MaxStickyThreads;
+    type MaxModeratedCategories = MaxModeratedCategories;
+    type ForumRolesValidator = integration::forum::ForumWorkingGroupRolesValidator;
+    type WeightInfo = weights::forum::WeightInfo;
 }
 
+// The forum working group instance alias.
+pub type ForumWorkingGroupInstance = working_group::Instance1;
+
 // The storage working group instance alias.
 pub type StorageWorkingGroupInstance = working_group::Instance2;
 
@@ -534,18 +599,42 @@ parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 100;
 }
 
+impl working_group::Trait<ForumWorkingGroupInstance> for Runtime {
+    type Event = Event;
+    type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = integration::working_group::ForumWorkingGroupWeightInfo;
+    type WorkerExitHandler = integration::working_group::ForumWorkerExitHandler;
+    type WorkerRoleAccountUpdateHandler =
+        integration::working_group::ForumWorkerRoleAccountUpdateHandler;
+}
+
 impl working_group::Trait<StorageWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = weights::working_group::WeightInfo;
+    type WorkerExitHandler = integration::working_group::StorageWorkerExitHandler;
+    type WorkerRoleAccountUpdateHandler = ();
 }
 
 impl working_group::Trait<ContentDirectoryWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = weights::working_group::WeightInfo;
+    type WorkerExitHandler = integration::working_group::ContentDirectoryWorkerExitHandler;
+    type WorkerRoleAccountUpdateHandler = ();
+}
+
+parameter_types! {
+    pub const MaxAccountInfosPrunedPerBlock: u32 = 20;
+    pub const MaxServiceRecordsPrunedPerBlock: u32 = 20;
 }
 
 impl service_discovery::Trait for Runtime {
     type Event = Event;
+    type WorkerOriginValidator = integration::working_group::WorkerOriginValidator;
+    type MaxAccountInfosPrunedPerBlock = MaxAccountInfosPrunedPerBlock;
+    type MaxServiceRecordsPrunedPerBlock = MaxServiceRecordsPrunedPerBlock;
+    type WeightInfo = weights::service_discovery::WeightInfo;
 }
 
 parameter_types! {
@@ -561,7 +650,7 @@ impl proposals_engine::Trait for Runtime {
     type ProposerOriginValidator = MembershipOriginValidator<Self>;
     type VoterOriginValidator = CouncilManager<Self>;
     type TotalVotersCounter = CouncilManager<Self>;
-    type ProposalId = u32;
+    type ProposalId = ProposalId;
     type StakeHandlerProvider = proposals_engine::DefaultStakeHandlerProvider;
     type CancellationFee = ProposalCancellationFee;
     type RejectionFee = ProposalRejectionFee;
@@ -604,6 +693,7 @@ impl proposals_codex::Trait for Runtime {
     type TextProposalMaxLength = TextProposalMaxLength;
     type RuntimeUpgradeWasmProposalMaxLength = RuntimeUpgradeWasmProposalMaxLength;
     type ProposalEncoder = ExtrinsicProposalEncoder;
+    type WeightInfo = weights::proposals_codex::WeightInfo;
 }
 
 parameter_types! {
@@ -678,8 +768,9 @@ construct_runtime!(
         ProposalsDiscussion: proposals_discussion::{Module, Call, Storage, Event<T>},
         ProposalsCodex: proposals_codex::{Module, Call, Storage, Config<T>},
         // --- Working groups
-        // reserved for the future use: ForumWorkingGroup: working_group::<Instance1>::{Module, Call, Storage, Event<T>},
         StorageWorkingGroup: working_group::<Instance2>::{Module, Call, Storage, Config<T>, Event<T>},
         ContentDirectoryWorkingGroup: working_group::<Instance3>::{Module, Call, Storage, Config<T>, Event<T>},
+        // Added after the other modules to keep their indices.
+        ForumWorkingGroup: working_group::<Instance1>::{Module, Call, Storage, Config<T>, Event<T>},
     }
 );
@@ -66,6 +66,9 @@ pub type ActorId = u64;
 /// Represent an member in membership group, which is the same in the working groups.
 pub type MemberId = u64;
 
+/// Represents a proposal identifier in the proposals engine and codex.
+pub type ProposalId = u32;
+
 /// App-specific crypto used for reporting equivocation/misbehavior in BABE and
 /// GRANDPA. Any rewards for misbehavior reporting will be paid out to this
 /// account.
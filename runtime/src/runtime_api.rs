@@ -1,5 +1,5 @@
 use frame_support::inherent::{CheckInherentsResult, InherentData};
-use frame_support::traits::{KeyOwnerProofSystem, OnRuntimeUpgrade, Randomness};
+use frame_support::traits::{Get, KeyOwnerProofSystem, OnRuntimeUpgrade, Randomness};
 use frame_support::unsigned::{TransactionSource, TransactionValidity};
 use pallet_grandpa::fg_primitives;
 use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
@@ -13,14 +13,22 @@ use sp_std::vec::Vec;
 use crate::constants::PRIMARY_PROBABILITY;
 use crate::integration::content_directory::ContentDirectoryWorkingGroup;
 use crate::{
-    content_directory, AccountId, AuthorityDiscoveryId, Balance, BlockNumber, EpochDuration,
-    GrandpaAuthorityList, GrandpaId, Hash, Index, RuntimeVersion, Signature, VERSION,
+    content_directory, data_directory, data_object_type_registry, AccountId, AuthorityDiscoveryId,
+    Balance, BlockNumber, ContentId, EpochDuration, ForumWorkingGroup, ForumWorkingGroupInstance,
+    GrandpaAuthorityList, GrandpaId, Hash, Index, ProposalsConfigParameters, RuntimeVersion,
+    Signature, VERSION,
 };
 use crate::{
-    AllModules, AuthorityDiscovery, Babe, Call, Grandpa, Historical, InherentDataExt,
-    RandomnessCollectiveFlip, Runtime, SessionKeys, System, TransactionPayment,
+    AllModules, AuthorityDiscovery, Babe, Call, DataObjectStorageRegistry, Grandpa, Historical,
+    InherentDataExt, ProposalId, ProposalsEngine, RandomnessCollectiveFlip, Runtime, SessionKeys,
+    System, TransactionPayment,
 };
+use codec::{Decode, Encode};
 use frame_support::weights::Weight;
+use frame_support::{StorageMap, StorageValue};
+use proposals_engine::{ProposalDecisionStatus, VotingResults};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 /// The SignedExtension to the basic transaction logic.
 pub type SignedExtra = (
@@ -70,12 +78,81 @@ impl OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
             default_content_working_group_mint_capacity,
         );
 
+        // Creates the mint and the text constraints of the new forum working group once.
+        if !working_group::Mint::<Runtime, ForumWorkingGroupInstance>::exists() {
+            let default_forum_working_group_mint_capacity = 0;
+
+            ForumWorkingGroup::initialize_working_group(
+                default_text_constraint,
+                default_text_constraint,
+                default_text_constraint,
+                default_forum_working_group_mint_capacity,
+            );
+        }
+
         // Next Id's are configured at genesis. Applications and tools are harcoded to expect initial
         // values of the ids to start at 1. With a runtime upgrade the initial values will not be
         // configured and get an initial default value of zero. This corrects this problem.
         content_directory::Module::<Runtime>::set_initial_ids_to_one();
 
-        10_000_000 // TODO: adjust weight
+        // Moves the known content ids from the legacy list to the paged index.
+        let known_content_ids_migration_weight =
+            data_directory::Module::<Runtime>::migrate_known_content_ids();
+
+        // Adds the empty data object constraints to the data object types created before them.
+        let data_object_types_migration_weight =
+            data_object_type_registry::Module::<Runtime>::migrate();
+
+        // Counts the ready replicas of the content stored before the replication tracking.
+        let replicas_migration_weight = DataObjectStorageRegistry::migrate();
+
+        // Indexes the service discovery records for the expired records pruning.
+        let account_info_expirations_migration_weight =
+            service_discovery::Module::<Runtime>::migrate_account_info_expirations();
+
+        // Configures the invitation terms once, before the members registered before the
+        // invitations get their invites, so the terms changed by the root are kept.
+        if !membership::CurrentInvitationTerms::<Runtime>::exists() {
+            membership::CurrentInvitationTerms::<Runtime>::put(membership::InvitationTerms {
+                invites_per_member: 5,
+                invited_member_balance: 0,
+                invited_member_lock_period: 0,
+            });
+        }
+
+        // Gives the invites to the members registered before the invitations and indexes the
+        // member handles for the case-insensitive uniqueness check.
+        let membership_migration_weight = membership::Module::<Runtime>::migrate();
+
+        // Adds the empty poll and title history to the threads created before them and the moved
+        // threads counter to the categories created before the thread moves.
+        let forum_migration_weight = forum::Module::<Runtime>::migrate();
+
+        // Configures the periods of the 'set membership validation limits' proposal once, so the
+        // periods changed by the council are kept.
+        if !proposals_codex::SetMembershipValidationLimitsProposalVotingPeriod::<Runtime>::exists()
+        {
+            let proposals_config = ProposalsConfigParameters::default();
+            proposals_codex::SetMembershipValidationLimitsProposalVotingPeriod::<Runtime>::put(
+                proposals_config.set_membership_validation_limits_proposal_voting_period,
+            );
+            proposals_codex::SetMembershipValidationLimitsProposalGracePeriod::<Runtime>::put(
+                proposals_config.set_membership_validation_limits_proposal_grace_period,
+            );
+        }
+
+        // Two working group initializations (a mint and three text constraints each), the content
+        // directory ids, the proposal periods, the invitation terms and the checks of the one-time
+        // initializations.
+        let initialization_weight = <Runtime as system::Trait>::DbWeight::get().reads_writes(7, 18);
+
+        initialization_weight
+            + known_content_ids_migration_weight
+            + data_object_types_migration_weight
+            + replicas_migration_weight
+            + account_info_expirations_migration_weight
+            + membership_migration_weight
+            + forum_migration_weight
     }
 }
 
@@ -89,6 +166,41 @@ pub type Executive = frame_executive::Executive<
     CustomOnRuntimeUpgrade,
 >;
 
+/// Proposal with its decoded details and the projected voting outcome.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct ProposalInfo {
+    /// Proposal as stored by the proposals engine.
+    pub proposal: proposals_engine::ProposalOf<Runtime>,
+
+    /// Decoded proposal details from the proposals codex.
+    pub details: proposals_codex::ProposalDetailsOf<Runtime>,
+
+    /// Current voting results.
+    pub voting_results: VotingResults,
+
+    /// Final decision of a finalized proposal or the decision the current voting results lead to
+    /// for an active proposal. None if the voting is still in progress.
+    pub decision_status: Option<ProposalDecisionStatus>,
+
+    /// Block at which the voting period or the grace period of the proposal ends, if any.
+    pub period_ends_at: Option<BlockNumber>,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Proposal queries for the user interfaces and the bots.
+    pub trait ProposalsApi {
+        /// Returns the proposal with its details and voting state, or None if it doesn't exist.
+        fn proposal(proposal_id: ProposalId) -> Option<ProposalInfo>;
+    }
+
+    /// Data directory queries for the storage nodes and the user interfaces.
+    pub trait DataDirectoryApi {
+        /// Returns a page of the known content ids starting from the `start` position.
+        fn known_content_ids(start: u64, limit: u64) -> Vec<ContentId>;
+    }
+}
+
 /// Export of the private const generated within the macro.
 pub const EXPORTED_RUNTIME_API_VERSIONS: sp_version::ApisVec = RUNTIME_API_VERSIONS;
 
@@ -236,4 +348,68 @@ impl_runtime_apis! {
             SessionKeys::decode_into_raw_public_keys(&encoded)
         }
     }
+
+    impl crate::runtime_api::ProposalsApi<Block> for Runtime {
+        fn proposal(proposal_id: ProposalId) -> Option<ProposalInfo> {
+            if !<proposals_engine::Proposals<Runtime>>::contains_key(proposal_id) {
+                return None;
+            }
+
+            let proposal = ProposalsEngine::proposals(proposal_id);
+
+            Some(ProposalInfo {
+                details: <proposals_codex::ProposalDetailsByProposalId<Runtime>>::get(proposal_id),
+                voting_results: proposal.voting_results.clone(),
+                decision_status: ProposalsEngine::projected_decision_status(proposal_id),
+                period_ends_at: ProposalsEngine::period_ends_at(proposal_id),
+                proposal,
+            })
+        }
+    }
+
+    impl crate::runtime_api::DataDirectoryApi<Block> for Runtime {
+        fn known_content_ids(start: u64, limit: u64) -> Vec<ContentId> {
+            data_directory::Module::<Runtime>::known_content_ids(start, limit)
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn dispatch_benchmark(
+            pallet: Vec<u8>,
+            benchmark: Vec<u8>,
+            lowest_range_values: Vec<u32>,
+            highest_range_values: Vec<u32>,
+            steps: Vec<u32>,
+            repeat: u32,
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+            use crate::{
+                Council, CouncilElection, ContentDirectory, ContentDirectoryWorkingGroup,
+                DataDirectory, DataObjectStorageRegistry, DataObjectTypeRegistry, Discovery, Forum,
+                Members, Memo, ForumWorkingGroup, ProposalsCodex, StorageWorkingGroup,
+            };
+            use frame_benchmarking::{add_benchmark, BenchmarkBatch, Benchmarking};
+
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (&pallet, &benchmark, &lowest_range_values, &highest_range_values, &steps, repeat);
+
+            add_benchmark!(params, batches, b"membership", Members);
+            add_benchmark!(params, batches, b"forum", Forum);
+            add_benchmark!(params, batches, b"council", Council);
+            add_benchmark!(params, batches, b"council_election", CouncilElection);
+            add_benchmark!(params, batches, b"data_object_type_registry", DataObjectTypeRegistry);
+            add_benchmark!(params, batches, b"data_directory", DataDirectory);
+            add_benchmark!(params, batches, b"data_object_storage_registry", DataObjectStorageRegistry);
+            add_benchmark!(params, batches, b"content_directory", ContentDirectory);
+            add_benchmark!(params, batches, b"proposals_codex", ProposalsCodex);
+            add_benchmark!(params, batches, b"storage_working_group", StorageWorkingGroup);
+            add_benchmark!(params, batches, b"content_directory_working_group", ContentDirectoryWorkingGroup);
+            add_benchmark!(params, batches, b"forum_working_group", ForumWorkingGroup);
+            add_benchmark!(params, batches, b"service_discovery", Discovery);
+            add_benchmark!(params, batches, b"memo", Memo);
+
+            if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
+            Ok(batches)
+        }
+    }
 }
@@ -3,6 +3,7 @@ use crate::integration::storage::StorageProviderHelper;
 use crate::Runtime;
 
 use frame_support::StorageMap;
+use storage::data_directory::{StorageCapacity, StorageCapacityByStorageProviderId};
 use working_group::{Instance2, Worker};
 
 #[test]
@@ -13,7 +14,7 @@ fn storage_provider_helper_succeeds() {
 
 		// Error - no workers.
 		let random_provider_result = <StorageProviderHelper
-			as storage::data_directory::StorageProviderHelper<Runtime>>::get_random_storage_provider();
+			as storage::data_directory::StorageProviderHelper<Runtime>>::get_random_storage_provider(1000);
 		assert!(random_provider_result.is_err());
 
 		let worker_id1 = 1;
@@ -25,7 +26,7 @@ fn storage_provider_helper_succeeds() {
 		<working_group::WorkerById<Runtime, Instance2>>::insert(worker_id3, Worker::default());
 
 		// Still error - not registered in the service discovery.
-		let random_provider_result = <StorageProviderHelper as storage::data_directory::StorageProviderHelper<Runtime>>::get_random_storage_provider();
+		let random_provider_result = <StorageProviderHelper as storage::data_directory::StorageProviderHelper<Runtime>>::get_random_storage_provider(1000);
 		assert!(random_provider_result.is_err());
 
 		let account_info = service_discovery::ServiceProviderRecord{
@@ -37,9 +38,103 @@ fn storage_provider_helper_succeeds() {
 		<service_discovery::AccountInfoByStorageProviderId<Runtime>>::insert(worker_id2,account_info.clone());
 		<service_discovery::AccountInfoByStorageProviderId<Runtime>>::insert(worker_id3,account_info);
 
-		// Should work now.
+		// Storage providers which have not declared their capacity are not limited.
 		let worker_ids = vec![worker_id1, worker_id2, worker_id3];
-		let random_provider_id = <StorageProviderHelper as storage::data_directory::StorageProviderHelper<Runtime>>::get_random_storage_provider().unwrap();
+		let random_provider_id = <StorageProviderHelper as storage::data_directory::StorageProviderHelper<Runtime>>::get_random_storage_provider(1000).unwrap();
+		assert!(worker_ids.contains(&random_provider_id));
+
+		let storage_capacity = StorageCapacity{
+			capacity: Some(5000),
+			used: 0
+		};
+
+		<StorageCapacityByStorageProviderId<Runtime>>::insert(worker_id1, storage_capacity.clone());
+		<StorageCapacityByStorageProviderId<Runtime>>::insert(worker_id2, storage_capacity.clone());
+		<StorageCapacityByStorageProviderId<Runtime>>::insert(worker_id3, storage_capacity);
+
+		// Storage providers with the declared capacity.
+		let random_provider_id = <StorageProviderHelper as storage::data_directory::StorageProviderHelper<Runtime>>::get_random_storage_provider(1000).unwrap();
 		assert!(worker_ids.contains(&random_provider_id));
 	});
 }
+
+#[test]
+fn storage_provider_helper_skips_storage_providers_without_free_space() {
+    initial_test_ext().execute_with(|| {
+        // Bug in random module requires move the initial block number.
+        <system::Module<Runtime>>::set_block_number(1);
+
+        let full_worker_id = 1;
+        let free_worker_id = 7;
+
+        for worker_id in vec![full_worker_id, free_worker_id] {
+            <working_group::WorkerById<Runtime, Instance2>>::insert(worker_id, Worker::default());
+            <service_discovery::AccountInfoByStorageProviderId<Runtime>>::insert(
+                worker_id,
+                service_discovery::ServiceProviderRecord {
+                    identity: Vec::new(),
+                    expires_at: 1000,
+                },
+            );
+        }
+
+        <StorageCapacityByStorageProviderId<Runtime>>::insert(
+            full_worker_id,
+            StorageCapacity {
+                capacity: Some(5000),
+                used: 4500,
+            },
+        );
+        <StorageCapacityByStorageProviderId<Runtime>>::insert(
+            free_worker_id,
+            StorageCapacity {
+                capacity: Some(5000),
+                used: 0,
+            },
+        );
+
+        let random_provider_id = <StorageProviderHelper as storage::data_directory::StorageProviderHelper<Runtime>>::get_random_storage_provider(1000);
+        assert_eq!(random_provider_id, Ok(free_worker_id));
+
+        // No storage provider can store the content.
+        let random_provider_result = <StorageProviderHelper as storage::data_directory::StorageProviderHelper<Runtime>>::get_random_storage_provider(10_000);
+        assert!(random_provider_result.is_err());
+    });
+}
+
+#[test]
+fn storage_provider_helper_prefers_storage_providers_with_declared_capacity() {
+    initial_test_ext().execute_with(|| {
+        // Bug in random module requires move the initial block number.
+        <system::Module<Runtime>>::set_block_number(1);
+
+        let declared_worker_id = 1;
+        let undeclared_worker_id = 7;
+
+        for worker_id in vec![declared_worker_id, undeclared_worker_id] {
+            <working_group::WorkerById<Runtime, Instance2>>::insert(worker_id, Worker::default());
+            <service_discovery::AccountInfoByStorageProviderId<Runtime>>::insert(
+                worker_id,
+                service_discovery::ServiceProviderRecord {
+                    identity: Vec::new(),
+                    expires_at: 1000,
+                },
+            );
+        }
+
+        <StorageCapacityByStorageProviderId<Runtime>>::insert(
+            declared_worker_id,
+            StorageCapacity {
+                capacity: Some(5000),
+                used: 0,
+            },
+        );
+
+        let random_provider_id = <StorageProviderHelper as storage::data_directory::StorageProviderHelper<Runtime>>::get_random_storage_provider(1000);
+        assert_eq!(random_provider_id, Ok(declared_worker_id));
+
+        // Falls back to the storage providers with the undeclared capacity.
+        let random_provider_id = <StorageProviderHelper as storage::data_directory::StorageProviderHelper<Runtime>>::get_random_storage_provider(10_000);
+        assert_eq!(random_provider_id, Ok(undeclared_worker_id));
+    });
+}
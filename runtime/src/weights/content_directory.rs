@@ -0,0 +1,78 @@
+//! Weights for the content directory module.
+
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl content_directory::WeightInfo for WeightInfo {
+    fn add_curator_group() -> Weight {
+        35_000_000 + DbWeight::get().reads_writes(3, 2)
+    }
+    fn remove_curator_group() -> Weight {
+        40_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn set_curator_group_status() -> Weight {
+        35_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn add_curator_to_group() -> Weight {
+        45_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn remove_curator_from_group() -> Weight {
+        45_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn update_entity_creation_voucher() -> Weight {
+        45_000_000 + DbWeight::get().reads_writes(4, 1)
+    }
+    fn create_class() -> Weight {
+        80_000_000 + DbWeight::get().reads_writes(13, 2)
+    }
+    fn add_maintainer_to_class() -> Weight {
+        55_000_000 + DbWeight::get().reads_writes(4, 2)
+    }
+    fn remove_maintainer_from_class() -> Weight {
+        55_000_000 + DbWeight::get().reads_writes(4, 2)
+    }
+    fn update_class_permissions() -> Weight {
+        95_000_000 + DbWeight::get().reads_writes(13, 11)
+    }
+    fn add_class_schema() -> Weight {
+        450_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn update_class_schema_status() -> Weight {
+        120_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn update_entity_permissions() -> Weight {
+        40_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn transfer_entity_ownership() -> Weight {
+        600_000_000 + DbWeight::get().reads_writes(4, 1)
+    }
+    fn create_entity() -> Weight {
+        110_000_000 + DbWeight::get().reads_writes(5, 4)
+    }
+    fn remove_entity() -> Weight {
+        650_000_000 + DbWeight::get().reads_writes(4, 3)
+    }
+    fn add_schema_support_to_entity() -> Weight {
+        1_300_000_000 + DbWeight::get().reads_writes(4, 1)
+    }
+    fn update_entity_property_values() -> Weight {
+        1_500_000_000 + DbWeight::get().reads_writes(4, 1)
+    }
+    fn clear_entity_property_vector() -> Weight {
+        700_000_000 + DbWeight::get().reads_writes(4, 1)
+    }
+    fn remove_at_entity_property_vector() -> Weight {
+        750_000_000 + DbWeight::get().reads_writes(4, 1)
+    }
+    fn insert_at_entity_property_vector() -> Weight {
+        800_000_000 + DbWeight::get().reads_writes(4, 1)
+    }
+    fn transaction(c: u32, u: u32, a: u32, s: u32) -> Weight {
+        15_000_000
+            + (c as Weight).saturating_mul(110_000_000 + DbWeight::get().reads_writes(5, 4))
+            + (u as Weight).saturating_mul(60_000_000 + DbWeight::get().reads_writes(4, 1))
+            + (a as Weight).saturating_mul(70_000_000 + DbWeight::get().reads_writes(4, 1))
+            + (s as Weight).saturating_mul(30_000)
+    }
+}
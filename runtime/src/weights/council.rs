@@ -0,0 +1,31 @@
+//! Weights for the council module.
+
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl governance::council::WeightInfo for WeightInfo {
+    fn set_council(i: u32) -> Weight {
+        190_000_000
+            + DbWeight::get().reads_writes(44, 21)
+            + (i as Weight).saturating_mul(45_000_000 + DbWeight::get().reads_writes(3, 4))
+    }
+    fn add_council_member() -> Weight {
+        60_000_000 + DbWeight::get().reads_writes(7, 5)
+    }
+    fn remove_council_member() -> Weight {
+        55_000_000 + DbWeight::get().reads_writes(4, 2)
+    }
+    fn set_term_ends_at() -> Weight {
+        15_000_000 + DbWeight::get().writes(1)
+    }
+    fn set_council_mint_capacity() -> Weight {
+        30_000_000 + DbWeight::get().reads_writes(2, 1)
+    }
+    fn spend_from_council_mint() -> Weight {
+        70_000_000 + DbWeight::get().reads_writes(3, 2)
+    }
+    fn set_council_rewards() -> Weight {
+        20_000_000 + DbWeight::get().writes(3)
+    }
+}
@@ -0,0 +1,40 @@
+//! Weights for the data directory module.
+
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl storage::data_directory::WeightInfo for WeightInfo {
+    fn add_content() -> Weight {
+        130_000_000 + DbWeight::get().reads_writes(14, 7)
+    }
+    fn remove_content() -> Weight {
+        130_000_000 + DbWeight::get().reads_writes(20, 31)
+    }
+    fn remove_content_as_lead() -> Weight {
+        125_000_000 + DbWeight::get().reads_writes(20, 31)
+    }
+    fn accept_content() -> Weight {
+        45_000_000 + DbWeight::get().reads_writes(8, 2)
+    }
+    fn reject_content() -> Weight {
+        45_000_000 + DbWeight::get().reads_writes(4, 1)
+    }
+    fn set_storage_capacity() -> Weight {
+        40_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn update_quota_limits() -> Weight {
+        40_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn set_upload_fee_per_byte() -> Weight {
+        15_000_000 + DbWeight::get().writes(1)
+    }
+    fn remove_known_content_id() -> Weight {
+        30_000_000 + DbWeight::get().reads_writes(3, 4)
+    }
+    fn inject_data_objects(i: u32) -> Weight {
+        20_000_000
+            + DbWeight::get().reads_writes(2, 1)
+            + (i as Weight).saturating_mul(10_000_000 + DbWeight::get().reads_writes(9, 10))
+    }
+}
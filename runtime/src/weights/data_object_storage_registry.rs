@@ -0,0 +1,26 @@
+//! Weights for the data object storage registry module.
+
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl storage::data_object_storage_registry::WeightInfo for WeightInfo {
+    fn add_relationship() -> Weight {
+        75_000_000 + DbWeight::get().reads_writes(11, 5)
+    }
+    fn set_relationship_ready() -> Weight {
+        60_000_000 + DbWeight::get().reads_writes(8, 3)
+    }
+    fn unset_relationship_ready() -> Weight {
+        60_000_000 + DbWeight::get().reads_writes(8, 3)
+    }
+    fn set_target_replication_factor() -> Weight {
+        35_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn answer_storage_challenge() -> Weight {
+        45_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn reject_storage_challenge_response() -> Weight {
+        50_000_000 + DbWeight::get().reads_writes(5, 2)
+    }
+}
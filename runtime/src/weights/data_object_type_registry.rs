@@ -0,0 +1,20 @@
+//! Weights for the data object type registry module.
+
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl storage::data_object_type_registry::WeightInfo for WeightInfo {
+    fn register_data_object_type() -> Weight {
+        35_000_000 + DbWeight::get().reads_writes(3, 2)
+    }
+    fn update_data_object_type() -> Weight {
+        35_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn activate_data_object_type() -> Weight {
+        30_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn deactivate_data_object_type() -> Weight {
+        30_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+}
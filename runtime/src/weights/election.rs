@@ -0,0 +1,38 @@
+//! Weights for the council election module.
+
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl governance::election::WeightInfo for WeightInfo {
+    fn apply() -> Weight {
+        90_000_000 + DbWeight::get().reads_writes(9, 4)
+    }
+    fn vote() -> Weight {
+        85_000_000 + DbWeight::get().reads_writes(9, 4)
+    }
+    fn reveal() -> Weight {
+        60_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn set_stage_announcing() -> Weight {
+        15_000_000 + DbWeight::get().writes(1)
+    }
+    fn set_stage_revealing() -> Weight {
+        15_000_000 + DbWeight::get().writes(1)
+    }
+    fn set_stage_voting() -> Weight {
+        15_000_000 + DbWeight::get().writes(1)
+    }
+    fn set_election_parameters() -> Weight {
+        25_000_000 + DbWeight::get().reads_writes(1, 8)
+    }
+    fn force_stop_election() -> Weight {
+        1_250_000_000 + DbWeight::get().reads_writes(125, 145)
+    }
+    fn force_start_election() -> Weight {
+        225_000_000 + DbWeight::get().reads_writes(27, 26)
+    }
+    fn set_auto_start() -> Weight {
+        10_000_000 + DbWeight::get().writes(1)
+    }
+}
@@ -0,0 +1,61 @@
+//! Weights for the forum module.
+
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl forum::WeightInfo for WeightInfo {
+    fn set_forum_sudo() -> Weight {
+        15_000_000 + DbWeight::get().reads_writes(1, 1)
+    }
+    fn create_category() -> Weight {
+        75_000_000 + DbWeight::get().reads_writes(7, 3)
+    }
+    fn update_category() -> Weight {
+        50_000_000 + DbWeight::get().reads_writes(5, 1)
+    }
+    fn create_thread(i: u32) -> Weight {
+        110_000_000 + (i as Weight).saturating_mul(1_500_000) + DbWeight::get().reads_writes(10, 6)
+    }
+    fn moderate_thread() -> Weight {
+        65_000_000 + DbWeight::get().reads_writes(7, 3)
+    }
+    fn add_post() -> Weight {
+        80_000_000 + DbWeight::get().reads_writes(10, 3)
+    }
+    fn edit_post_text() -> Weight {
+        70_000_000 + DbWeight::get().reads_writes(7, 1)
+    }
+    fn moderate_post() -> Weight {
+        70_000_000 + DbWeight::get().reads_writes(7, 2)
+    }
+    fn add_category_moderator() -> Weight {
+        30_000_000 + DbWeight::get().reads_writes(5, 2)
+    }
+    fn remove_category_moderator() -> Weight {
+        25_000_000 + DbWeight::get().reads_writes(3, 2)
+    }
+    fn vote_on_poll(i: u32) -> Weight {
+        45_000_000 + (i as Weight).saturating_mul(800_000) + DbWeight::get().reads_writes(7, 2)
+    }
+    fn react_to_post() -> Weight {
+        50_000_000 + DbWeight::get().reads_writes(8, 2)
+    }
+    fn follow_thread() -> Weight {
+        35_000_000 + DbWeight::get().reads_writes(3, 2)
+    }
+    fn unfollow_thread() -> Weight {
+        25_000_000 + DbWeight::get().reads_writes(2, 2)
+    }
+    fn edit_thread_title() -> Weight {
+        60_000_000 + DbWeight::get().reads_writes(6, 1)
+    }
+    fn move_thread() -> Weight {
+        90_000_000 + DbWeight::get().reads_writes(12, 4)
+    }
+    fn set_sticky_threads(i: u32) -> Weight {
+        40_000_000
+            + (i as Weight).saturating_mul(5_000_000)
+            + DbWeight::get().reads_writes(5 + i as Weight, 1)
+    }
+}
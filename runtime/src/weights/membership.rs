@@ -0,0 +1,80 @@
+//! Weights for the membership module.
+
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl membership::WeightInfo for WeightInfo {
+    fn buy_membership() -> Weight {
+        140_000_000 + DbWeight::get().reads_writes(13, 7)
+    }
+    fn change_member_about_text() -> Weight {
+        45_000_000 + DbWeight::get().reads_writes(2, 1)
+    }
+    fn change_member_avatar() -> Weight {
+        40_000_000 + DbWeight::get().reads_writes(2, 1)
+    }
+    fn change_member_handle() -> Weight {
+        55_000_000 + DbWeight::get().reads_writes(6, 5)
+    }
+    fn update_membership() -> Weight {
+        110_000_000 + DbWeight::get().reads_writes(11, 7)
+    }
+    fn set_controller_account() -> Weight {
+        60_000_000 + DbWeight::get().reads_writes(3, 3)
+    }
+    fn set_root_account() -> Weight {
+        60_000_000 + DbWeight::get().reads_writes(3, 3)
+    }
+    fn add_screened_member() -> Weight {
+        105_000_000 + DbWeight::get().reads_writes(10, 5)
+    }
+    fn set_screening_authority() -> Weight {
+        10_000_000 + DbWeight::get().writes(1)
+    }
+    fn add_verifier() -> Weight {
+        15_000_000 + DbWeight::get().writes(1)
+    }
+    fn remove_verifier() -> Weight {
+        20_000_000 + DbWeight::get().reads_writes(1, 1)
+    }
+    fn attest_member() -> Weight {
+        50_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn revoke_attestation() -> Weight {
+        35_000_000 + DbWeight::get().reads_writes(2, 1)
+    }
+    fn invite_member() -> Weight {
+        160_000_000 + DbWeight::get().reads_writes(14, 11)
+    }
+    fn set_member_invites_count() -> Weight {
+        20_000_000 + DbWeight::get().reads_writes(1, 1)
+    }
+    fn set_invitation_terms() -> Weight {
+        10_000_000 + DbWeight::get().writes(1)
+    }
+    fn remove_invitation_lock() -> Weight {
+        55_000_000 + DbWeight::get().reads_writes(3, 3)
+    }
+    fn set_referral_cut() -> Weight {
+        15_000_000 + DbWeight::get().reads_writes(1, 1)
+    }
+    fn release_handle() -> Weight {
+        45_000_000 + DbWeight::get().reads_writes(3, 4)
+    }
+    fn approve_handle_transfer() -> Weight {
+        35_000_000 + DbWeight::get().reads_writes(2, 1)
+    }
+    fn transfer_handle() -> Weight {
+        90_000_000 + DbWeight::get().reads_writes(6, 9)
+    }
+    fn reserve_handle() -> Weight {
+        20_000_000 + DbWeight::get().reads_writes(2, 1)
+    }
+    fn unreserve_handle() -> Weight {
+        20_000_000 + DbWeight::get().reads_writes(1, 1)
+    }
+    fn set_validation_limits() -> Weight {
+        15_000_000 + DbWeight::get().writes(4)
+    }
+}
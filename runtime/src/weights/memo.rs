@@ -0,0 +1,29 @@
+//! Weights for the memo module.
+
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl memo::WeightInfo for WeightInfo {
+    fn update_memo(i: u32) -> Weight {
+        50_000_000 + DbWeight::get().reads_writes(4, 3) + (i as Weight).saturating_mul(2_000)
+    }
+    fn clear_memo() -> Weight {
+        40_000_000 + DbWeight::get().reads_writes(2, 3)
+    }
+    fn set_max_memo_length() -> Weight {
+        15_000_000 + DbWeight::get().writes(1)
+    }
+    fn send_message(i: u32) -> Weight {
+        70_000_000 + DbWeight::get().reads_writes(7, 5) + (i as Weight).saturating_mul(2_000)
+    }
+    fn acknowledge_message() -> Weight {
+        60_000_000 + DbWeight::get().reads_writes(4, 4)
+    }
+    fn delete_message() -> Weight {
+        60_000_000 + DbWeight::get().reads_writes(4, 4)
+    }
+    fn prune_expired_message() -> Weight {
+        55_000_000 + DbWeight::get().reads_writes(3, 4)
+    }
+}
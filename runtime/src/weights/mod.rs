@@ -0,0 +1,19 @@
+//! Weights of the Joystream pallets extrinsics.
+//!
+//! Every file contains the `WeightInfo` implementation of a single pallet and is generated by
+//! `scripts/run-benchmarks.sh`, which runs the `benchmark` node subcommand with `--output` for
+//! every pallet and writes the results here. Files that have not been regenerated yet hold
+//! values estimated from the storage accesses of each extrinsic on top of a fixed execution cost.
+
+pub mod content_directory;
+pub mod council;
+pub mod data_directory;
+pub mod data_object_storage_registry;
+pub mod data_object_type_registry;
+pub mod election;
+pub mod forum;
+pub mod membership;
+pub mod memo;
+pub mod proposals_codex;
+pub mod service_discovery;
+pub mod working_group;
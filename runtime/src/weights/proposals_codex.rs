@@ -0,0 +1,53 @@
+//! Weights for the proposals codex module.
+
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl proposals_codex::WeightInfo for WeightInfo {
+    fn create_text_proposal(i: u32) -> Weight {
+        270_000_000 + (i as Weight).saturating_mul(1_000) + DbWeight::get().reads_writes(13, 12)
+    }
+    fn create_runtime_upgrade_proposal(i: u32) -> Weight {
+        280_000_000 + (i as Weight).saturating_mul(1_000) + DbWeight::get().reads_writes(13, 12)
+    }
+    fn create_set_election_parameters_proposal() -> Weight {
+        285_000_000 + DbWeight::get().reads_writes(13, 12)
+    }
+    fn create_spending_proposal() -> Weight {
+        275_000_000 + DbWeight::get().reads_writes(13, 12)
+    }
+    fn create_set_validator_count_proposal() -> Weight {
+        280_000_000 + DbWeight::get().reads_writes(14, 12)
+    }
+    fn create_add_working_group_leader_opening_proposal() -> Weight {
+        290_000_000 + DbWeight::get().reads_writes(13, 12)
+    }
+    fn create_begin_review_working_group_leader_applications_proposal() -> Weight {
+        275_000_000 + DbWeight::get().reads_writes(13, 12)
+    }
+    fn create_fill_working_group_leader_opening_proposal() -> Weight {
+        280_000_000 + DbWeight::get().reads_writes(13, 12)
+    }
+    fn create_set_working_group_mint_capacity_proposal() -> Weight {
+        275_000_000 + DbWeight::get().reads_writes(13, 12)
+    }
+    fn create_decrease_working_group_leader_stake_proposal() -> Weight {
+        275_000_000 + DbWeight::get().reads_writes(13, 12)
+    }
+    fn create_slash_working_group_leader_stake_proposal() -> Weight {
+        275_000_000 + DbWeight::get().reads_writes(13, 12)
+    }
+    fn create_set_working_group_leader_reward_proposal() -> Weight {
+        275_000_000 + DbWeight::get().reads_writes(13, 12)
+    }
+    fn create_terminate_working_group_leader_role_proposal() -> Weight {
+        285_000_000 + DbWeight::get().reads_writes(13, 12)
+    }
+    fn create_set_membership_validation_limits_proposal() -> Weight {
+        265_000_000 + DbWeight::get().reads_writes(13, 12)
+    }
+    fn execute_text_proposal(i: u32) -> Weight {
+        5_000_000 + (i as Weight).saturating_mul(1_000)
+    }
+}
@@ -0,0 +1,26 @@
+//! Weights for the service discovery module.
+
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl service_discovery::WeightInfo for WeightInfo {
+    fn set_ipns_id() -> Weight {
+        60_000_000 + DbWeight::get().reads_writes(3, 2)
+    }
+    fn unset_ipns_id() -> Weight {
+        30_000_000 + DbWeight::get().reads_writes(2, 1)
+    }
+    fn set_service_record(i: u32) -> Weight {
+        40_000_000 + DbWeight::get().reads_writes(3, 2) + (i as Weight).saturating_mul(1_000_000)
+    }
+    fn unset_service_record() -> Weight {
+        30_000_000 + DbWeight::get().reads_writes(2, 1)
+    }
+    fn set_default_lifetime() -> Weight {
+        15_000_000 + DbWeight::get().writes(1)
+    }
+    fn set_bootstrap_endpoints(i: u32) -> Weight {
+        15_000_000 + DbWeight::get().writes(1) + (i as Weight).saturating_mul(2_000)
+    }
+}
@@ -0,0 +1,58 @@
+//! Weights for the working group module.
+
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
+
+pub struct WeightInfo;
+impl working_group::WeightInfo for WeightInfo {
+    fn update_role_account() -> Weight {
+        40_000_000 + DbWeight::get().reads_writes(2, 1)
+    }
+    fn update_reward_account() -> Weight {
+        55_000_000 + DbWeight::get().reads_writes(3, 1)
+    }
+    fn update_reward_amount() -> Weight {
+        60_000_000 + DbWeight::get().reads_writes(4, 1)
+    }
+    fn leave_role() -> Weight {
+        285_000_000 + DbWeight::get().reads_writes(18, 17)
+    }
+    fn terminate_role() -> Weight {
+        330_000_000 + DbWeight::get().reads_writes(20, 18)
+    }
+    fn add_opening() -> Weight {
+        95_000_000 + DbWeight::get().reads_writes(5, 4)
+    }
+    fn accept_applications() -> Weight {
+        70_000_000 + DbWeight::get().reads_writes(4, 1)
+    }
+    fn apply_on_opening() -> Weight {
+        310_000_000 + DbWeight::get().reads_writes(14, 12)
+    }
+    fn withdraw_application() -> Weight {
+        170_000_000 + DbWeight::get().reads_writes(9, 7)
+    }
+    fn terminate_application() -> Weight {
+        175_000_000 + DbWeight::get().reads_writes(10, 7)
+    }
+    fn begin_applicant_review() -> Weight {
+        80_000_000 + DbWeight::get().reads_writes(5, 2)
+    }
+    fn fill_opening(i: u32) -> Weight {
+        120_000_000
+            + DbWeight::get().reads_writes(6, 3)
+            + (i as Weight).saturating_mul(150_000_000 + DbWeight::get().reads_writes(9, 8))
+    }
+    fn slash_stake() -> Weight {
+        110_000_000 + DbWeight::get().reads_writes(6, 2)
+    }
+    fn decrease_stake() -> Weight {
+        125_000_000 + DbWeight::get().reads_writes(7, 3)
+    }
+    fn increase_stake() -> Weight {
+        120_000_000 + DbWeight::get().reads_writes(6, 3)
+    }
+    fn set_mint_capacity() -> Weight {
+        45_000_000 + DbWeight::get().reads_writes(2, 1)
+    }
+}
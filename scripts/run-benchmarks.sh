@@ -0,0 +1,59 @@
+#!/usr/bin/env bash
+
+# Runs the runtime benchmarks of the Joystream pallets and writes the generated WeightInfo
+# implementations to runtime/src/weights.
+
+set -e
+
+export WASM_BUILD_TOOLCHAIN=nightly-2020-05-23
+
+SCRIPT_PATH="$( cd "$( dirname "${BASH_SOURCE[0]}" )" && pwd )"
+ROOT_DIR=${SCRIPT_PATH}/..
+WEIGHTS_DIR=${ROOT_DIR}/runtime/src/weights
+NODE_BIN=${ROOT_DIR}/target/release/joystream-node
+
+# <benchmarked pallet>:<weights module>:<WeightInfo trait path>
+# The working group instances share a single weights module, so only one of them is benchmarked.
+PALLETS=(
+  membership:membership:membership::WeightInfo
+  forum:forum:forum::WeightInfo
+  council:council:governance::council::WeightInfo
+  council_election:election:governance::election::WeightInfo
+  data_object_type_registry:data_object_type_registry:storage::data_object_type_registry::WeightInfo
+  data_directory:data_directory:storage::data_directory::WeightInfo
+  data_object_storage_registry:data_object_storage_registry:storage::data_object_storage_registry::WeightInfo
+  content_directory:content_directory:content_directory::WeightInfo
+  proposals_codex:proposals_codex:proposals_codex::WeightInfo
+  storage_working_group:working_group:working_group::WeightInfo
+  service_discovery:service_discovery:service_discovery::WeightInfo
+  memo:memo:memo::WeightInfo
+)
+
+# Build release binary with the benchmarks enabled
+(cd ${ROOT_DIR}/node && cargo build --release --features runtime-benchmarks)
+
+OUTPUT_DIR=$(mktemp -d)
+trap "rm -rf ${OUTPUT_DIR}" EXIT
+
+for entry in "${PALLETS[@]}"; do
+  IFS=: read -r pallet module trait_path <<< "${entry//::/|}"
+  trait_path=${trait_path//|/::}
+
+  echo "Benchmarking ${pallet}"
+  rm -f ${OUTPUT_DIR}/*.rs
+  (cd ${OUTPUT_DIR} && ${NODE_BIN} benchmark \
+    --chain dev \
+    --execution wasm \
+    --wasm-execution compiled \
+    --pallet ${pallet} \
+    --extrinsic '*' \
+    --steps 50 \
+    --repeat 20 \
+    --output)
+
+  # The writer names the implemented trait after the benchmarked pallet.
+  sed -e "s/^impl .*WeightInfo for WeightInfo/impl ${trait_path} for WeightInfo/" \
+    ${OUTPUT_DIR}/*.rs > ${WEIGHTS_DIR}/${module}.rs
+done
+
+rustfmt --edition 2018 ${WEIGHTS_DIR}/*.rs
@@ -1,6 +1,7 @@
 import axios, { AxiosRequestConfig } from 'axios'
 import fs from 'fs'
 import ipfsHash from 'ipfs-only-hash'
+import mime from 'mime-types'
 import { ContentId, DataObject } from '@joystream/types/media'
 import BN from 'bn.js'
 import { Option } from '@polkadot/types/codec'
@@ -14,6 +15,9 @@ const debug = Debug('joystream:storage-cli:upload')
 // Defines maximum content length for the assets (files). Limits the upload.
 const MAX_CONTENT_LENGTH = 500 * 1024 * 1024 // 500Mb
 
+// Content type of the files with unknown extensions.
+const DEFAULT_CONTENT_TYPE = 'application/octet-stream'
+
 // Defines the necessary parameters for the AddContent runtime tx.
 interface AddContentParams {
   accountId: string
@@ -22,6 +26,7 @@ interface AddContentParams {
   fileSize: BN
   dataObjectTypeId: number
   memberId: number
+  contentType: string
 }
 
 // Upload command class. Validates input parameters and uploads the asset to the storage node and runtime.
@@ -80,6 +85,11 @@ export class UploadCommand extends BaseCommand {
     return stats.size
   }
 
+  // Detects the MIME type of the file by its extension.
+  private getContentType(): string {
+    return mime.lookup(this.mediaSourceFilePath) || DEFAULT_CONTENT_TYPE
+  }
+
   // Creates parameters for the AddContent runtime tx.
   private async getAddContentParams(): Promise<AddContentParams> {
     const identity = await this.loadIdentity()
@@ -102,6 +112,7 @@ export class UploadCommand extends BaseCommand {
       fileSize: new BN(this.getFileSize()),
       dataObjectTypeId,
       memberId,
+      contentType: this.getContentType(),
     }
   }
 
@@ -111,9 +122,11 @@ export class UploadCommand extends BaseCommand {
       const dataObject: Option<DataObject> = await this.api.assets.createDataObject(
         p.accountId,
         p.memberId,
+        null, // the uploaded content doesn't belong to a channel
         p.contentId,
         p.dataObjectTypeId,
         p.fileSize,
+        p.contentType,
         p.ipfsCid
       )
 
@@ -3,6 +3,9 @@
 const debug = require('debug')('joystream:runtime:assets')
 const { decodeAddress } = require('@polkadot/keyring')
 
+// Number of the known content ids queried at once.
+const KNOWN_CONTENT_IDS_PAGE_SIZE = 1000
+
 function parseContentId(contentId) {
   try {
     return decodeAddress(contentId)
@@ -27,11 +30,20 @@ class AssetsApi {
   }
 
   /*
-   * Create and return a data object.
+   * Create and return a data object. The channel id is optional, pass null for the content
+   * not belonging to a channel.
    */
-  async createDataObject(accountId, memberId, contentId, doTypeId, size, ipfsCid) {
+  async createDataObject(accountId, memberId, channelId, contentId, doTypeId, size, contentType, ipfsCid) {
     contentId = parseContentId(contentId)
-    const tx = this.base.api.tx.dataDirectory.addContent(memberId, contentId, doTypeId, size, ipfsCid)
+    const tx = this.base.api.tx.dataDirectory.addContent(
+      memberId,
+      channelId,
+      contentId,
+      doTypeId,
+      size,
+      contentType,
+      ipfsCid
+    )
     await this.base.signAndSend(accountId, tx)
 
     // If the data object constructed properly, we should now be able to return
@@ -144,7 +156,16 @@ class AssetsApi {
    * Returns array of know content ids
    */
   async getKnownContentIds() {
-    return this.base.api.query.dataDirectory.knownContentIds()
+    const count = (await this.base.api.query.dataDirectory.knownContentIdsCount()).toNumber()
+    const ids = []
+
+    for (let start = 0; start < count; start += KNOWN_CONTENT_IDS_PAGE_SIZE) {
+      const end = Math.min(start + KNOWN_CONTENT_IDS_PAGE_SIZE, count)
+      const indexes = [...Array(end - start).keys()].map((offset) => start + offset)
+      ids.push(...(await this.base.api.query.dataDirectory.knownContentIdByIndex.multi(indexes)))
+    }
+
+    return ids
   }
 }
 
@@ -188,10 +188,10 @@ class IdentitiesApi {
 
   /*
    * Register account id with userInfo as a new member
-   * using default policy 0, returns new member id
+   * using default policy 0 and no referrer, returns new member id
    */
   async registerMember(accountId, userInfo) {
-    const tx = this.base.api.tx.members.buyMembership(0, userInfo.handle, userInfo.avatarUri, userInfo.about)
+    const tx = this.base.api.tx.members.buyMembership(0, userInfo.handle, userInfo.avatarUri, userInfo.about, null)
 
     return this.base.signAndSendThenGetEventResult(accountId, tx, {
       module: 'members',
@@ -40,6 +40,7 @@ import Debugger from 'debug'
 export enum WorkingGroups {
   StorageWorkingGroup = 'storageWorkingGroup',
   ContentDirectoryWorkingGroup = 'contentDirectoryWorkingGroup',
+  ForumWorkingGroup = 'forumWorkingGroup',
 }
 
 export class ApiFactory {
@@ -131,6 +132,8 @@ export class Api {
         return 'Storage'
       case WorkingGroups.ContentDirectoryWorkingGroup:
         return 'Content'
+      case WorkingGroups.ForumWorkingGroup:
+        return 'Forum'
       default:
         throw new Error(`Invalid working group string representation: ${workingGroup}`)
     }
@@ -147,7 +150,7 @@ export class Api {
 
   public async buyMembership(account: string, paidTermsId: PaidTermId, name: string): Promise<ISubmittableResult> {
     return this.sender.signAndSend(
-      this.api.tx.members.buyMembership(paidTermsId, /* Handle: */ name, /* Avatar uri: */ '', /* About: */ ''),
+      this.api.tx.members.buyMembership(paidTermsId, /* Handle: */ name, /* Avatar uri: */ '', /* About: */ '', /* Referrer: */ null),
       account
     )
   }
@@ -190,7 +193,7 @@ export class Api {
 
   public estimateBuyMembershipFee(account: string, paidTermsId: PaidTermId, name: string): BN {
     return this.estimateTxFee(
-      this.api.tx.members.buyMembership(paidTermsId, /* Handle: */ name, /* Avatar uri: */ '', /* About: */ '')
+      this.api.tx.members.buyMembership(paidTermsId, /* Handle: */ name, /* Avatar uri: */ '', /* About: */ '', /* Referrer: */ null)
     )
   }
 
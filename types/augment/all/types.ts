@@ -518,6 +518,8 @@ export interface EntryMethod extends Enum {
   readonly isScreening: boolean;
   readonly asScreening: AccountId;
   readonly isGenesis: boolean;
+  readonly isInvited: boolean;
+  readonly asInvited: MemberId;
 }
 
 /** @name ExecutionFailed */
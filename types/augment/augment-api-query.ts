@@ -338,9 +338,17 @@ declare module '@polkadot/api/types/storage' {
        **/
       dataObjectByContentId: AugmentedQuery<ApiType, (arg: ContentId | string | Uint8Array) => Observable<Option<DataObject>>>;
       /**
-       * List of ids known to the system.
+       * Content ids known to the system by their position in the index.
        **/
-      knownContentIds: AugmentedQuery<ApiType, () => Observable<Vec<ContentId>>>;
+      knownContentIdByIndex: AugmentedQuery<ApiType, (arg: u64 | AnyNumber | Uint8Array) => Observable<ContentId>>;
+      /**
+       * Positions of the content ids known to the system in the index.
+       **/
+      knownContentIdIndex: AugmentedQuery<ApiType, (arg: ContentId | string | Uint8Array) => Observable<Option<u64>>>;
+      /**
+       * Number of the content ids known to the system.
+       **/
+      knownContentIdsCount: AugmentedQuery<ApiType, () => Observable<u64>>;
     };
     dataObjectStorageRegistry: {
       /**
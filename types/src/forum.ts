@@ -1,5 +1,5 @@
-import { bool, u32, u64, Text, Option, Vec as Vector } from '@polkadot/types'
-import { BlockAndTime, ThreadId, PostId, JoyStructCustom, JoyStructDecorated } from './common'
+import { bool, u32, u64, Text, Option, Null, Vec as Vector } from '@polkadot/types'
+import { BlockAndTime, ThreadId, PostId, JoyEnum, JoyStructCustom, JoyStructDecorated } from './common'
 import { RegistryTypes } from '@polkadot/types/types'
 import AccountId from '@polkadot/types/generic/AccountId'
 
@@ -51,6 +51,28 @@ export class PostTextChange extends JoyStructCustom({
 
 export class VecPostTextChange extends Vector.with(PostTextChange) {}
 
+export type ThreadTitleChangeType = {
+  expired_at: BlockAndTime
+  title: Text
+}
+
+export class ThreadTitleChange extends JoyStructCustom({
+  expired_at: BlockAndTime,
+  title: Text,
+})
+// FIXME: Make it JoyStructDecorated compatible
+{
+  get expired_at(): BlockAndTime {
+    return this.getField('expired_at')
+  }
+
+  get title(): string {
+    return this.getString('title')
+  }
+}
+
+export class VecThreadTitleChange extends Vector.with(ThreadTitleChange) {}
+
 export class OptionModerationAction extends Option.with(ModerationAction) {}
 
 export class CategoryId extends u64 {}
@@ -90,6 +112,7 @@ export type CategoryType = {
   num_direct_moderated_threads: u32
   position_in_parent_category: OptionChildPositionInParentCategory
   moderator_id: AccountId
+  num_direct_moved_threads: u32
 }
 
 export class Category extends JoyStructCustom({
@@ -104,6 +127,7 @@ export class Category extends JoyStructCustom({
   num_direct_moderated_threads: u32,
   position_in_parent_category: OptionChildPositionInParentCategory,
   moderator_id: AccountId,
+  num_direct_moved_threads: u32,
 })
 // FIXME: Make it JoyStructDecorated compatible
 {
@@ -143,8 +167,15 @@ export class Category extends JoyStructCustom({
     return this.getField('num_direct_moderated_threads')
   }
 
+  get num_direct_moved_threads(): u32 {
+    return this.getField('num_direct_moved_threads')
+  }
+
   get num_threads_created(): u32 {
-    return this.registry.createType('u32', this.num_direct_unmoderated_threads.add(this.num_direct_moderated_threads))
+    return this.registry.createType(
+      'u32',
+      this.num_direct_unmoderated_threads.add(this.num_direct_moderated_threads).add(this.num_direct_moved_threads)
+    )
   }
 
   get hasSubcategories(): boolean {
@@ -178,6 +209,43 @@ export class Category extends JoyStructCustom({
   }
 }
 
+export const PostReactionDef = {
+  Like: Null,
+  Dislike: Null,
+  Love: Null,
+  Laugh: Null,
+} as const
+export type PostReactionKey = keyof typeof PostReactionDef
+export class PostReaction extends JoyEnum(PostReactionDef) {}
+
+export class PostReactionCounts extends JoyStructDecorated({
+  likes: u32,
+  dislikes: u32,
+  loves: u32,
+  laughs: u32,
+}) {}
+
+export class PollAlternative extends JoyStructDecorated({
+  text: Text,
+  vote_count: u32,
+}) {}
+
+export class VecPollAlternative extends Vector.with(PollAlternative) {}
+
+export class Poll extends JoyStructDecorated({
+  question: Text,
+  alternatives: VecPollAlternative,
+  ends_at: u32, // BlockNumber
+}) {}
+
+export class OptionPoll extends Option.with(Poll) {}
+
+export class PollInput extends JoyStructDecorated({
+  question: Text,
+  alternatives: Vector.with(Text),
+  ends_at: u32, // BlockNumber
+}) {}
+
 export type ThreadType = {
   id: ThreadId
   title: Text
@@ -188,6 +256,8 @@ export type ThreadType = {
   num_moderated_posts: u32
   created_at: BlockAndTime
   author_id: AccountId
+  poll: OptionPoll
+  title_change_history: VecThreadTitleChange
 }
 
 export class Thread extends JoyStructCustom({
@@ -200,6 +270,8 @@ export class Thread extends JoyStructCustom({
   num_moderated_posts: u32,
   created_at: BlockAndTime,
   author_id: AccountId,
+  poll: OptionPoll,
+  title_change_history: VecThreadTitleChange,
 })
 // FIXME: Make it JoyStructDecorated compatible
 {
@@ -246,6 +318,14 @@ export class Thread extends JoyStructCustom({
   get author_id(): AccountId {
     return this.getField('author_id')
   }
+
+  get poll(): Poll | null {
+    return this.getField('poll').unwrapOr(null)
+  }
+
+  get title_change_history(): VecThreadTitleChange {
+    return this.getField('title_change_history')
+  }
 }
 
 export type PostType = {
@@ -348,10 +428,16 @@ export class Reply extends JoyStructCustom({
 
 export const forumTypes: RegistryTypes = {
   PostTextChange,
+  ThreadTitleChange,
   ModerationAction,
   ChildPositionInParentCategory,
   CategoryId,
   Category,
+  PostReaction,
+  PostReactionCounts,
+  PollAlternative,
+  Poll,
+  PollInput,
   Thread,
   Post,
   ReplyId,
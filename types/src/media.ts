@@ -1,4 +1,4 @@
-import { Option, Vec as Vector, BTreeMap, u64, bool, Text, Null } from '@polkadot/types'
+import { Option, Vec as Vector, BTreeMap, u32, u64, bool, Text, Null } from '@polkadot/types'
 import { BlockAndTime, JoyEnum, JoyStructDecorated, Hash } from './common'
 import { MemberId } from './members'
 import { StorageProviderId } from './working-group' // this should be in discovery really
@@ -64,6 +64,9 @@ export class DataObjectStorageRelationship extends JoyStructDecorated({
 export class DataObjectType extends JoyStructDecorated({
   description: Text,
   active: bool,
+  max_size: Option.with(u64),
+  allowed_content_types: Vector.with(Text),
+  min_replication_factor: Option.with(u32),
 }) {}
 
 export class DataObjectsMap extends BTreeMap.with(ContentId, DataObject) {}
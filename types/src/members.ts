@@ -1,4 +1,4 @@
-import { Option, Null, bool, u32, u64, u128, Text } from '@polkadot/types'
+import { Option, Null, bool, u32, u64, u128, Text, Bytes } from '@polkadot/types'
 import { BlockNumber, Moment } from '@polkadot/types/interfaces'
 import AccountId from '@polkadot/types/generic/AccountId'
 import { RegistryTypes } from '@polkadot/types/types'
@@ -12,10 +12,12 @@ export class ActorId extends u64 {}
 export class Paid extends PaidTermId {}
 export class Screening extends AccountId {}
 export class Genesis extends Null {}
+export class Invited extends MemberId {}
 export class EntryMethod extends JoyEnum({
   Paid,
   Screening,
   Genesis,
+  Invited,
 }) {}
 
 export type IMembership = {
@@ -50,6 +52,32 @@ export class PaidMembershipTerms extends JoyStructDecorated({
   text: Text,
 }) {}
 
+export class ValidationLimits extends JoyStructDecorated({
+  min_handle_length: u32,
+  max_handle_length: u32,
+  max_avatar_uri_length: u32,
+  max_about_text_length: u32,
+}) {}
+
+export class InvitationTerms extends JoyStructDecorated({
+  invites_per_member: u32,
+  invited_member_balance: u128, // BalanceOf
+  invited_member_lock_period: u32, // BlockNumber
+}) {}
+
+export class AttestationKind extends JoyEnum({
+  SocialAccount: Null,
+  KycLight: Null,
+  FoundingMember: Null,
+}) {}
+
+export class Attestation extends JoyStructDecorated({
+  verifier: AccountId,
+  data: Bytes,
+  attested_at: u32, // BlockNumber
+  expires_at: Option.with(u32), // BlockNumber
+}) {}
+
 export const membersTypes: RegistryTypes = {
   EntryMethod,
   MemberId,
@@ -58,6 +86,10 @@ export const membersTypes: RegistryTypes = {
   Membership,
   PaidMembershipTerms,
   ActorId,
+  ValidationLimits,
+  InvitationTerms,
+  AttestationKind,
+  Attestation,
 }
 
 export default membersTypes
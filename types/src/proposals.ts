@@ -3,7 +3,7 @@ import { bool, u128 } from '@polkadot/types/primitive'
 import { BlockNumber, Balance } from '@polkadot/types/interfaces'
 import AccountId from '@polkadot/types/generic/AccountId'
 import { ThreadId, WorkingGroup, JoyEnum, JoyStructDecorated } from './common'
-import { MemberId } from './members'
+import { MemberId, ValidationLimits } from './members'
 import { RoleParameters } from './roles'
 import { StakeId } from './stake'
 import { ElectionParameters } from './council'
@@ -267,6 +267,7 @@ export class ProposalDetails extends JoyEnum({
   SlashWorkingGroupLeaderStake: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
   SetWorkingGroupLeaderReward: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
   TerminateWorkingGroupLeaderRole: TerminateRoleParameters,
+  SetMembershipValidationLimits: ValidationLimits,
 } as const) {}
 
 // export default proposalTypes;
@@ -7,7 +7,8 @@
 // https://testnet.joystream.org/#/js
 
 const script = async ({ api }) => {
-  const ids = await api.query.dataDirectory.knownContentIds()
+  const count = (await api.query.dataDirectory.knownContentIdsCount()).toNumber()
+  const ids = await api.query.dataDirectory.knownContentIdByIndex.multi([...Array(count).keys()])
 
   // When a BTreeMap is constructed for injection the node will fail to decode
   // it if its not sorted.
@@ -30,8 +30,8 @@ const script = async ({ api, keyring }) => {
   let nonce = (await api.query.system.account(sudoAddress)).nonce
   const max = api.consts.dataDirectory.maxObjectsPerInjection.toNumber()
 
-  const preInjectionIds = await api.query.dataDirectory.knownContentIds()
-  console.log(`Before injection there are ${preInjectionIds.length} known object ids`)
+  const preInjectionIdsCount = await api.query.dataDirectory.knownContentIdsCount()
+  console.log(`Before injection there are ${preInjectionIdsCount.toNumber()} known object ids`)
 
   // split injection into batches of max objects
   while (parsed.length) {
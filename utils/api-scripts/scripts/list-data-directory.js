@@ -8,7 +8,8 @@
 // requires nicaea release+
 
 const script = async ({ api }) => {
-  const ids = await api.query.dataDirectory.knownContentIds()
+  const count = (await api.query.dataDirectory.knownContentIdsCount()).toNumber()
+  const ids = await api.query.dataDirectory.knownContentIdByIndex.multi([...Array(count).keys()])
 
   await Promise.all(
     ids.map(async (id) => {